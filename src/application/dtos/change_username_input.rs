@@ -0,0 +1,27 @@
+use crate::domain::{
+    models::{
+        field_error::{FieldError, collect_field},
+        profile::ProfileError,
+    },
+    object_values::{id::Id, username::Username},
+};
+
+#[derive(Debug, Clone)]
+pub struct ChangeUsernameInput {
+    pub id: Id,
+    pub username: Username,
+}
+
+impl ChangeUsernameInput {
+    pub fn try_new(id: String, username: String) -> Result<Self, ProfileError> {
+        let mut errors: Vec<FieldError> = Vec::new();
+
+        let id = collect_field(Id::try_from(id), &mut errors);
+        let username = collect_field(Username::try_from(username), &mut errors);
+
+        match (id, username) {
+            (Some(id), Some(username)) if errors.is_empty() => Ok(Self { id, username }),
+            _ => Err(ProfileError::InvalidFields(errors)),
+        }
+    }
+}
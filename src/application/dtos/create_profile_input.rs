@@ -1,5 +1,5 @@
 use crate::domain::{
-    models::profile::ProfileError,
+    models::{field_error::collect_field, profile::ProfileError},
     object_values::{email::Email, id::Id},
 };
 
@@ -11,9 +11,14 @@ pub struct CreateProfileInput {
 
 impl CreateProfileInput {
     pub fn try_new(id: String, email: String) -> Result<Self, ProfileError> {
-        let id = Id::try_from(id)?;
-        let email = Email::try_from(email)?;
+        let mut errors = Vec::new();
 
-        Ok(Self { id, email })
+        let id = collect_field(Id::try_from(id), &mut errors);
+        let email = collect_field(Email::try_from(email), &mut errors);
+
+        match (id, email) {
+            (Some(id), Some(email)) => Ok(Self { id, email }),
+            _ => Err(ProfileError::InvalidFields(errors)),
+        }
     }
 }
@@ -0,0 +1,14 @@
+use crate::domain::{models::profile::ProfileError, object_values::username::Username};
+
+#[derive(Debug, Clone)]
+pub struct GetProfileByUsernameInput {
+    pub username: Username,
+}
+
+impl GetProfileByUsernameInput {
+    pub fn try_new(username: String) -> Result<Self, ProfileError> {
+        let username = Username::try_from(username)?;
+
+        Ok(Self { username })
+    }
+}
@@ -0,0 +1,79 @@
+use crate::domain::{
+    models::{
+        field_error::{FieldError, collect_field},
+        profile::ProfileError,
+    },
+    object_values::id::Id,
+};
+
+#[derive(Debug, Clone)]
+pub struct GetProfilesByIdsInput {
+    pub ids: Vec<Id>,
+}
+
+impl GetProfilesByIdsInput {
+    /// Parses every id, reporting each invalid one as `ids[<index>]`.
+    /// Repeated ids are kept once, in the order they first appear.
+    pub fn try_new(ids: Vec<String>) -> Result<Self, ProfileError> {
+        let mut errors = Vec::new();
+        let mut parsed: Vec<Id> = Vec::with_capacity(ids.len());
+
+        for (index, id) in ids.into_iter().enumerate() {
+            let result = Id::try_from(id).map_err(|error| FieldError {
+                field: format!("ids[{}]", index),
+                ..error.into()
+            });
+
+            if let Some(id) = collect_field(result, &mut errors)
+                && !parsed.contains(&id)
+            {
+                parsed.push(id);
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(ProfileError::InvalidFields(errors));
+        }
+
+        Ok(Self { ids: parsed })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::*;
+
+    #[test]
+    fn when_ids_are_repeated_should_keep_first_occurrence() {
+        let (first, second) = (Uuid::now_v7().to_string(), Uuid::now_v7().to_string());
+
+        let input =
+            GetProfilesByIdsInput::try_new(vec![first.clone(), second.clone(), first.clone()])
+                .unwrap();
+
+        assert_eq!(
+            input.ids.iter().map(Id::to_string).collect::<Vec<_>>(),
+            vec![first, second]
+        );
+    }
+
+    #[test]
+    fn when_ids_are_invalid_should_report_each_by_index() {
+        let result = GetProfilesByIdsInput::try_new(vec![
+            Uuid::now_v7().to_string(),
+            "nope".into(),
+            "".into(),
+        ]);
+
+        let Err(ProfileError::InvalidFields(errors)) = result else {
+            panic!("expected invalid fields");
+        };
+
+        assert_eq!(
+            errors.iter().map(|e| e.field.as_str()).collect::<Vec<_>>(),
+            vec!["ids[1]", "ids[2]"]
+        );
+    }
+}
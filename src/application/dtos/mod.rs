@@ -1,3 +1,8 @@
+pub mod change_username_input;
 pub mod create_profile_input;
 pub mod get_profile_by_id_input;
+pub mod get_profile_by_username_input;
+pub mod get_profiles_by_ids_input;
+pub mod update_display_name_input;
+pub mod update_privacy_settings_input;
 pub mod update_profile_input;
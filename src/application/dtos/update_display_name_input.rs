@@ -0,0 +1,51 @@
+use crate::domain::{
+    models::{
+        field_error::{FieldError, collect_field},
+        profile::ProfileError,
+    },
+    object_values::{
+        display_name::{DisplayName, DisplayNameFormat},
+        id::Id,
+    },
+};
+
+#[derive(Debug, Clone)]
+pub struct UpdateDisplayNameInput {
+    pub id: Id,
+    pub display_name: Option<DisplayName>,
+    pub format: DisplayNameFormat,
+}
+
+impl UpdateDisplayNameInput {
+    /// Without a `display_name` the name is composed again from the first
+    /// and last names; without a `format` they are shown given name first.
+    pub fn try_new(
+        id: String,
+        display_name: Option<String>,
+        format: Option<String>,
+    ) -> Result<Self, ProfileError> {
+        let mut errors: Vec<FieldError> = Vec::new();
+
+        let id = collect_field(Id::try_from(id), &mut errors);
+        let display_name = collect_field(
+            display_name.map(DisplayName::try_from).transpose(),
+            &mut errors,
+        );
+        let format = collect_field(
+            format
+                .as_deref()
+                .map(DisplayNameFormat::try_new)
+                .transpose(),
+            &mut errors,
+        );
+
+        match (id, display_name, format) {
+            (Some(id), Some(display_name), Some(format)) if errors.is_empty() => Ok(Self {
+                id,
+                display_name,
+                format: format.unwrap_or_default(),
+            }),
+            _ => Err(ProfileError::InvalidFields(errors)),
+        }
+    }
+}
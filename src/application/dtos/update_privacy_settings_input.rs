@@ -0,0 +1,30 @@
+use crate::domain::{models::profile::ProfileError, object_values::id::Id};
+
+#[derive(Debug, Clone)]
+pub struct UpdatePrivacySettingsInput {
+    pub id: Id,
+    pub first_name_visible: Option<bool>,
+    pub last_name_visible: Option<bool>,
+    pub bio_visible: Option<bool>,
+    pub profile_image_url_visible: Option<bool>,
+}
+
+impl UpdatePrivacySettingsInput {
+    pub fn try_new(
+        id: String,
+        first_name_visible: Option<bool>,
+        last_name_visible: Option<bool>,
+        bio_visible: Option<bool>,
+        profile_image_url_visible: Option<bool>,
+    ) -> Result<Self, ProfileError> {
+        let id = Id::try_from(id)?;
+
+        Ok(Self {
+            id,
+            first_name_visible,
+            last_name_visible,
+            bio_visible,
+            profile_image_url_visible,
+        })
+    }
+}
@@ -1,5 +1,8 @@
 use crate::domain::{
-    models::profile::ProfileError,
+    models::{
+        field_error::{FieldError, collect_field},
+        profile::ProfileError,
+    },
     object_values::{
         bio::Bio, first_name::FirstName, id::Id, image_url::ImageUrl, last_name::LastName,
     },
@@ -15,6 +18,8 @@ pub struct UpdateProfileInput {
 }
 
 impl UpdateProfileInput {
+    /// Validates every field before failing, so the caller gets all the
+    /// invalid fields in a single `ProfileError::InvalidFields`.
     pub fn try_new(
         id: String,
         first_name: Option<String>,
@@ -22,19 +27,83 @@ impl UpdateProfileInput {
         bio: Option<String>,
         profile_image_url: Option<String>,
     ) -> Result<Self, ProfileError> {
-        let id = Id::try_from(id)?;
-
-        let first_name = first_name.map(FirstName::try_from).transpose()?;
-        let last_name = last_name.map(LastName::try_from).transpose()?;
-        let bio = bio.map(Bio::try_from).transpose()?;
-        let profile_image_url = profile_image_url.map(ImageUrl::try_from).transpose()?;
-
-        Ok(Self {
-            id,
-            first_name,
-            last_name,
-            bio,
-            profile_image_url,
-        })
+        let mut errors: Vec<FieldError> = Vec::new();
+
+        let id = collect_field(Id::try_from(id), &mut errors);
+        let first_name =
+            collect_field(first_name.map(FirstName::try_from).transpose(), &mut errors);
+        let last_name = collect_field(last_name.map(LastName::try_from).transpose(), &mut errors);
+        let bio = collect_field(bio.map(Bio::try_from).transpose(), &mut errors);
+        let profile_image_url = collect_field(
+            profile_image_url.map(ImageUrl::try_from).transpose(),
+            &mut errors,
+        );
+
+        match (id, first_name, last_name, bio, profile_image_url) {
+            (Some(id), Some(first_name), Some(last_name), Some(bio), Some(profile_image_url))
+                if errors.is_empty() =>
+            {
+                Ok(Self {
+                    id,
+                    first_name,
+                    last_name,
+                    bio,
+                    profile_image_url,
+                })
+            }
+            _ => Err(ProfileError::InvalidFields(errors)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::error_code::ErrorCode;
+
+    #[test]
+    fn when_several_fields_are_invalid_should_report_all_of_them() {
+        let result = UpdateProfileInput::try_new(
+            "123e4567-e89b-12d3-a456-426614174000".into(),
+            Some("J".into()),
+            Some("Doe".into()),
+            Some("short".into()),
+            Some("ftp://example.com/me.png".into()),
+        );
+
+        let Err(ProfileError::InvalidFields(errors)) = result else {
+            panic!("expected invalid fields");
+        };
+
+        let codes: Vec<(&str, ErrorCode)> = errors
+            .iter()
+            .map(|error| (error.field.as_str(), error.code))
+            .collect();
+
+        assert_eq!(
+            codes,
+            vec![
+                ("firstName", ErrorCode::FirstNameTooShort),
+                ("bio", ErrorCode::BioTooShort),
+                ("profileImageUrl", ErrorCode::ImageUrlInvalidScheme),
+            ]
+        );
+        assert_eq!(errors[0].params["min"], 2);
+        assert_eq!(errors[1].params["min"], 10);
+    }
+
+    #[test]
+    fn when_all_fields_are_valid_should_build_input() {
+        let input = UpdateProfileInput::try_new(
+            "123e4567-e89b-12d3-a456-426614174000".into(),
+            Some("John".into()),
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert!(input.first_name.is_some());
+        assert!(input.last_name.is_none());
     }
 }
@@ -0,0 +1,103 @@
+use std::sync::Arc;
+
+use crate::{
+    application::dtos::create_profile_input::CreateProfileInput,
+    domain::{
+        models::profile::{Profile, ProfileError},
+        repositories::profile_repo::ProfileRepository,
+    },
+};
+
+#[derive(Clone)]
+pub struct BulkCreateProfilesUseCase<R: ProfileRepository + Send + Sync> {
+    repository: Arc<R>,
+}
+
+impl<R: ProfileRepository + Send + Sync> BulkCreateProfilesUseCase<R> {
+    pub fn new(repository: Arc<R>) -> Self {
+        Self { repository }
+    }
+
+    /// Creates every profile with a single write. Each input gets its own
+    /// outcome, in order; the call only fails as a whole when the batch
+    /// could not be written at all.
+    pub async fn execute(
+        &self,
+        inputs: Vec<CreateProfileInput>,
+    ) -> Result<Vec<Result<Profile, ProfileError>>, ProfileError> {
+        let profiles: Vec<Profile> = inputs
+            .into_iter()
+            .map(|input| Profile::new(input.id, input.email))
+            .collect();
+
+        let outcomes = self.repository.insert_profiles(&profiles).await?;
+
+        Ok(profiles
+            .into_iter()
+            .zip(outcomes)
+            .map(|(profile, outcome)| outcome.map(|_| profile).map_err(ProfileError::from))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fake::{Fake, faker::internet::en::FreeEmail};
+
+    use super::*;
+    use crate::domain::{
+        object_values::{email::Email, id::Id},
+        repositories::profile_repo::{MockProfileRepository, ProfileRepositoryError},
+    };
+
+    fn input() -> CreateProfileInput {
+        CreateProfileInput {
+            id: Id::generate(),
+            email: Email::try_new(FreeEmail().fake()).unwrap(),
+        }
+    }
+
+    #[tokio::test]
+    async fn when_some_inserts_fail_should_report_each_outcome_in_order() {
+        let mut mock_repo = MockProfileRepository::new();
+
+        mock_repo
+            .expect_insert_profiles()
+            .times(1)
+            .returning(|profiles| {
+                Ok(vec![
+                    Ok(()),
+                    Err(ProfileRepositoryError::AlreadyExists(
+                        profiles[1].id().to_string(),
+                    )),
+                    Ok(()),
+                ])
+            });
+
+        let use_case = BulkCreateProfilesUseCase::new(Arc::new(mock_repo));
+        let inputs = vec![input(), input(), input()];
+        let ids: Vec<Id> = inputs.iter().map(|input| input.id.clone()).collect();
+
+        let result = use_case.execute(inputs).await.unwrap();
+
+        assert_eq!(result[0].as_ref().unwrap().id(), &ids[0]);
+        assert!(matches!(result[1], Err(ProfileError::AlreadyExists(_))));
+        assert_eq!(result[2].as_ref().unwrap().version(), 1);
+    }
+
+    #[tokio::test]
+    async fn when_batch_cannot_be_written_should_return_error() {
+        let mut mock_repo = MockProfileRepository::new();
+
+        mock_repo
+            .expect_insert_profiles()
+            .times(1)
+            .returning(|_| Err(ProfileRepositoryError::Unknown("mock error".into())));
+
+        let use_case = BulkCreateProfilesUseCase::new(Arc::new(mock_repo));
+
+        let result = use_case.execute(vec![input()]).await;
+
+        assert!(matches!(result, Err(ProfileError::Unknown(_))));
+    }
+}
@@ -0,0 +1,214 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+};
+
+use crate::{
+    application::dtos::update_profile_input::UpdateProfileInput,
+    domain::{
+        models::profile::{Profile, ProfileError},
+        object_values::id::Id,
+        repositories::profile_repo::ProfileRepository,
+    },
+};
+
+#[derive(Clone)]
+pub struct BulkUpdateProfilesUseCase<R: ProfileRepository + Send + Sync> {
+    repository: Arc<R>,
+}
+
+impl<R: ProfileRepository + Send + Sync> BulkUpdateProfilesUseCase<R> {
+    pub fn new(repository: Arc<R>) -> Self {
+        Self { repository }
+    }
+
+    /// Applies every update with one read and one write. Each input gets its
+    /// own outcome, in order. A profile updated twice in the same batch only
+    /// takes the first update; the second is reported as a version conflict,
+    /// as it would be if both had been sent concurrently.
+    pub async fn execute(
+        &self,
+        inputs: Vec<UpdateProfileInput>,
+    ) -> Result<Vec<Result<Profile, ProfileError>>, ProfileError> {
+        let mut ids: Vec<Id> = inputs.iter().map(|input| input.id.clone()).collect();
+        ids.sort();
+        ids.dedup();
+
+        let mut existing: BTreeMap<Id, Profile> = if ids.is_empty() {
+            BTreeMap::new()
+        } else {
+            self.repository
+                .get_profiles_by_ids(&ids)
+                .await?
+                .into_iter()
+                .map(|profile| (profile.id().clone(), profile))
+                .collect()
+        };
+
+        let now = chrono::Utc::now();
+        let mut outcomes: Vec<Result<Profile, ProfileError>> = Vec::with_capacity(inputs.len());
+        let mut writes: Vec<(usize, Profile)> = Vec::new();
+        let mut seen: BTreeSet<Id> = BTreeSet::new();
+
+        for (index, input) in inputs.into_iter().enumerate() {
+            if !seen.insert(input.id.clone()) {
+                outcomes.push(Err(ProfileError::VersionConflict(input.id.to_string())));
+                continue;
+            }
+
+            let Some(mut profile) = existing.remove(&input.id) else {
+                outcomes.push(Err(ProfileError::NotFound(input.id.to_string())));
+                continue;
+            };
+
+            if profile.updated_at().unwrap_or(profile.created_at()) > now {
+                outcomes.push(Err(ProfileError::VersionConflict(
+                    "Version mismatch".to_string(),
+                )));
+                continue;
+            }
+
+            profile.update_profile(
+                input.first_name,
+                input.last_name,
+                input.bio,
+                input.profile_image_url,
+            );
+
+            outcomes.push(Ok(profile.clone()));
+            writes.push((index, profile));
+        }
+
+        if writes.is_empty() {
+            return Ok(outcomes);
+        }
+
+        let profiles: Vec<Profile> = writes.iter().map(|(_, profile)| profile.clone()).collect();
+        let results = self.repository.update_profiles(&profiles).await?;
+
+        for ((index, _), result) in writes.into_iter().zip(results) {
+            if let Err(e) = result {
+                outcomes[index] = Err(ProfileError::from(e));
+            }
+        }
+
+        Ok(outcomes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        object_values::{email::Email, first_name::FirstName},
+        repositories::profile_repo::{MockProfileRepository, ProfileRepositoryError},
+    };
+
+    fn input(id: &Id, first_name: &str) -> UpdateProfileInput {
+        UpdateProfileInput {
+            id: id.clone(),
+            first_name: Some(FirstName::try_from(first_name.to_string()).unwrap()),
+            last_name: None,
+            bio: None,
+            profile_image_url: None,
+        }
+    }
+
+    fn profile(id: &Id) -> Profile {
+        Profile::new(id.clone(), Email::try_from("john.doe@example.com").unwrap())
+    }
+
+    #[tokio::test]
+    async fn when_batch_mixes_outcomes_should_report_each_in_order() {
+        let (updated, conflicting, absent) = (Id::generate(), Id::generate(), Id::generate());
+        let mut mock_repo = MockProfileRepository::new();
+
+        let stored = vec![profile(&updated), profile(&conflicting)];
+        mock_repo
+            .expect_get_profiles_by_ids()
+            .times(1)
+            .returning(move |_| Ok(stored.clone()));
+        mock_repo
+            .expect_update_profiles()
+            .withf(|profiles| profiles.len() == 2)
+            .times(1)
+            .returning(|profiles| {
+                Ok(vec![
+                    Ok(()),
+                    Err(ProfileRepositoryError::VersionConflict(
+                        profiles[1].id().to_string(),
+                    )),
+                ])
+            });
+
+        let use_case = BulkUpdateProfilesUseCase::new(Arc::new(mock_repo));
+
+        let result = use_case
+            .execute(vec![
+                input(&updated, "John"),
+                input(&absent, "Jane"),
+                input(&conflicting, "Joe"),
+            ])
+            .await
+            .unwrap();
+
+        let profile = result[0].as_ref().unwrap();
+        assert_eq!(profile.first_name().unwrap().to_string(), "John");
+        assert_eq!(profile.version(), 2);
+        assert!(matches!(result[1], Err(ProfileError::NotFound(_))));
+        assert!(matches!(result[2], Err(ProfileError::VersionConflict(_))));
+    }
+
+    #[tokio::test]
+    async fn when_profile_repeats_in_batch_should_only_apply_first_update() {
+        let id = Id::generate();
+        let mut mock_repo = MockProfileRepository::new();
+
+        let stored = profile(&id);
+        mock_repo
+            .expect_get_profiles_by_ids()
+            .withf(|ids| ids.len() == 1)
+            .returning(move |_| Ok(vec![stored.clone()]));
+        mock_repo
+            .expect_update_profiles()
+            .withf(|profiles| profiles.len() == 1)
+            .returning(|_| Ok(vec![Ok(())]));
+
+        let use_case = BulkUpdateProfilesUseCase::new(Arc::new(mock_repo));
+
+        let result = use_case
+            .execute(vec![input(&id, "John"), input(&id, "Jane")])
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result[0]
+                .as_ref()
+                .unwrap()
+                .first_name()
+                .unwrap()
+                .to_string(),
+            "John"
+        );
+        assert!(matches!(result[1], Err(ProfileError::VersionConflict(_))));
+    }
+
+    #[tokio::test]
+    async fn when_nothing_can_be_updated_should_not_write() {
+        let mut mock_repo = MockProfileRepository::new();
+
+        mock_repo
+            .expect_get_profiles_by_ids()
+            .returning(|_| Ok(Vec::new()));
+        mock_repo.expect_update_profiles().never();
+
+        let use_case = BulkUpdateProfilesUseCase::new(Arc::new(mock_repo));
+
+        let result = use_case
+            .execute(vec![input(&Id::generate(), "John")])
+            .await
+            .unwrap();
+
+        assert!(matches!(result[0], Err(ProfileError::NotFound(_))));
+    }
+}
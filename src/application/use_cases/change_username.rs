@@ -0,0 +1,252 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+
+use crate::{
+    application::dtos::change_username_input::ChangeUsernameInput,
+    domain::{
+        models::profile::{Profile, ProfileError},
+        repositories::profile_repo::{ProfileRepository, UsernameReservation},
+    },
+};
+
+/// Limits on how often a profile may rename itself, and for how long the
+/// handle it gives up stays out of reach of anybody else.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UsernamePolicy {
+    pub change_interval: chrono::Duration,
+    pub reservation_period: chrono::Duration,
+}
+
+impl Default for UsernamePolicy {
+    fn default() -> Self {
+        Self {
+            change_interval: chrono::Duration::days(30),
+            reservation_period: chrono::Duration::days(90),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct ChangeUsernameUseCase<R: ProfileRepository + Send + Sync> {
+    repository: Arc<R>,
+    policy: UsernamePolicy,
+}
+
+impl<R: ProfileRepository + Send + Sync> ChangeUsernameUseCase<R> {
+    pub fn new(repository: Arc<R>, policy: UsernamePolicy) -> Self {
+        Self { repository, policy }
+    }
+
+    pub fn with_policy(&self, policy: UsernamePolicy) -> Self {
+        Self::new(Arc::clone(&self.repository), policy)
+    }
+
+    pub async fn execute(&self, input: ChangeUsernameInput) -> Result<Profile, ProfileError> {
+        let mut profile = self
+            .repository
+            .get_profile_by_id(&input.id)
+            .await?
+            .ok_or_else(|| ProfileError::NotFound(input.id.to_string()))?;
+
+        if profile.username() == Some(&input.username) {
+            return Ok(profile);
+        }
+
+        // Um nome liberado continua disponível apenas para quem o liberou
+        if let Some(reservation) = self
+            .repository
+            .get_username_reservation(&input.username)
+            .await?
+            && reservation.profile_id != input.id
+        {
+            return Err(ProfileError::UsernameTaken(input.username.to_string()));
+        }
+
+        let released = profile.change_username(input.username, self.policy.change_interval)?;
+
+        // Reservado antes de gravar, para que ninguém o tome no intervalo
+        if let Some(username) = released {
+            self.repository
+                .reserve_username(&UsernameReservation {
+                    username,
+                    profile_id: input.id,
+                    expires_at: Utc::now() + self.policy.reservation_period,
+                })
+                .await?;
+        }
+
+        self.repository.save(&profile).await?;
+
+        Ok(profile)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        object_values::{email::Email, id::Id, username::Username},
+        repositories::profile_repo::{MockProfileRepository, ProfileRepositoryError},
+    };
+
+    fn profile_with(username: Option<&str>, changed_days_ago: i64) -> Profile {
+        Profile::new(
+            Id::generate(),
+            Email::try_from("john.doe@example.com").unwrap(),
+        )
+        .with_username(
+            username.map(|username| Username::try_from(username).unwrap()),
+            Some(Utc::now() - chrono::Duration::days(changed_days_ago)),
+        )
+    }
+
+    fn use_case(mock_repo: MockProfileRepository) -> ChangeUsernameUseCase<MockProfileRepository> {
+        ChangeUsernameUseCase::new(Arc::new(mock_repo), UsernamePolicy::default())
+    }
+
+    fn input(profile: &Profile, username: &str) -> ChangeUsernameInput {
+        ChangeUsernameInput::try_new(profile.id().to_string(), username.to_string()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn when_renaming_should_reserve_released_username_for_owner() {
+        let stored = profile_with(Some("john.doe"), 40);
+        let id = stored.id().clone();
+        let mut mock_repo = MockProfileRepository::new();
+
+        let returned = stored.clone();
+        mock_repo
+            .expect_get_profile_by_id()
+            .returning(move |_| Ok(Some(returned.clone())));
+        mock_repo
+            .expect_get_username_reservation()
+            .returning(|_| Ok(None));
+
+        let owner = id.clone();
+        mock_repo
+            .expect_reserve_username()
+            .withf(move |reservation| {
+                reservation.username.as_ref() == "john.doe"
+                    && reservation.profile_id == owner
+                    && reservation.expires_at > Utc::now() + chrono::Duration::days(89)
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+        mock_repo
+            .expect_save()
+            .withf(|profile| profile.username().unwrap().as_ref() == "johnny")
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let profile = use_case(mock_repo)
+            .execute(input(&stored, "johnny"))
+            .await
+            .unwrap();
+
+        assert_eq!(profile.version(), stored.version() + 1);
+    }
+
+    #[tokio::test]
+    async fn when_username_is_reserved_by_someone_else_should_return_taken_error() {
+        let stored = profile_with(None, 0);
+        let mut mock_repo = MockProfileRepository::new();
+
+        let returned = stored.clone();
+        mock_repo
+            .expect_get_profile_by_id()
+            .returning(move |_| Ok(Some(returned.clone())));
+        mock_repo
+            .expect_get_username_reservation()
+            .returning(|username| {
+                Ok(Some(UsernameReservation {
+                    username: username.clone(),
+                    profile_id: Id::generate(),
+                    expires_at: Utc::now() + chrono::Duration::days(10),
+                }))
+            });
+        mock_repo.expect_save().never();
+
+        let result = use_case(mock_repo)
+            .execute(input(&stored, "j0hn.doe"))
+            .await;
+
+        assert_eq!(result, Err(ProfileError::UsernameTaken("j0hn.doe".into())));
+    }
+
+    #[tokio::test]
+    async fn when_owner_reclaims_reserved_username_should_allow_it() {
+        let stored = profile_with(Some("johnny"), 40);
+        let owner = stored.id().clone();
+        let mut mock_repo = MockProfileRepository::new();
+
+        let returned = stored.clone();
+        mock_repo
+            .expect_get_profile_by_id()
+            .returning(move |_| Ok(Some(returned.clone())));
+        mock_repo
+            .expect_get_username_reservation()
+            .returning(move |username| {
+                Ok(Some(UsernameReservation {
+                    username: username.clone(),
+                    profile_id: owner.clone(),
+                    expires_at: Utc::now() + chrono::Duration::days(10),
+                }))
+            });
+        mock_repo.expect_reserve_username().returning(|_| Ok(()));
+        mock_repo.expect_save().times(1).returning(|_| Ok(()));
+
+        let profile = use_case(mock_repo)
+            .execute(input(&stored, "john.doe"))
+            .await
+            .unwrap();
+
+        assert_eq!(profile.username().unwrap().as_ref(), "john.doe");
+    }
+
+    #[tokio::test]
+    async fn when_renamed_recently_should_return_too_soon_error() {
+        let stored = profile_with(Some("john.doe"), 5);
+        let mut mock_repo = MockProfileRepository::new();
+
+        let returned = stored.clone();
+        mock_repo
+            .expect_get_profile_by_id()
+            .returning(move |_| Ok(Some(returned.clone())));
+        mock_repo
+            .expect_get_username_reservation()
+            .returning(|_| Ok(None));
+        mock_repo.expect_reserve_username().never();
+        mock_repo.expect_save().never();
+
+        let result = use_case(mock_repo).execute(input(&stored, "johnny")).await;
+
+        assert!(matches!(
+            result,
+            Err(ProfileError::UsernameChangeTooSoon(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn when_username_is_held_by_another_profile_should_return_taken_error() {
+        let stored = profile_with(None, 0);
+        let mut mock_repo = MockProfileRepository::new();
+
+        let returned = stored.clone();
+        mock_repo
+            .expect_get_profile_by_id()
+            .returning(move |_| Ok(Some(returned.clone())));
+        mock_repo
+            .expect_get_username_reservation()
+            .returning(|_| Ok(None));
+        mock_repo
+            .expect_save()
+            .returning(|_| Err(ProfileRepositoryError::UsernameTaken("john.doe".into())));
+
+        let result = use_case(mock_repo)
+            .execute(input(&stored, "john.doe"))
+            .await;
+
+        assert_eq!(result, Err(ProfileError::UsernameTaken("john.doe".into())));
+    }
+}
@@ -18,72 +18,92 @@ impl<R: ProfileRepository + Send + Sync> CreateProfileUseCase<R> {
         Self { repository }
     }
 
-    pub async fn execute(&self, input: CreateProfileInput) -> Result<(), ProfileError> {
-        if let Ok(Some(_)) = self.repository.get_profile_by_id(&input.id).await {
-            return Err(ProfileError::AlreadyExists(input.id.to_string()));
-        }
-
+    /// Uniqueness is enforced by the repository on insert, not by a prior
+    /// lookup, so concurrent creates of the same id cannot both succeed.
+    pub async fn execute(&self, input: CreateProfileInput) -> Result<Profile, ProfileError> {
         let profile = Profile::new(input.id, input.email);
 
         self.repository.save(&profile).await?;
 
-        Ok(())
+        Ok(profile)
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+
     use fake::{Fake, faker::internet::en::FreeEmail};
 
     use super::*;
     use crate::domain::{
         object_values::{email::Email, id::Id},
-        repositories::profile_repo::MockProfileRepository,
+        repositories::profile_repo::{MockProfileRepository, ProfileRepositoryError},
     };
 
+    fn input(id: Id) -> CreateProfileInput {
+        CreateProfileInput {
+            id,
+            email: Email::try_new(FreeEmail().fake()).unwrap(),
+        }
+    }
+
     #[tokio::test]
-    async fn when_input_exist_profile_should_return_already_exists_error() {
+    async fn when_repository_reports_duplicate_should_return_already_exists_error() {
         let mut mock_repo = MockProfileRepository::new();
 
-        let input = CreateProfileInput {
-            id: Id::generate(),
-            email: Email::try_new(FreeEmail().fake()).unwrap(),
-        };
+        mock_repo.expect_save().times(1).returning(|profile| {
+            Err(ProfileRepositoryError::AlreadyExists(
+                profile.id().to_string(),
+            ))
+        });
+        mock_repo.expect_get_profile_by_id().never();
+
+        let use_case = CreateProfileUseCase::new(Arc::new(mock_repo));
 
-        let profile = Profile::new(Id::generate(), Email::try_new(FreeEmail().fake()).unwrap());
+        let result = use_case.execute(input(Id::generate())).await;
+
+        assert!(matches!(result, Err(ProfileError::AlreadyExists(_))));
+    }
+
+    #[tokio::test]
+    async fn when_repository_fails_should_return_error() {
+        let mut mock_repo = MockProfileRepository::new();
 
         mock_repo
-            .expect_get_profile_by_id()
+            .expect_save()
             .times(1)
-            .return_const(Ok(Some(profile)));
+            .return_const(Err(ProfileRepositoryError::Unknown(
+                "connection refused".into(),
+            )));
 
         let use_case = CreateProfileUseCase::new(Arc::new(mock_repo));
 
-        let result = use_case.execute(input).await;
+        let result = use_case.execute(input(Id::generate())).await;
 
-        assert!(matches!(result, Err(ProfileError::AlreadyExists(_))));
+        assert!(matches!(result, Err(ProfileError::Unknown(_))));
     }
 
     #[tokio::test]
-    async fn when_input_valid_and_not_exist_should_return_ok() {
+    async fn when_input_valid_and_not_exist_should_return_created_profile() {
         let mut mock_repo = MockProfileRepository::new();
 
+        let id = Id::generate();
+        let email = Email::try_new(FreeEmail().fake()).unwrap();
+
         let input = CreateProfileInput {
-            id: Id::generate(),
-            email: Email::try_new(FreeEmail().fake()).unwrap(),
+            id: id.clone(),
+            email: email.clone(),
         };
 
-        mock_repo
-            .expect_get_profile_by_id()
-            .times(1)
-            .return_const(Ok(None));
-
         mock_repo.expect_save().times(1).return_const(Ok(()));
 
         let use_case = CreateProfileUseCase::new(Arc::new(mock_repo));
 
-        let result = use_case.execute(input).await;
+        let profile = use_case.execute(input).await.unwrap();
 
-        assert!(result.is_ok())
+        assert_eq!(profile.id(), &id);
+        assert_eq!(profile.email(), &email);
+        assert_eq!(profile.version(), 1);
     }
 }
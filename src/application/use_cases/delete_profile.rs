@@ -0,0 +1,68 @@
+use std::sync::Arc;
+
+use crate::{
+    application::dtos::get_profile_by_id_input::GetProfileByIdInput,
+    domain::{models::profile::ProfileError, repositories::profile_repo::ProfileRepository},
+};
+
+#[derive(Clone)]
+pub struct DeleteProfileUseCase<R: ProfileRepository + Send + Sync> {
+    repository: Arc<R>,
+}
+
+impl<R: ProfileRepository + Send + Sync> DeleteProfileUseCase<R> {
+    pub fn new(repository: Arc<R>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, input: GetProfileByIdInput) -> Result<(), ProfileError> {
+        if self.repository.delete_profile(&input.id).await? {
+            Ok(())
+        } else {
+            Err(ProfileError::NotFound(input.id.to_string()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::domain::repositories::profile_repo::MockProfileRepository;
+
+    #[tokio::test]
+    async fn when_profile_exists_should_delete_it() {
+        let mut mock_repo = MockProfileRepository::new();
+
+        let input = GetProfileByIdInput::try_new(Uuid::now_v7().to_string()).unwrap();
+
+        let expected = input.id.clone();
+        mock_repo
+            .expect_delete_profile()
+            .withf(move |id| id == &expected)
+            .times(1)
+            .return_const(Ok(true));
+
+        let use_case = DeleteProfileUseCase::new(Arc::new(mock_repo));
+
+        assert!(use_case.execute(input).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn when_profile_not_found_should_return_not_found_error() {
+        let mut mock_repo = MockProfileRepository::new();
+
+        mock_repo
+            .expect_delete_profile()
+            .times(1)
+            .return_const(Ok(false));
+
+        let use_case = DeleteProfileUseCase::new(Arc::new(mock_repo));
+
+        let input = GetProfileByIdInput::try_new(Uuid::now_v7().to_string()).unwrap();
+        let result = use_case.execute(input).await;
+
+        assert!(matches!(result, Err(ProfileError::NotFound(_))));
+    }
+}
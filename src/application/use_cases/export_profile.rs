@@ -0,0 +1,108 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+
+use crate::{
+    application::dtos::get_profile_by_id_input::GetProfileByIdInput,
+    domain::{
+        models::profile::{Profile, ProfileError},
+        repositories::profile_repo::ProfileRepository,
+    },
+};
+
+/// Everything the service stores about a user, as handed over on a data
+/// portability request. The profile is complete: privacy settings only hide
+/// fields from other users, never from the owner, and a profile with fields
+/// that no longer pass validation fails rather than leaving them out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProfileExport {
+    pub profile: Profile,
+    pub exported_at: DateTime<Utc>,
+}
+
+#[derive(Clone)]
+pub struct ExportProfileUseCase<R: ProfileRepository + Send + Sync> {
+    repository: Arc<R>,
+}
+
+impl<R: ProfileRepository + Send + Sync> ExportProfileUseCase<R> {
+    pub fn new(repository: Arc<R>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, input: GetProfileByIdInput) -> Result<ProfileExport, ProfileError> {
+        let profile = self
+            .repository
+            .get_complete_profile_by_id(&input.id)
+            .await
+            .map_err(ProfileError::from)?
+            .ok_or_else(|| ProfileError::NotFound(input.id.to_string()))?;
+
+        Ok(ProfileExport {
+            profile,
+            exported_at: Utc::now(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::domain::{
+        object_values::{bio::Bio, email::Email, privacy_settings::PrivacySettings},
+        repositories::profile_repo::MockProfileRepository,
+    };
+
+    #[tokio::test]
+    async fn when_profile_not_found_should_return_not_found_error() {
+        let mut mock_repo = MockProfileRepository::new();
+
+        mock_repo
+            .expect_get_complete_profile_by_id()
+            .times(1)
+            .return_const(Ok(None));
+
+        let use_case = ExportProfileUseCase::new(Arc::new(mock_repo));
+
+        let input = GetProfileByIdInput::try_new(Uuid::now_v7().to_string()).unwrap();
+        let result = use_case.execute(input).await;
+
+        assert!(matches!(result, Err(ProfileError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn when_fields_are_private_should_still_export_them() {
+        let mut mock_repo = MockProfileRepository::new();
+
+        let input = GetProfileByIdInput::try_new(Uuid::now_v7().to_string()).unwrap();
+
+        let profile = Profile::from_parts(
+            input.id.clone(),
+            Email::try_from("john.doe@example.com").unwrap(),
+            None,
+            None,
+            Some(Bio::try_from("A bio that is long enough".to_string()).unwrap()),
+            None,
+            PrivacySettings::new(false, false, false, false),
+            Utc::now(),
+            None,
+            3,
+        );
+
+        mock_repo
+            .expect_get_complete_profile_by_id()
+            .times(1)
+            .return_const(Ok(Some(profile.clone())));
+
+        let use_case = ExportProfileUseCase::new(Arc::new(mock_repo));
+
+        let before = Utc::now();
+        let export = use_case.execute(input).await.unwrap();
+
+        assert_eq!(export.profile, profile);
+        assert!(export.profile.bio().is_some());
+        assert!(export.exported_at >= before);
+    }
+}
@@ -0,0 +1,82 @@
+use std::sync::Arc;
+
+use crate::{
+    application::dtos::get_profile_by_username_input::GetProfileByUsernameInput,
+    domain::{
+        models::profile::{Profile, ProfileError},
+        repositories::profile_repo::ProfileRepository,
+    },
+};
+
+#[derive(Clone)]
+pub struct GetProfileByUsernameUseCase<R: ProfileRepository + Send + Sync> {
+    repository: Arc<R>,
+}
+
+impl<R: ProfileRepository + Send + Sync> GetProfileByUsernameUseCase<R> {
+    pub fn new(repository: Arc<R>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, input: GetProfileByUsernameInput) -> Result<Profile, ProfileError> {
+        self.repository
+            .get_profile_by_username(&input.username)
+            .await?
+            .ok_or_else(|| ProfileError::NotFound(input.username.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        object_values::{email::Email, id::Id, username::Username},
+        repositories::profile_repo::MockProfileRepository,
+    };
+
+    #[tokio::test]
+    async fn when_username_is_held_should_return_its_profile() {
+        let mut mock_repo = MockProfileRepository::new();
+
+        mock_repo
+            .expect_get_profile_by_username()
+            .withf(|username| username.as_ref() == "john.doe")
+            .times(1)
+            .returning(|username| {
+                Ok(Some(
+                    Profile::new(
+                        Id::generate(),
+                        Email::try_from("john.doe@example.com").unwrap(),
+                    )
+                    .with_username(Some(username.clone()), None),
+                ))
+            });
+
+        let use_case = GetProfileByUsernameUseCase::new(Arc::new(mock_repo));
+
+        let input = GetProfileByUsernameInput::try_new("John.Doe".to_string()).unwrap();
+        let profile = use_case.execute(input).await.unwrap();
+
+        assert_eq!(
+            profile.username(),
+            Some(&Username::try_from("john.doe").unwrap())
+        );
+    }
+
+    #[tokio::test]
+    async fn when_username_is_free_should_return_not_found_error() {
+        let mut mock_repo = MockProfileRepository::new();
+
+        mock_repo
+            .expect_get_profile_by_username()
+            .times(1)
+            .returning(|_| Ok(None));
+
+        let use_case = GetProfileByUsernameUseCase::new(Arc::new(mock_repo));
+
+        let input = GetProfileByUsernameInput::try_new("john.doe".to_string()).unwrap();
+        let result = use_case.execute(input).await;
+
+        assert!(matches!(result, Err(ProfileError::NotFound(_))));
+    }
+}
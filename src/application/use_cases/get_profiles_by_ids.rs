@@ -0,0 +1,142 @@
+use std::sync::Arc;
+
+use crate::{
+    application::dtos::get_profiles_by_ids_input::GetProfilesByIdsInput,
+    domain::{
+        models::profile::{Profile, ProfileError},
+        object_values::id::Id,
+        repositories::profile_repo::ProfileRepository,
+    },
+};
+
+/// Result of a batch lookup: profiles in the order they were asked for, and
+/// the ids that do not exist.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProfilesByIds {
+    pub found: Vec<Profile>,
+    pub missing: Vec<Id>,
+}
+
+#[derive(Clone)]
+pub struct GetProfilesByIdsUseCase<R: ProfileRepository + Send + Sync> {
+    repository: Arc<R>,
+}
+
+impl<R: ProfileRepository + Send + Sync> GetProfilesByIdsUseCase<R> {
+    pub fn new(repository: Arc<R>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        input: GetProfilesByIdsInput,
+    ) -> Result<ProfilesByIds, ProfileError> {
+        if input.ids.is_empty() {
+            return Ok(ProfilesByIds {
+                found: Vec::new(),
+                missing: Vec::new(),
+            });
+        }
+
+        let mut profiles = self
+            .repository
+            .get_profiles_by_ids(&input.ids)
+            .await
+            .map_err(ProfileError::from)?;
+
+        let mut found = Vec::with_capacity(profiles.len());
+        let mut missing = Vec::new();
+
+        for id in input.ids {
+            match profiles.iter().position(|profile| profile.id() == &id) {
+                Some(index) => found.push(profiles.swap_remove(index)),
+                None => missing.push(id),
+            }
+        }
+
+        Ok(ProfilesByIds { found, missing })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fake::{Fake, faker::internet::en::FreeEmail};
+
+    use super::*;
+    use crate::domain::{
+        object_values::email::Email,
+        repositories::profile_repo::{MockProfileRepository, ProfileRepositoryError},
+    };
+
+    fn profile(id: &Id) -> Profile {
+        Profile::new(
+            id.clone(),
+            Email::try_from(FreeEmail().fake::<String>()).unwrap(),
+        )
+    }
+
+    #[tokio::test]
+    async fn when_some_profiles_exist_should_split_found_and_missing_in_request_order() {
+        let ids = vec![Id::generate(), Id::generate(), Id::generate()];
+        let mut mock_repo = MockProfileRepository::new();
+
+        // O banco devolve em ordem arbitrária
+        let stored = vec![profile(&ids[2]), profile(&ids[0])];
+        mock_repo
+            .expect_get_profiles_by_ids()
+            .times(1)
+            .return_const(Ok(stored));
+
+        let use_case = GetProfilesByIdsUseCase::new(Arc::new(mock_repo));
+
+        let result = use_case
+            .execute(GetProfilesByIdsInput { ids: ids.clone() })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result
+                .found
+                .iter()
+                .map(|p| p.id().clone())
+                .collect::<Vec<_>>(),
+            vec![ids[0].clone(), ids[2].clone()]
+        );
+        assert_eq!(result.missing, vec![ids[1].clone()]);
+    }
+
+    #[tokio::test]
+    async fn when_no_ids_are_given_should_not_query_repository() {
+        let mock_repo = MockProfileRepository::new();
+
+        let use_case = GetProfilesByIdsUseCase::new(Arc::new(mock_repo));
+
+        let result = use_case
+            .execute(GetProfilesByIdsInput { ids: Vec::new() })
+            .await
+            .unwrap();
+
+        assert!(result.found.is_empty());
+        assert!(result.missing.is_empty());
+    }
+
+    #[tokio::test]
+    async fn when_repository_error_should_return_repository_error() {
+        let mut mock_repo = MockProfileRepository::new();
+
+        mock_repo
+            .expect_get_profiles_by_ids()
+            .times(1)
+            .return_const(Err(ProfileRepositoryError::Unknown("mock error".into())));
+
+        let use_case = GetProfilesByIdsUseCase::new(Arc::new(mock_repo));
+
+        let result = use_case
+            .execute(GetProfilesByIdsInput {
+                ids: vec![Id::generate()],
+            })
+            .await;
+
+        assert!(matches!(result, Err(ProfileError::Unknown(_))));
+    }
+}
@@ -1,3 +1,12 @@
+pub mod bulk_create_profiles;
+pub mod bulk_update_profiles;
+pub mod change_username;
 pub mod create_profile;
+pub mod delete_profile;
+pub mod export_profile;
 pub mod get_profile_by_id;
+pub mod get_profile_by_username;
+pub mod get_profiles_by_ids;
+pub mod update_display_name;
+pub mod update_privacy_settings;
 pub mod update_profile;
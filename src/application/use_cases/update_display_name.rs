@@ -0,0 +1,99 @@
+use std::sync::Arc;
+
+use crate::{
+    application::dtos::update_display_name_input::UpdateDisplayNameInput,
+    domain::{
+        models::profile::{Profile, ProfileError},
+        repositories::profile_repo::ProfileRepository,
+    },
+};
+
+#[derive(Clone)]
+pub struct UpdateDisplayNameUseCase<R: ProfileRepository + Send + Sync> {
+    repository: Arc<R>,
+}
+
+impl<R: ProfileRepository + Send + Sync> UpdateDisplayNameUseCase<R> {
+    pub fn new(repository: Arc<R>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, input: UpdateDisplayNameInput) -> Result<Profile, ProfileError> {
+        let mut profile = self
+            .repository
+            .get_profile_by_id(&input.id)
+            .await?
+            .ok_or_else(|| ProfileError::NotFound(input.id.to_string()))?;
+
+        profile.update_display_name(input.display_name, input.format);
+
+        self.repository.save(&profile).await?;
+
+        Ok(profile)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::domain::{
+        object_values::{display_name::DisplayNameFormat, email::Email},
+        repositories::profile_repo::MockProfileRepository,
+    };
+
+    #[tokio::test]
+    async fn when_profile_does_not_exist_should_return_not_found_error() {
+        let mut mock_repo = MockProfileRepository::new();
+
+        mock_repo
+            .expect_get_profile_by_id()
+            .times(1)
+            .return_const(Ok(None));
+        mock_repo.expect_save().never();
+
+        let use_case = UpdateDisplayNameUseCase::new(Arc::new(mock_repo));
+
+        let input =
+            UpdateDisplayNameInput::try_new(Uuid::now_v7().to_string(), None, None).unwrap();
+        let result = use_case.execute(input).await;
+
+        assert!(matches!(result, Err(ProfileError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn when_profile_exists_should_save_display_name_preferences() {
+        let mut mock_repo = MockProfileRepository::new();
+
+        mock_repo
+            .expect_get_profile_by_id()
+            .times(1)
+            .returning(|id| {
+                Ok(Some(Profile::new(
+                    id.clone(),
+                    Email::try_from("jdoe@example.com").unwrap(),
+                )))
+            });
+        mock_repo
+            .expect_save()
+            .withf(|profile| {
+                profile.display_name_format() == DisplayNameFormat::Initials
+                    && profile.display_name().as_ref() == "Johnny"
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let use_case = UpdateDisplayNameUseCase::new(Arc::new(mock_repo));
+
+        let input = UpdateDisplayNameInput::try_new(
+            Uuid::now_v7().to_string(),
+            Some("Johnny".into()),
+            Some("initials".into()),
+        )
+        .unwrap();
+        let profile = use_case.execute(input).await.unwrap();
+
+        assert_eq!(profile.version(), 2);
+    }
+}
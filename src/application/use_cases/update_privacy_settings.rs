@@ -0,0 +1,135 @@
+use std::sync::Arc;
+
+use crate::{
+    application::dtos::update_privacy_settings_input::UpdatePrivacySettingsInput,
+    domain::{
+        models::profile::{Profile, ProfileError},
+        repositories::profile_repo::ProfileRepository,
+    },
+};
+
+#[derive(Clone)]
+pub struct UpdatePrivacySettingsUseCase<R: ProfileRepository + Send + Sync> {
+    repository: Arc<R>,
+}
+
+impl<R: ProfileRepository + Send + Sync> UpdatePrivacySettingsUseCase<R> {
+    pub fn new(repository: Arc<R>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        input: UpdatePrivacySettingsInput,
+    ) -> Result<Profile, ProfileError> {
+        let mut profile = self
+            .repository
+            .get_profile_by_id(&input.id)
+            .await?
+            .ok_or_else(|| ProfileError::NotFound(input.id.to_string()))?;
+
+        let privacy_settings = profile.privacy_settings().merge(
+            input.first_name_visible,
+            input.last_name_visible,
+            input.bio_visible,
+            input.profile_image_url_visible,
+        );
+
+        profile.update_privacy_settings(privacy_settings);
+
+        self.repository.save(&profile).await?;
+
+        Ok(profile)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::domain::{
+        object_values::{email::Email, id::Id},
+        repositories::profile_repo::{MockProfileRepository, ProfileRepositoryError},
+    };
+
+    #[tokio::test]
+    async fn when_profile_does_not_exist_should_return_not_found_error() {
+        let mut mock_repo = MockProfileRepository::new();
+
+        mock_repo
+            .expect_get_profile_by_id()
+            .times(1)
+            .return_const(Ok(None));
+
+        let use_case = UpdatePrivacySettingsUseCase::new(Arc::new(mock_repo));
+
+        let input = UpdatePrivacySettingsInput::try_new(
+            Uuid::now_v7().to_string(),
+            Some(false),
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let result = use_case.execute(input).await;
+
+        assert!(matches!(result, Err(ProfileError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn when_profile_exists_should_update_only_provided_settings() {
+        let mut mock_repo = MockProfileRepository::new();
+
+        let id = Id::generate();
+        let profile = Profile::new(id.clone(), Email::try_from("jane@example.com").unwrap());
+
+        mock_repo
+            .expect_get_profile_by_id()
+            .times(1)
+            .return_const(Ok(Some(profile)));
+
+        mock_repo.expect_save().times(1).return_const(Ok(()));
+
+        let use_case = UpdatePrivacySettingsUseCase::new(Arc::new(mock_repo));
+
+        let input =
+            UpdatePrivacySettingsInput::try_new(id.to_string(), None, None, Some(false), None)
+                .unwrap();
+
+        let updated = use_case.execute(input).await.unwrap();
+
+        assert!(updated.privacy_settings().first_name_visible());
+        assert!(!updated.privacy_settings().bio_visible());
+        assert_eq!(updated.version(), 2);
+    }
+
+    #[tokio::test]
+    async fn when_save_fails_should_return_repository_error() {
+        let mut mock_repo = MockProfileRepository::new();
+
+        let id = Id::generate();
+        let profile = Profile::new(id.clone(), Email::try_from("jane@example.com").unwrap());
+
+        mock_repo
+            .expect_get_profile_by_id()
+            .times(1)
+            .return_const(Ok(Some(profile)));
+
+        mock_repo
+            .expect_save()
+            .times(1)
+            .return_const(Err(ProfileRepositoryError::VersionConflict(id.to_string())));
+
+        let use_case = UpdatePrivacySettingsUseCase::new(Arc::new(mock_repo));
+
+        let input =
+            UpdatePrivacySettingsInput::try_new(id.to_string(), Some(false), None, None, None)
+                .unwrap();
+
+        let result = use_case.execute(input).await;
+
+        assert!(matches!(result, Err(ProfileError::VersionConflict(_))));
+    }
+}
@@ -18,10 +18,7 @@ impl<R: ProfileRepository + Send + Sync> UpdateProfileUseCase<R> {
         Self { repository }
     }
 
-    pub async fn execute(
-        &self,
-        input: UpdateProfileInput,
-    ) -> Result<Option<Profile>, ProfileError> {
+    pub async fn execute(&self, input: UpdateProfileInput) -> Result<Profile, ProfileError> {
         let profile = self.repository.get_profile_by_id(&input.id).await?;
 
         let now = chrono::Utc::now();
@@ -42,7 +39,7 @@ impl<R: ProfileRepository + Send + Sync> UpdateProfileUseCase<R> {
 
             self.repository.save(&profile).await?;
 
-            Ok(Some(profile))
+            Ok(profile)
         } else {
             Err(ProfileError::NotFound(input.id.to_string()))
         }
@@ -59,7 +56,7 @@ mod tests {
     use crate::domain::{
         object_values::{
             bio::Bio, email::Email, first_name::FirstName, id::Id, image_url::ImageUrl,
-            last_name::LastName,
+            last_name::LastName, privacy_settings::PrivacySettings,
         },
         repositories::profile_repo::MockProfileRepository,
     };
@@ -77,6 +74,7 @@ mod tests {
             None,
             None,
             None,
+            PrivacySettings::default(),
             chrono::Utc::now(),
             Some(chrono::Utc::now() + chrono::Duration::hours(2)),
             2,
@@ -143,6 +141,7 @@ mod tests {
             Some(last_name),
             Some(bio),
             Some(profile_image_url),
+            PrivacySettings::default(),
             chrono::Utc::now() - chrono::Duration::hours(1),
             None,
             1,
@@ -170,7 +169,7 @@ mod tests {
 
         assert!(result.is_ok());
 
-        let updated_profile = result.unwrap().unwrap();
+        let updated_profile = result.unwrap();
 
         assert_eq!(
             updated_profile.first_name().map(|e| e.to_string()).unwrap(),
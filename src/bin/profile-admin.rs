@@ -0,0 +1,54 @@
+use std::{io, process::ExitCode, sync::Arc};
+
+use anyhow::Result;
+use clap::Parser;
+use dotenvy::dotenv;
+use profile_service::{
+    infrastructure::repositories::{
+        cached_profile_repo::CachedProfileRepository, mongo_profile_repo::MongoProfileRepository,
+        mongo_service::MongoService, redis_profile_cache::RedisProfileCache,
+    },
+    presentation::{
+        api::settings::{ProfileCacheBackend, ProfileCacheSettings, ProfileReadSettings},
+        cli::{self, Cli, Output},
+    },
+};
+
+#[tokio::main]
+async fn main() -> Result<ExitCode> {
+    dotenv().ok();
+
+    let cli = Cli::parse();
+
+    let mongo_service = Arc::new(MongoService::new().await?);
+    let repository =
+        MongoProfileRepository::new(mongo_service, ProfileReadSettings::from_env()?.policy);
+    repository.ensure_indexes().await?;
+    let cache_settings = ProfileCacheSettings::from_env()?;
+
+    let (mut stdout, mut stderr) = (io::stdout().lock(), io::stderr().lock());
+    let output = Output {
+        out: &mut stdout,
+        err: &mut stderr,
+    };
+
+    // Só o cache Redis é compartilhado com o serviço; o de memória vive
+    // dentro de cada processo e não precisa ser invalidado daqui
+    match cache_settings.backend {
+        ProfileCacheBackend::Redis { url } => {
+            let cache = Arc::new(RedisProfileCache::new(&url, cache_settings.ttl)?);
+            let cached = CachedProfileRepository::new(repository.clone(), cache);
+
+            cli::run(cli.command, Arc::new(cached), &repository, output).await
+        }
+        ProfileCacheBackend::Disabled | ProfileCacheBackend::Memory => {
+            cli::run(
+                cli.command,
+                Arc::new(repository.clone()),
+                &repository,
+                output,
+            )
+            .await
+        }
+    }
+}
@@ -3,6 +3,10 @@ use regex::Regex;
 
 lazy_static! {
     pub static ref VALID_CHARS_REGEX: Regex = Regex::new(r"^[\p{L}\s-]+$").unwrap();
-    pub static ref BIO_VALID_CHARS_REGEX: Regex = Regex::new(r#"^[\p{L}\p{N}\p{M}\s._\-,;:!?'""()]+$"#).unwrap();
+    pub static ref BIO_VALID_CHARS_REGEX: Regex =
+        Regex::new(r#"^[\p{L}\p{N}\p{M}\s._\-,;:!?'""()]+$"#).unwrap();
+    pub static ref DISPLAY_NAME_VALID_CHARS_REGEX: Regex =
+        Regex::new(r"^[\p{L}\p{N}\p{M} .'\-]+$").unwrap();
+    pub static ref USERNAME_VALID_CHARS_REGEX: Regex = Regex::new(r"^[a-z0-9_.]+$").unwrap();
     pub static ref EMAIL_REGEX: Regex = Regex::new(r"^[^\s@]+@[^\s@]+\.[^\s@]+$").unwrap();
 }
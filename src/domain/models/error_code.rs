@@ -0,0 +1,210 @@
+use serde::Serialize;
+use strum_macros::{AsRefStr, Display, EnumIter, EnumString};
+use utoipa::ToSchema;
+
+/// Stable, machine-readable identifiers returned in the `code` field of every
+/// error response. Clients key on these instead of the English `message`, so
+/// a code is never renamed or reused once published; new failures get new
+/// codes.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    ToSchema,
+    EnumString,
+    EnumIter,
+    Display,
+    AsRefStr,
+)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum ErrorCode {
+    // Perfil
+    ProfileAlreadyExists,
+    ProfileNotFound,
+    ProfileVersionConflict,
+    ProfileDataCorrupted,
+    InternalError,
+
+    // Identificador
+    IdInvalid,
+
+    // Email
+    EmailEmpty,
+    EmailTooLong,
+    EmailInvalid,
+
+    // Primeiro nome
+    FirstNameEmpty,
+    FirstNameTooShort,
+    FirstNameTooLong,
+    FirstNameInvalidCharacters,
+    FirstNameInvalidEdgeCharacters,
+
+    // Sobrenome
+    LastNameEmpty,
+    LastNameTooShort,
+    LastNameTooLong,
+    LastNameInvalidCharacters,
+    LastNameInvalidEdgeCharacters,
+
+    // Bio
+    BioEmpty,
+    BioTooShort,
+    BioTooLong,
+    BioInvalidCharacters,
+
+    // Nome de usuário
+    UsernameEmpty,
+    UsernameTooShort,
+    UsernameTooLong,
+    UsernameInvalidCharacters,
+    UsernameInvalidEdgeCharacters,
+    UsernameConfusable,
+    UsernameReserved,
+    UsernameTaken,
+    UsernameChangeTooSoon,
+
+    // Nome de exibição
+    DisplayNameEmpty,
+    DisplayNameTooLong,
+    DisplayNameInvalidCharacters,
+    DisplayNameInvalidFormat,
+
+    // Imagem de perfil
+    ImageUrlEmpty,
+    ImageUrlTooLong,
+    ImageUrlInvalid,
+    ImageUrlInvalidScheme,
+    ImageUrlInvalidExtension,
+
+    // Requisição
+    InvalidJson,
+    ValidationFailed,
+    FieldTooShort,
+    FieldTooLong,
+    FieldInvalidFormat,
+    FieldInvalid,
+    MissingPathParameter,
+    ClientAddressUnavailable,
+    RateLimited,
+    PayloadTooLarge,
+    ExportFormatUnsupported,
+
+    // Idempotência
+    IdempotencyKeyInvalid,
+    IdempotencyKeyReused,
+    IdempotencyRequestInProgress,
+
+    // Autenticação e autorização
+    TokenMissing,
+    TokenMalformed,
+    TokenExpired,
+    TokenNotYetValid,
+    TokenInvalidSignature,
+    TokenInvalidAudience,
+    TokenInvalid,
+    AccessDenied,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashSet, str::FromStr};
+
+    use strum::IntoEnumIterator;
+
+    use super::*;
+    use crate::domain::{
+        models::profile::ProfileError,
+        object_values::{
+            bio::Bio, email::Email, first_name::FirstName, id::Id, image_url::ImageUrl,
+            last_name::LastName,
+        },
+    };
+
+    #[test]
+    fn when_code_is_displayed_should_use_screaming_snake_case() {
+        assert_eq!(ErrorCode::ProfileNotFound.to_string(), "PROFILE_NOT_FOUND");
+        assert_eq!(ErrorCode::TokenExpired.as_ref(), "TOKEN_EXPIRED");
+        assert_eq!(
+            ErrorCode::FirstNameTooShort.to_string(),
+            "FIRST_NAME_TOO_SHORT"
+        );
+        assert_eq!(
+            serde_json::to_value(ErrorCode::ImageUrlInvalidScheme).unwrap(),
+            "IMAGE_URL_INVALID_SCHEME"
+        );
+    }
+
+    #[test]
+    fn when_catalogue_is_listed_should_have_unique_round_trippable_codes() {
+        let codes: Vec<String> = ErrorCode::iter().map(|code| code.to_string()).collect();
+        let unique: HashSet<&String> = codes.iter().collect();
+
+        assert_eq!(codes.len(), unique.len());
+
+        for code in &codes {
+            assert!(
+                code.chars()
+                    .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+            );
+            assert_eq!(ErrorCode::from_str(code).unwrap().to_string(), *code);
+        }
+    }
+
+    #[test]
+    fn when_value_object_is_invalid_should_carry_its_code_into_profile_error() {
+        let code = |error: ProfileError| error.code();
+
+        assert_eq!(
+            code(Id::try_from("nope".to_string()).unwrap_err().into()),
+            ErrorCode::IdInvalid
+        );
+        assert_eq!(
+            code(Email::try_from("nope".to_string()).unwrap_err().into()),
+            ErrorCode::EmailInvalid
+        );
+        assert_eq!(
+            code(FirstName::try_from("J".to_string()).unwrap_err().into()),
+            ErrorCode::FirstNameTooShort
+        );
+        assert_eq!(
+            code(LastName::try_from(" ".to_string()).unwrap_err().into()),
+            ErrorCode::LastNameEmpty
+        );
+        assert_eq!(
+            code(Bio::try_from("a".repeat(1000)).unwrap_err().into()),
+            ErrorCode::BioTooLong
+        );
+        assert_eq!(
+            code(
+                ImageUrl::try_from("ftp://example.com/a.png".to_string())
+                    .unwrap_err()
+                    .into()
+            ),
+            ErrorCode::ImageUrlInvalidScheme
+        );
+    }
+
+    #[test]
+    fn when_profile_error_is_not_field_related_should_map_to_profile_codes() {
+        assert_eq!(
+            ProfileError::NotFound("id".into()).code(),
+            ErrorCode::ProfileNotFound
+        );
+        assert_eq!(
+            ProfileError::VersionConflict("id".into()).code(),
+            ErrorCode::ProfileVersionConflict
+        );
+        assert_eq!(
+            ProfileError::Unknown("boom".into()).code(),
+            ErrorCode::InternalError
+        );
+    }
+}
@@ -0,0 +1,77 @@
+use std::{collections::BTreeMap, fmt::Display};
+
+use crate::domain::models::error_code::ErrorCode;
+
+/// A single invalid field, named as it appears in the API (`firstName`,
+/// `profileImageUrl`...), with the limits that were broken as params.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct FieldError {
+    pub field: String,
+    pub code: ErrorCode,
+    pub message: String,
+    pub params: BTreeMap<&'static str, usize>,
+}
+
+impl FieldError {
+    pub fn new(field: impl Into<String>, code: ErrorCode, message: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            code,
+            message: message.into(),
+            params: BTreeMap::new(),
+        }
+    }
+
+    pub fn with_param(mut self, name: &'static str, value: usize) -> Self {
+        self.params.insert(name, value);
+        self
+    }
+}
+
+impl Display for FieldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "'{}': '{}'", self.field, self.message)
+    }
+}
+
+/// Keeps the value when valid, otherwise records the error so every invalid
+/// field can be reported at once instead of stopping at the first one.
+pub fn collect_field<T, E>(result: Result<T, E>, errors: &mut Vec<FieldError>) -> Option<T>
+where
+    E: Into<FieldError>,
+{
+    result.map_err(|error| errors.push(error.into())).ok()
+}
+
+pub fn join_field_errors(errors: &[FieldError]) -> String {
+    errors
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn when_results_are_collected_should_keep_values_and_record_errors() {
+        let mut errors = Vec::new();
+
+        let valid = collect_field(Ok::<_, FieldError>(1), &mut errors);
+        let invalid = collect_field(
+            Err::<u8, _>(
+                FieldError::new("bio", ErrorCode::BioTooShort, "Bio is too short")
+                    .with_param("min", 10),
+            ),
+            &mut errors,
+        );
+
+        assert_eq!(valid, Some(1));
+        assert_eq!(invalid, None);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].params["min"], 10);
+        assert_eq!(join_field_errors(&errors), "'bio': 'Bio is too short'");
+    }
+}
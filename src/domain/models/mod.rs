@@ -1 +1,3 @@
+pub mod error_code;
+pub mod field_error;
 pub mod profile;
@@ -1,8 +1,22 @@
 use chrono::{DateTime, Utc};
 use thiserror::Error;
 
-use crate::domain::object_values::{
-    bio::Bio, email::Email, first_name::FirstName, id::Id, image_url::ImageUrl, last_name::LastName,
+use crate::domain::{
+    models::{
+        error_code::ErrorCode,
+        field_error::{FieldError, join_field_errors},
+    },
+    object_values::{
+        bio::Bio,
+        display_name::{DisplayName, DisplayNameFormat},
+        email::Email,
+        first_name::FirstName,
+        id::Id,
+        image_url::ImageUrl,
+        last_name::LastName,
+        privacy_settings::PrivacySettings,
+        username::Username,
+    },
 };
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -13,6 +27,12 @@ pub struct Profile {
     last_name: Option<LastName>,
     bio: Option<Bio>,
     profile_image_url: Option<ImageUrl>,
+    privacy_settings: PrivacySettings,
+    username: Option<Username>,
+    username_changed_at: Option<DateTime<Utc>>,
+    /// Chosen by the owner; replaces the one composed from the names.
+    display_name: Option<DisplayName>,
+    display_name_format: DisplayNameFormat,
     created_at: DateTime<Utc>,
     updated_at: Option<DateTime<Utc>>,
     version: u64,
@@ -26,16 +46,43 @@ pub enum ProfileError {
     #[error("Invalid profile data: {0}")]
     InvalidData(String),
 
+    #[error("Invalid profile data: {}", join_field_errors(.0))]
+    InvalidFields(Vec<FieldError>),
+
     #[error("Profile not found with id: {0}")]
     NotFound(String),
 
     #[error("Version conflict for profile with id: {0}")]
     VersionConflict(String),
 
+    #[error("Username {0} is already taken")]
+    UsernameTaken(String),
+
+    #[error("Username can be changed again at {}", .0.to_rfc3339())]
+    UsernameChangeTooSoon(DateTime<Utc>),
+
     #[error("Unknown error: {0}")]
     Unknown(String),
 }
 
+impl ProfileError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            ProfileError::AlreadyExists(_) => ErrorCode::ProfileAlreadyExists,
+            ProfileError::InvalidData(_) => ErrorCode::ProfileDataCorrupted,
+            ProfileError::InvalidFields(errors) => match errors.as_slice() {
+                [error] => error.code,
+                _ => ErrorCode::ValidationFailed,
+            },
+            ProfileError::NotFound(_) => ErrorCode::ProfileNotFound,
+            ProfileError::VersionConflict(_) => ErrorCode::ProfileVersionConflict,
+            ProfileError::UsernameTaken(_) => ErrorCode::UsernameTaken,
+            ProfileError::UsernameChangeTooSoon(_) => ErrorCode::UsernameChangeTooSoon,
+            ProfileError::Unknown(_) => ErrorCode::InternalError,
+        }
+    }
+}
+
 impl Profile {
     pub fn new(id: Id, email: Email) -> Self {
         let now = Utc::now();
@@ -47,12 +94,18 @@ impl Profile {
             last_name: None,
             bio: None,
             profile_image_url: None,
+            privacy_settings: PrivacySettings::default(),
+            username: None,
+            username_changed_at: None,
+            display_name: None,
+            display_name_format: DisplayNameFormat::default(),
             created_at: now,
             updated_at: None,
             version: 1,
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn from_parts(
         id: Id,
         email: Email,
@@ -60,6 +113,7 @@ impl Profile {
         last_name: Option<LastName>,
         bio: Option<Bio>,
         profile_image_url: Option<ImageUrl>,
+        privacy_settings: PrivacySettings,
         created_at: DateTime<Utc>,
         updated_at: Option<DateTime<Utc>>,
         version: u64,
@@ -71,12 +125,40 @@ impl Profile {
             last_name,
             bio,
             profile_image_url,
+            privacy_settings,
+            username: None,
+            username_changed_at: None,
+            display_name: None,
+            display_name_format: DisplayNameFormat::default(),
             created_at,
             updated_at,
             version,
         }
     }
 
+    /// Sets the handle of a profile rebuilt by `from_parts`, as stored.
+    pub fn with_username(
+        mut self,
+        username: Option<Username>,
+        changed_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        self.username = username;
+        self.username_changed_at = changed_at;
+        self
+    }
+
+    /// Sets the display name preferences of a profile rebuilt by
+    /// `from_parts`, as stored.
+    pub fn with_display_name(
+        mut self,
+        display_name: Option<DisplayName>,
+        format: DisplayNameFormat,
+    ) -> Self {
+        self.display_name = display_name;
+        self.display_name_format = format;
+        self
+    }
+
     pub fn id(&self) -> &Id {
         &self.id
     }
@@ -101,6 +183,39 @@ impl Profile {
         self.profile_image_url.as_ref()
     }
 
+    pub fn privacy_settings(&self) -> &PrivacySettings {
+        &self.privacy_settings
+    }
+
+    pub fn username(&self) -> Option<&Username> {
+        self.username.as_ref()
+    }
+
+    pub fn username_changed_at(&self) -> Option<DateTime<Utc>> {
+        self.username_changed_at
+    }
+
+    /// Name to show for the profile: the one chosen by the owner or, without
+    /// it, the names in the preferred format, falling back to the email.
+    pub fn display_name(&self) -> DisplayName {
+        self.display_name.clone().unwrap_or_else(|| {
+            DisplayName::compose(
+                self.display_name_format,
+                self.first_name.as_ref(),
+                self.last_name.as_ref(),
+                &self.email,
+            )
+        })
+    }
+
+    pub fn display_name_override(&self) -> Option<&DisplayName> {
+        self.display_name.as_ref()
+    }
+
+    pub fn display_name_format(&self) -> DisplayNameFormat {
+        self.display_name_format
+    }
+
     pub fn created_at(&self) -> DateTime<Utc> {
         self.created_at
     }
@@ -136,6 +251,76 @@ impl Profile {
         self.updated_at = Some(Utc::now());
         self.version += 1;
     }
+
+    pub fn update_privacy_settings(&mut self, privacy_settings: PrivacySettings) {
+        self.privacy_settings = privacy_settings;
+
+        self.updated_at = Some(Utc::now());
+        self.version += 1;
+    }
+
+    /// Replaces both preferences; `None` goes back to the composed name.
+    pub fn update_display_name(
+        &mut self,
+        display_name: Option<DisplayName>,
+        format: DisplayNameFormat,
+    ) {
+        self.display_name = display_name;
+        self.display_name_format = format;
+
+        self.updated_at = Some(Utc::now());
+        self.version += 1;
+    }
+
+    /// Replaces the handle and returns the one released, if any. Claiming the
+    /// first handle is always allowed; after that, a new change must wait
+    /// `change_interval` since the last one.
+    pub fn change_username(
+        &mut self,
+        username: Username,
+        change_interval: chrono::Duration,
+    ) -> Result<Option<Username>, ProfileError> {
+        let now = Utc::now();
+
+        if self.username.is_some()
+            && let Some(changed_at) = self.username_changed_at
+            && changed_at + change_interval > now
+        {
+            return Err(ProfileError::UsernameChangeTooSoon(
+                changed_at + change_interval,
+            ));
+        }
+
+        let released = self.username.replace(username);
+        self.username_changed_at = Some(now);
+
+        self.updated_at = Some(now);
+        self.version += 1;
+
+        Ok(released)
+    }
+
+    /// Returns the profile as seen by someone other than its owner, without
+    /// the fields the owner chose to hide.
+    pub fn public_view(&self) -> Self {
+        let settings = self.privacy_settings;
+        let mut profile = self.clone();
+
+        if !settings.first_name_visible() {
+            profile.first_name = None;
+        }
+        if !settings.last_name_visible() {
+            profile.last_name = None;
+        }
+        if !settings.bio_visible() {
+            profile.bio = None;
+        }
+        if !settings.profile_image_url_visible() {
+            profile.profile_image_url = None;
+        }
+
+        profile
+    }
 }
 
 #[cfg(test)]
@@ -225,4 +410,145 @@ mod tests {
         );
         assert_eq!(profile.version(), initial_version + 1);
     }
+
+    #[test]
+    fn when_update_privacy_settings_should_bump_version() {
+        let id = Id::try_from("123e4567-e89b-12d3-a456-426614174000".to_string()).unwrap();
+        let email = Email::try_from("test@example.com".to_string()).unwrap();
+
+        let mut profile = Profile::new(id, email);
+        let settings = PrivacySettings::new(false, true, false, true);
+
+        profile.update_privacy_settings(settings);
+
+        assert_eq!(profile.privacy_settings(), &settings);
+        assert_eq!(profile.version(), 2);
+        assert!(profile.updated_at().is_some());
+    }
+
+    #[test]
+    fn when_username_is_claimed_should_not_be_rate_limited() {
+        let id = Id::try_from("123e4567-e89b-12d3-a456-426614174000".to_string()).unwrap();
+        let email = Email::try_from("test@example.com".to_string()).unwrap();
+
+        let mut profile = Profile::new(id, email);
+
+        let released = profile
+            .change_username(Username::try_from("john.doe").unwrap(), Duration::days(30))
+            .unwrap();
+
+        assert_eq!(released, None);
+        assert_eq!(profile.username().unwrap().as_ref(), "john.doe");
+        assert!(profile.username_changed_at().is_some());
+        assert_eq!(profile.version(), 2);
+    }
+
+    #[test]
+    fn when_username_changed_recently_should_reject_rename() {
+        let id = Id::try_from("123e4567-e89b-12d3-a456-426614174000".to_string()).unwrap();
+        let email = Email::try_from("test@example.com".to_string()).unwrap();
+        let changed_at = Utc::now() - Duration::days(10);
+
+        let mut profile = Profile::new(id, email).with_username(
+            Some(Username::try_from("john.doe").unwrap()),
+            Some(changed_at),
+        );
+
+        let result =
+            profile.change_username(Username::try_from("johnny").unwrap(), Duration::days(30));
+
+        assert_eq!(
+            result,
+            Err(ProfileError::UsernameChangeTooSoon(
+                changed_at + Duration::days(30)
+            ))
+        );
+        assert_eq!(profile.username().unwrap().as_ref(), "john.doe");
+        assert_eq!(profile.version(), 1);
+    }
+
+    #[test]
+    fn when_username_changed_long_ago_should_release_previous() {
+        let id = Id::try_from("123e4567-e89b-12d3-a456-426614174000".to_string()).unwrap();
+        let email = Email::try_from("test@example.com".to_string()).unwrap();
+
+        let mut profile = Profile::new(id, email).with_username(
+            Some(Username::try_from("john.doe").unwrap()),
+            Some(Utc::now() - Duration::days(31)),
+        );
+
+        let released = profile
+            .change_username(Username::try_from("johnny").unwrap(), Duration::days(30))
+            .unwrap();
+
+        assert_eq!(released, Some(Username::try_from("john.doe").unwrap()));
+        assert_eq!(profile.username().unwrap().as_ref(), "johnny");
+    }
+
+    #[test]
+    fn when_public_view_should_hide_fields_not_visible() {
+        let id = Id::try_from("123e4567-e89b-12d3-a456-426614174000".to_string()).unwrap();
+        let email = Email::try_from("test@example.com".to_string()).unwrap();
+
+        let mut profile = Profile::new(id, email);
+        profile.update_profile(
+            Some(FirstName::try_from("John".to_string()).unwrap()),
+            Some(LastName::try_from("Doe".to_string()).unwrap()),
+            Some(Bio::try_from("A simple bio".to_string()).unwrap()),
+            Some(ImageUrl::try_from("https://example.com/image.jpg".to_string()).unwrap()),
+        );
+        profile.update_privacy_settings(PrivacySettings::new(true, false, false, true));
+
+        let public = profile.public_view();
+
+        assert_eq!(public.first_name(), profile.first_name());
+        assert_eq!(public.last_name(), None);
+        assert_eq!(public.bio(), None);
+        assert_eq!(public.profile_image_url(), profile.profile_image_url());
+        assert_eq!(public.email(), profile.email());
+    }
+
+    #[test]
+    fn when_names_are_hidden_public_display_name_should_not_reveal_them() {
+        let id = Id::try_from("123e4567-e89b-12d3-a456-426614174000".to_string()).unwrap();
+        let email = Email::try_from("jdoe@example.com".to_string()).unwrap();
+
+        let mut profile = Profile::new(id, email);
+        profile.update_profile(
+            Some(FirstName::try_from("John".to_string()).unwrap()),
+            Some(LastName::try_from("Doe".to_string()).unwrap()),
+            None,
+            None,
+        );
+        profile.update_display_name(None, DisplayNameFormat::FamilyGiven);
+
+        assert_eq!(profile.display_name().as_ref(), "Doe John");
+
+        profile.update_privacy_settings(PrivacySettings::new(true, false, true, true));
+        assert_eq!(profile.public_view().display_name().as_ref(), "John");
+
+        profile.update_privacy_settings(PrivacySettings::new(false, false, true, true));
+        assert_eq!(profile.public_view().display_name().as_ref(), "jdoe");
+    }
+
+    #[test]
+    fn when_display_name_is_chosen_should_override_composed_one() {
+        let id = Id::try_from("123e4567-e89b-12d3-a456-426614174000".to_string()).unwrap();
+        let email = Email::try_from("jdoe@example.com".to_string()).unwrap();
+
+        let mut profile = Profile::new(id, email);
+        let version = profile.version();
+
+        profile.update_display_name(
+            Some(DisplayName::try_from("Johnny D").unwrap()),
+            DisplayNameFormat::Initials,
+        );
+
+        assert_eq!(profile.display_name().as_ref(), "Johnny D");
+        assert_eq!(profile.public_view().display_name().as_ref(), "Johnny D");
+        assert_eq!(profile.version(), version + 1);
+
+        profile.update_display_name(None, DisplayNameFormat::Initials);
+        assert_eq!(profile.display_name().as_ref(), "jdoe");
+    }
 }
@@ -2,7 +2,10 @@ use std::fmt::Display;
 
 use thiserror::Error;
 
-use crate::domain::{helpers::BIO_VALID_CHARS_REGEX, models::profile::ProfileError};
+use crate::domain::{
+    helpers::BIO_VALID_CHARS_REGEX,
+    models::{error_code::ErrorCode, field_error::FieldError, profile::ProfileError},
+};
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Bio(String);
@@ -24,6 +27,17 @@ pub enum BioError {
     InvalidCharacters,
 }
 
+impl BioError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            BioError::Empty => ErrorCode::BioEmpty,
+            BioError::TooShort(_) => ErrorCode::BioTooShort,
+            BioError::TooLong(_) => ErrorCode::BioTooLong,
+            BioError::InvalidCharacters => ErrorCode::BioInvalidCharacters,
+        }
+    }
+}
+
 impl Bio {
     const MIN_LENGTH: usize = 10;
     const MAX_LENGTH: usize = 160;
@@ -81,9 +95,21 @@ impl TryFrom<String> for Bio {
     }
 }
 
+impl From<BioError> for FieldError {
+    fn from(error: BioError) -> Self {
+        let field_error = FieldError::new("bio", error.code(), error.to_string());
+
+        match error {
+            BioError::TooShort(min) => field_error.with_param("min", min),
+            BioError::TooLong(max) => field_error.with_param("max", max),
+            _ => field_error,
+        }
+    }
+}
+
 impl From<BioError> for ProfileError {
     fn from(error: BioError) -> Self {
-        ProfileError::InvalidData(error.to_string())
+        ProfileError::InvalidFields(vec![error.into()])
     }
 }
 
@@ -123,6 +149,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::to_string_in_format_args)]
     fn when_bio_valid_should_create_bio() {
         let bio_str = "This is a valid bio.";
         let bio = Bio::try_new(bio_str.to_string()).unwrap();
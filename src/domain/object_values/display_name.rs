@@ -0,0 +1,286 @@
+use std::fmt::Display;
+
+use strum_macros::{AsRefStr, Display as StrumDisplay, EnumString};
+use thiserror::Error;
+
+use crate::domain::{
+    helpers::DISPLAY_NAME_VALID_CHARS_REGEX,
+    models::{error_code::ErrorCode, field_error::FieldError, profile::ProfileError},
+    object_values::{email::Email, first_name::FirstName, last_name::LastName},
+};
+
+/// Name shown for a profile: the one its owner chose, or one composed from
+/// the first and last names.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DisplayName(String);
+
+/// Order in which the names are joined when no display name was chosen.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    AsRefStr,
+    StrumDisplay,
+    EnumString,
+)]
+#[strum(serialize_all = "snake_case")]
+pub enum DisplayNameFormat {
+    /// `John Doe`
+    #[default]
+    GivenFamily,
+    /// `Doe John`, for locales that put the family name first
+    FamilyGiven,
+    /// `J. D.`
+    Initials,
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Error)]
+pub enum DisplayNameError {
+    #[error("Display name cannot be empty")]
+    Empty,
+
+    #[error("Display name is too long (maximum {0} characters)")]
+    TooLong(usize),
+
+    #[error(
+        "Display name contains invalid characters (only letters, numbers, spaces, dots, apostrophes, and hyphens are allowed)"
+    )]
+    InvalidCharacters,
+
+    #[error("Display name format must be one of given_family, family_given, or initials")]
+    InvalidFormat,
+}
+
+impl DisplayNameError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            DisplayNameError::Empty => ErrorCode::DisplayNameEmpty,
+            DisplayNameError::TooLong(_) => ErrorCode::DisplayNameTooLong,
+            DisplayNameError::InvalidCharacters => ErrorCode::DisplayNameInvalidCharacters,
+            DisplayNameError::InvalidFormat => ErrorCode::DisplayNameInvalidFormat,
+        }
+    }
+}
+
+impl DisplayName {
+    const MAX_LENGTH: usize = 50;
+
+    /// Validates a name chosen by the owner. Runs of whitespace collapse
+    /// into a single space.
+    pub fn try_new(value: String) -> Result<Self, DisplayNameError> {
+        let collapsed = value.split_whitespace().collect::<Vec<_>>().join(" ");
+
+        if collapsed.is_empty() {
+            return Err(DisplayNameError::Empty);
+        }
+
+        if collapsed.len() > Self::MAX_LENGTH {
+            return Err(DisplayNameError::TooLong(Self::MAX_LENGTH));
+        }
+
+        if !DISPLAY_NAME_VALID_CHARS_REGEX.is_match(&collapsed) {
+            return Err(DisplayNameError::InvalidCharacters);
+        }
+
+        Ok(Self(collapsed))
+    }
+
+    /// Joins the names in `format`. With neither name, falls back to the
+    /// local part of `email`.
+    pub fn compose(
+        format: DisplayNameFormat,
+        first_name: Option<&FirstName>,
+        last_name: Option<&LastName>,
+        email: &Email,
+    ) -> Self {
+        let given = first_name.map(FirstName::as_ref);
+        let family = last_name.map(LastName::as_ref);
+
+        if given.is_none() && family.is_none() {
+            return Self(email.local_part().to_string());
+        }
+
+        let names = match format {
+            DisplayNameFormat::GivenFamily | DisplayNameFormat::Initials => [given, family],
+            DisplayNameFormat::FamilyGiven => [family, given],
+        };
+        let names = names.into_iter().flatten();
+
+        let composed = if format == DisplayNameFormat::Initials {
+            names
+                .flat_map(|name| name.split([' ', '-']))
+                .filter_map(|part| part.chars().next())
+                .map(|initial| format!("{}.", initial.to_uppercase()))
+                .collect::<Vec<_>>()
+                .join(" ")
+        } else {
+            names.collect::<Vec<_>>().join(" ")
+        };
+
+        Self(composed)
+    }
+
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl DisplayNameFormat {
+    pub fn try_new(value: &str) -> Result<Self, DisplayNameError> {
+        value
+            .trim()
+            .to_lowercase()
+            .parse()
+            .map_err(|_| DisplayNameError::InvalidFormat)
+    }
+}
+
+impl AsRef<str> for DisplayName {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for DisplayName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl TryFrom<&str> for DisplayName {
+    type Error = DisplayNameError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::try_new(value.to_string())
+    }
+}
+
+impl TryFrom<String> for DisplayName {
+    type Error = DisplayNameError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_new(value)
+    }
+}
+
+impl From<DisplayNameError> for FieldError {
+    fn from(error: DisplayNameError) -> Self {
+        let field = match error {
+            DisplayNameError::InvalidFormat => "displayNameFormat",
+            _ => "displayName",
+        };
+        let field_error = FieldError::new(field, error.code(), error.to_string());
+
+        match error {
+            DisplayNameError::TooLong(max) => field_error.with_param("max", max),
+            _ => field_error,
+        }
+    }
+}
+
+impl From<DisplayNameError> for ProfileError {
+    fn from(error: DisplayNameError) -> Self {
+        ProfileError::InvalidFields(vec![error.into()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn compose(format: DisplayNameFormat, first: Option<&str>, last: Option<&str>) -> String {
+        DisplayName::compose(
+            format,
+            first
+                .map(|f| FirstName::try_from(f.to_string()).unwrap())
+                .as_ref(),
+            last.map(|l| LastName::try_from(l.to_string()).unwrap())
+                .as_ref(),
+            &Email::try_from("john.doe+news@example.com").unwrap(),
+        )
+        .into_inner()
+    }
+
+    #[test]
+    fn when_display_name_is_valid_should_collapse_whitespace() {
+        let name = DisplayName::try_new("  Johnny   D'Oe-Smith ".to_string()).unwrap();
+        assert_eq!(name.as_ref(), "Johnny D'Oe-Smith");
+    }
+
+    #[test]
+    fn when_display_name_empty_should_empty_error() {
+        let name = DisplayName::try_new("   ".to_string());
+        assert!(matches!(name, Err(DisplayNameError::Empty)));
+    }
+
+    #[test]
+    fn when_display_name_too_long_should_max_length_error() {
+        let name = DisplayName::try_new("a".repeat(51));
+        assert!(matches!(name, Err(DisplayNameError::TooLong(50))));
+    }
+
+    #[test]
+    fn when_display_name_have_invalid_characters_should_invalid_characters_error() {
+        let name = DisplayName::try_new("John <script>".to_string());
+        assert!(matches!(name, Err(DisplayNameError::InvalidCharacters)));
+    }
+
+    #[test]
+    fn when_composing_should_follow_format() {
+        let (first, last) = (Some("Mary Ann"), Some("Smith-Jones"));
+
+        assert_eq!(
+            compose(DisplayNameFormat::GivenFamily, first, last),
+            "Mary Ann Smith-Jones"
+        );
+        assert_eq!(
+            compose(DisplayNameFormat::FamilyGiven, first, last),
+            "Smith-Jones Mary Ann"
+        );
+        assert_eq!(
+            compose(DisplayNameFormat::Initials, first, last),
+            "M. A. S. J."
+        );
+    }
+
+    #[test]
+    fn when_only_one_name_is_known_should_use_it_alone() {
+        assert_eq!(
+            compose(DisplayNameFormat::FamilyGiven, Some("John"), None),
+            "John"
+        );
+        assert_eq!(
+            compose(DisplayNameFormat::Initials, None, Some("Doe")),
+            "D."
+        );
+    }
+
+    #[test]
+    fn when_no_name_is_known_should_fall_back_to_email_local_part() {
+        for format in [
+            DisplayNameFormat::GivenFamily,
+            DisplayNameFormat::FamilyGiven,
+            DisplayNameFormat::Initials,
+        ] {
+            assert_eq!(compose(format, None, None), "john.doe");
+        }
+    }
+
+    #[test]
+    fn when_format_is_known_should_parse_it_case_insensitively() {
+        assert_eq!(
+            DisplayNameFormat::try_new(" Family_Given "),
+            Ok(DisplayNameFormat::FamilyGiven)
+        );
+        assert_eq!(
+            DisplayNameFormat::try_new("surname_first"),
+            Err(DisplayNameError::InvalidFormat)
+        );
+    }
+}
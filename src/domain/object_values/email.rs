@@ -2,7 +2,10 @@ use std::fmt::Display;
 
 use thiserror::Error;
 
-use crate::domain::{helpers::EMAIL_REGEX, models::profile::ProfileError};
+use crate::domain::{
+    helpers::EMAIL_REGEX,
+    models::{error_code::ErrorCode, field_error::FieldError, profile::ProfileError},
+};
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Email(String);
@@ -10,15 +13,25 @@ pub struct Email(String);
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Error)]
 pub enum EmailError {
     #[error("Email cannot be empty")]
-    Emtpy,
+    Empty,
 
-    #[error("Email is too long (maximum {0} characters")]
+    #[error("Email is too long (maximum {0} characters)")]
     TooLong(usize),
 
-    #[error("Invalid email format (e.g., user@example.com")]
+    #[error("Invalid email format (e.g., user@example.com)")]
     Invalid,
 }
 
+impl EmailError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            EmailError::Empty => ErrorCode::EmailEmpty,
+            EmailError::TooLong(_) => ErrorCode::EmailTooLong,
+            EmailError::Invalid => ErrorCode::EmailInvalid,
+        }
+    }
+}
+
 impl Email {
     const MAX_LENGTH: usize = 255;
 
@@ -26,7 +39,7 @@ impl Email {
         let trimmed = email.trim();
 
         if trimmed.is_empty() {
-            return Err(EmailError::Emtpy);
+            return Err(EmailError::Empty);
         }
 
         if trimmed.len() > Self::MAX_LENGTH {
@@ -44,6 +57,12 @@ impl Email {
         EMAIL_REGEX.is_match(email)
     }
 
+    /// Part before the `@`, without a `+tag` suffix.
+    pub fn local_part(&self) -> &str {
+        let local = self.0.split('@').next().unwrap_or_default();
+        local.split('+').next().unwrap_or(local)
+    }
+
     pub fn into_inner(self) -> String {
         self.0
     }
@@ -75,9 +94,20 @@ impl TryFrom<&str> for Email {
     }
 }
 
+impl From<EmailError> for FieldError {
+    fn from(error: EmailError) -> Self {
+        let field_error = FieldError::new("email", error.code(), error.to_string());
+
+        match error {
+            EmailError::TooLong(max) => field_error.with_param("max", max),
+            _ => field_error,
+        }
+    }
+}
+
 impl From<EmailError> for ProfileError {
     fn from(error: EmailError) -> Self {
-        ProfileError::InvalidData(error.to_string())
+        ProfileError::InvalidFields(vec![error.into()])
     }
 }
 
@@ -88,7 +118,7 @@ mod tests {
     #[test]
     fn when_email_empty_should_return_empty_error() {
         let email = Email::try_new("".to_string());
-        assert!(matches!(email, Err(EmailError::Emtpy)));
+        assert!(matches!(email, Err(EmailError::Empty)));
     }
 
     #[test]
@@ -2,24 +2,27 @@ use std::fmt::Display;
 
 use thiserror::Error;
 
-use crate::domain::{helpers::VALID_CHARS_REGEX, models::profile::ProfileError};
+use crate::domain::{
+    helpers::VALID_CHARS_REGEX,
+    models::{error_code::ErrorCode, field_error::FieldError, profile::ProfileError},
+};
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct FirstName(String);
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Error)]
 pub enum FirstNameError {
-    #[error("First name cannot be emtpy")]
+    #[error("First name cannot be empty")]
     Empty,
 
-    #[error("First name is too long(maximum {0} characters)")]
-    ToLong(usize),
+    #[error("First name is too long (maximum {0} characters)")]
+    TooLong(usize),
 
-    #[error("Frist name is too short(minimum {0} characters)")]
-    ToShort(usize),
+    #[error("First name is too short (minimum {0} characters)")]
+    TooShort(usize),
 
     #[error(
-        "First name contains invalid characters (only letters, numbers, underscores, and dots are allowed"
+        "First name contains invalid characters (only letters, spaces, and hyphens are allowed)"
     )]
     InvalidCharacters,
 
@@ -27,6 +30,18 @@ pub enum FirstNameError {
     InvalidEdgeCharacters,
 }
 
+impl FirstNameError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            FirstNameError::Empty => ErrorCode::FirstNameEmpty,
+            FirstNameError::TooShort(_) => ErrorCode::FirstNameTooShort,
+            FirstNameError::TooLong(_) => ErrorCode::FirstNameTooLong,
+            FirstNameError::InvalidCharacters => ErrorCode::FirstNameInvalidCharacters,
+            FirstNameError::InvalidEdgeCharacters => ErrorCode::FirstNameInvalidEdgeCharacters,
+        }
+    }
+}
+
 impl FirstName {
     const MIN_LENGTH: usize = 2;
     const MAX_LENGTH: usize = 15;
@@ -39,11 +54,11 @@ impl FirstName {
         }
 
         if trimmed.len() < Self::MIN_LENGTH {
-            return Err(FirstNameError::ToShort(Self::MIN_LENGTH));
+            return Err(FirstNameError::TooShort(Self::MIN_LENGTH));
         }
 
         if trimmed.len() > Self::MAX_LENGTH {
-            return Err(FirstNameError::ToLong(Self::MAX_LENGTH));
+            return Err(FirstNameError::TooLong(Self::MAX_LENGTH));
         }
 
         if trimmed.starts_with(|c: char| !c.is_alphanumeric())
@@ -90,9 +105,21 @@ impl TryFrom<String> for FirstName {
     }
 }
 
+impl From<FirstNameError> for FieldError {
+    fn from(error: FirstNameError) -> Self {
+        let field_error = FieldError::new("firstName", error.code(), error.to_string());
+
+        match error {
+            FirstNameError::TooShort(min) => field_error.with_param("min", min),
+            FirstNameError::TooLong(max) => field_error.with_param("max", max),
+            _ => field_error,
+        }
+    }
+}
+
 impl From<FirstNameError> for ProfileError {
     fn from(error: FirstNameError) -> Self {
-        ProfileError::InvalidData(error.to_string())
+        ProfileError::InvalidFields(vec![error.into()])
     }
 }
 
@@ -115,7 +142,7 @@ mod tests {
     #[test]
     fn when_first_name_too_short_should_min_length_error() {
         let first_name = FirstName::try_new("a".to_string());
-        assert!(matches!(first_name, Err(FirstNameError::ToShort(_))));
+        assert!(matches!(first_name, Err(FirstNameError::TooShort(_))));
     }
 
     #[test]
@@ -123,7 +150,7 @@ mod tests {
         let first_name = FirstName::try_new(
             "Hello, I am a long first name for this history, because I am so so".to_string(),
         );
-        assert!(matches!(first_name, Err(FirstNameError::ToLong(_))));
+        assert!(matches!(first_name, Err(FirstNameError::TooLong(_))));
     }
 
     #[test]
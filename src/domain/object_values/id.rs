@@ -3,7 +3,9 @@ use std::fmt::Display;
 use thiserror::Error;
 use uuid::Uuid;
 
-use crate::domain::models::profile::ProfileError;
+use crate::domain::models::{
+    error_code::ErrorCode, field_error::FieldError, profile::ProfileError,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Id(Uuid);
@@ -14,6 +16,14 @@ pub enum IdError {
     Invalid(String),
 }
 
+impl IdError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            IdError::Invalid(_) => ErrorCode::IdInvalid,
+        }
+    }
+}
+
 impl Id {
     pub fn from_str(id_str: &str) -> Result<Self, IdError> {
         Uuid::parse_str(id_str)
@@ -54,9 +64,15 @@ impl TryFrom<String> for Id {
     }
 }
 
+impl From<IdError> for FieldError {
+    fn from(error: IdError) -> Self {
+        FieldError::new("id", error.code(), error.to_string())
+    }
+}
+
 impl From<IdError> for ProfileError {
     fn from(error: IdError) -> Self {
-        ProfileError::InvalidData(error.to_string())
+        ProfileError::InvalidFields(vec![error.into()])
     }
 }
 
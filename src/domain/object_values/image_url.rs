@@ -2,7 +2,9 @@ use std::fmt::Display;
 
 use thiserror::Error;
 
-use crate::domain::models::profile::ProfileError;
+use crate::domain::models::{
+    error_code::ErrorCode, field_error::FieldError, profile::ProfileError,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct ImageUrl(String);
@@ -25,6 +27,18 @@ pub enum ImageUrlError {
     TooLong(usize),
 }
 
+impl ImageUrlError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            ImageUrlError::InvalidUrl => ErrorCode::ImageUrlInvalid,
+            ImageUrlError::InvalidScheme => ErrorCode::ImageUrlInvalidScheme,
+            ImageUrlError::InvalidExtension => ErrorCode::ImageUrlInvalidExtension,
+            ImageUrlError::Empty => ErrorCode::ImageUrlEmpty,
+            ImageUrlError::TooLong(_) => ErrorCode::ImageUrlTooLong,
+        }
+    }
+}
+
 impl ImageUrl {
     const MAX_LENGTH: usize = 2048;
 
@@ -82,9 +96,20 @@ impl TryFrom<&str> for ImageUrl {
     }
 }
 
+impl From<ImageUrlError> for FieldError {
+    fn from(error: ImageUrlError) -> Self {
+        let field_error = FieldError::new("profileImageUrl", error.code(), error.to_string());
+
+        match error {
+            ImageUrlError::TooLong(max) => field_error.with_param("max", max),
+            _ => field_error,
+        }
+    }
+}
+
 impl From<ImageUrlError> for ProfileError {
     fn from(error: ImageUrlError) -> Self {
-        ProfileError::InvalidData(error.to_string())
+        ProfileError::InvalidFields(vec![error.into()])
     }
 }
 
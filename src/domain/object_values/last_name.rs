@@ -2,24 +2,27 @@ use std::fmt::Display;
 
 use thiserror::Error;
 
-use crate::domain::{helpers::VALID_CHARS_REGEX, models::profile::ProfileError};
+use crate::domain::{
+    helpers::VALID_CHARS_REGEX,
+    models::{error_code::ErrorCode, field_error::FieldError, profile::ProfileError},
+};
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct LastName(String);
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Error)]
 pub enum LastNameError {
-    #[error("Last name cannot be emtpy")]
+    #[error("Last name cannot be empty")]
     Empty,
 
-    #[error("Last name is too long(maximum {0} characters)")]
-    ToLong(usize),
+    #[error("Last name is too long (maximum {0} characters)")]
+    TooLong(usize),
 
-    #[error("Last name is too short(minimum {0} characters)")]
-    ToShort(usize),
+    #[error("Last name is too short (minimum {0} characters)")]
+    TooShort(usize),
 
     #[error(
-        "Last name contains invalid characters (only letters, numbers, underscores, and dots are allowed"
+        "Last name contains invalid characters (only letters, spaces, and hyphens are allowed)"
     )]
     InvalidCharacters,
 
@@ -27,6 +30,18 @@ pub enum LastNameError {
     InvalidEdgeCharacters,
 }
 
+impl LastNameError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            LastNameError::Empty => ErrorCode::LastNameEmpty,
+            LastNameError::TooShort(_) => ErrorCode::LastNameTooShort,
+            LastNameError::TooLong(_) => ErrorCode::LastNameTooLong,
+            LastNameError::InvalidCharacters => ErrorCode::LastNameInvalidCharacters,
+            LastNameError::InvalidEdgeCharacters => ErrorCode::LastNameInvalidEdgeCharacters,
+        }
+    }
+}
+
 impl LastName {
     const MIN_LENGTH: usize = 2;
     const MAX_LENGTH: usize = 25;
@@ -39,11 +54,11 @@ impl LastName {
         }
 
         if trimmed.len() < Self::MIN_LENGTH {
-            return Err(LastNameError::ToShort(Self::MIN_LENGTH));
+            return Err(LastNameError::TooShort(Self::MIN_LENGTH));
         }
 
         if trimmed.len() > Self::MAX_LENGTH {
-            return Err(LastNameError::ToLong(Self::MAX_LENGTH));
+            return Err(LastNameError::TooLong(Self::MAX_LENGTH));
         }
 
         if trimmed.starts_with(|c: char| !c.is_alphanumeric())
@@ -90,9 +105,21 @@ impl TryFrom<String> for LastName {
     }
 }
 
+impl From<LastNameError> for FieldError {
+    fn from(error: LastNameError) -> Self {
+        let field_error = FieldError::new("lastName", error.code(), error.to_string());
+
+        match error {
+            LastNameError::TooShort(min) => field_error.with_param("min", min),
+            LastNameError::TooLong(max) => field_error.with_param("max", max),
+            _ => field_error,
+        }
+    }
+}
+
 impl From<LastNameError> for ProfileError {
     fn from(error: LastNameError) -> Self {
-        ProfileError::InvalidData(error.to_string())
+        ProfileError::InvalidFields(vec![error.into()])
     }
 }
 
@@ -115,14 +142,14 @@ mod tests {
     #[test]
     fn when_last_name_to_short_should_min_length_error() {
         let last_name = LastName::try_new("a".to_string());
-        assert!(matches!(last_name, Err(LastNameError::ToShort(_))));
+        assert!(matches!(last_name, Err(LastNameError::TooShort(_))));
     }
 
     #[test]
     fn when_last_name_to_long_should_max_length_error() {
         let last_name =
             LastName::try_new("jfi fuodf oufdif oufsli fidojfo fios ufdsoufo".to_string());
-        assert!(matches!(last_name, Err(LastNameError::ToLong(_))));
+        assert!(matches!(last_name, Err(LastNameError::TooLong(_))));
     }
 
     #[test]
@@ -1,6 +1,12 @@
+// Os objetos de valor expõem `as_ref` e `from_str` como métodos próprios
+#![allow(clippy::should_implement_trait)]
+
 pub mod bio;
+pub mod display_name;
 pub mod email;
 pub mod first_name;
 pub mod id;
 pub mod image_url;
 pub mod last_name;
+pub mod privacy_settings;
+pub mod username;
@@ -0,0 +1,86 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PrivacySettings {
+    first_name_visible: bool,
+    last_name_visible: bool,
+    bio_visible: bool,
+    profile_image_url_visible: bool,
+}
+
+impl PrivacySettings {
+    pub fn new(
+        first_name_visible: bool,
+        last_name_visible: bool,
+        bio_visible: bool,
+        profile_image_url_visible: bool,
+    ) -> Self {
+        Self {
+            first_name_visible,
+            last_name_visible,
+            bio_visible,
+            profile_image_url_visible,
+        }
+    }
+
+    pub fn first_name_visible(&self) -> bool {
+        self.first_name_visible
+    }
+
+    pub fn last_name_visible(&self) -> bool {
+        self.last_name_visible
+    }
+
+    pub fn bio_visible(&self) -> bool {
+        self.bio_visible
+    }
+
+    pub fn profile_image_url_visible(&self) -> bool {
+        self.profile_image_url_visible
+    }
+
+    /// Applies a partial change, keeping the current value for every `None`.
+    pub fn merge(
+        &self,
+        first_name_visible: Option<bool>,
+        last_name_visible: Option<bool>,
+        bio_visible: Option<bool>,
+        profile_image_url_visible: Option<bool>,
+    ) -> Self {
+        Self {
+            first_name_visible: first_name_visible.unwrap_or(self.first_name_visible),
+            last_name_visible: last_name_visible.unwrap_or(self.last_name_visible),
+            bio_visible: bio_visible.unwrap_or(self.bio_visible),
+            profile_image_url_visible: profile_image_url_visible
+                .unwrap_or(self.profile_image_url_visible),
+        }
+    }
+}
+
+impl Default for PrivacySettings {
+    /// Every field is visible until the user opts out.
+    fn default() -> Self {
+        Self::new(true, true, true, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn when_default_should_have_every_field_visible() {
+        let settings = PrivacySettings::default();
+        assert!(settings.first_name_visible());
+        assert!(settings.last_name_visible());
+        assert!(settings.bio_visible());
+        assert!(settings.profile_image_url_visible());
+    }
+
+    #[test]
+    fn when_merge_should_keep_values_not_provided() {
+        let settings = PrivacySettings::default().merge(Some(false), None, Some(false), None);
+        assert!(!settings.first_name_visible());
+        assert!(settings.last_name_visible());
+        assert!(!settings.bio_visible());
+        assert!(settings.profile_image_url_visible());
+    }
+}
@@ -0,0 +1,290 @@
+use std::fmt::Display;
+
+use thiserror::Error;
+
+use crate::domain::{
+    helpers::USERNAME_VALID_CHARS_REGEX,
+    models::{error_code::ErrorCode, field_error::FieldError, profile::ProfileError},
+};
+
+/// Public handle of a profile, stored in lowercase.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Username(String);
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Error)]
+pub enum UsernameError {
+    #[error("Username cannot be empty")]
+    Empty,
+
+    #[error("Username is too long (maximum {0} characters)")]
+    TooLong(usize),
+
+    #[error("Username is too short (minimum {0} characters)")]
+    TooShort(usize),
+
+    #[error(
+        "Username contains invalid characters (only letters, numbers, underscores, and dots are allowed)"
+    )]
+    InvalidCharacters,
+
+    #[error("Username must start and end with a letter or number")]
+    InvalidEdgeCharacters,
+
+    #[error("Username contains characters that imitate letters or numbers")]
+    Confusable,
+
+    #[error("Username is reserved")]
+    Reserved,
+}
+
+impl UsernameError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            UsernameError::Empty => ErrorCode::UsernameEmpty,
+            UsernameError::TooShort(_) => ErrorCode::UsernameTooShort,
+            UsernameError::TooLong(_) => ErrorCode::UsernameTooLong,
+            UsernameError::InvalidCharacters => ErrorCode::UsernameInvalidCharacters,
+            UsernameError::InvalidEdgeCharacters => ErrorCode::UsernameInvalidEdgeCharacters,
+            UsernameError::Confusable => ErrorCode::UsernameConfusable,
+            UsernameError::Reserved => ErrorCode::UsernameReserved,
+        }
+    }
+}
+
+/// Handles nobody may take, as they could pass for the service or a route.
+/// Compared by [`Username::key`], so `r00t` is reserved as well.
+const RESERVED: &[&str] = &[
+    "about",
+    "admin",
+    "administrator",
+    "anonymous",
+    "api",
+    "by_username",
+    "everyone",
+    "help",
+    "login",
+    "logout",
+    "me",
+    "moderator",
+    "null",
+    "official",
+    "profile",
+    "profiles",
+    "register",
+    "root",
+    "security",
+    "settings",
+    "signup",
+    "staff",
+    "status",
+    "support",
+    "system",
+    "undefined",
+    "www",
+];
+
+impl Username {
+    const MIN_LENGTH: usize = 3;
+    const MAX_LENGTH: usize = 20;
+
+    pub fn try_new(value: String) -> Result<Self, UsernameError> {
+        let normalized = value.trim().to_lowercase();
+
+        if normalized.is_empty() {
+            return Err(UsernameError::Empty);
+        }
+
+        if normalized.len() < Self::MIN_LENGTH {
+            return Err(UsernameError::TooShort(Self::MIN_LENGTH));
+        }
+
+        if normalized.len() > Self::MAX_LENGTH {
+            return Err(UsernameError::TooLong(Self::MAX_LENGTH));
+        }
+
+        if normalized.chars().any(is_confusable) {
+            return Err(UsernameError::Confusable);
+        }
+
+        if !USERNAME_VALID_CHARS_REGEX.is_match(&normalized) {
+            return Err(UsernameError::InvalidCharacters);
+        }
+
+        if normalized.starts_with(|c: char| !c.is_ascii_alphanumeric())
+            || normalized.ends_with(|c: char| !c.is_ascii_alphanumeric())
+        {
+            return Err(UsernameError::InvalidEdgeCharacters);
+        }
+
+        let key = skeleton(&normalized);
+
+        if RESERVED.iter().any(|reserved| skeleton(reserved) == key) {
+            return Err(UsernameError::Reserved);
+        }
+
+        Ok(Self(normalized))
+    }
+
+    /// Form shared by handles that read alike, e.g. `john.doe`, `j0hn_doe`
+    /// and `jOhn.d0e`. Two profiles never hold handles with the same key.
+    pub fn key(&self) -> String {
+        skeleton(&self.0)
+    }
+
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+fn skeleton(value: &str) -> String {
+    let folded: String = value
+        .chars()
+        .map(|c| match c {
+            '0' => 'o',
+            '1' => 'l',
+            '.' => '_',
+            c => c,
+        })
+        .collect();
+
+    folded.replace("rn", "m").replace("vv", "w")
+}
+
+/// Letters and digits from other scripts, or other forms of the Latin ones,
+/// that render like the characters a handle may contain.
+fn is_confusable(c: char) -> bool {
+    matches!(
+        c,
+        // Cirílico
+        'а' | 'с' | 'ԁ' | 'е' | 'һ' | 'і' | 'ј' | 'ӏ' | 'о' | 'р' | 'ԛ' | 'ѕ' | 'ԝ' | 'х' | 'у'
+        // Grego
+        | 'α' | 'ι' | 'κ' | 'ν' | 'ο' | 'ρ' | 'υ'
+        // Latim sem ponto e afins
+        | 'ı' | 'ȷ' | 'ɡ' | 'ℓ'
+        // Formas de largura total
+        | '\u{FF10}'..='\u{FF19}' | '\u{FF21}'..='\u{FF3A}' | '\u{FF41}'..='\u{FF5A}'
+        // Símbolos alfanuméricos matemáticos
+        | '\u{1D400}'..='\u{1D7FF}'
+    )
+}
+
+impl AsRef<str> for Username {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for Username {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl TryFrom<&str> for Username {
+    type Error = UsernameError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::try_new(value.to_string())
+    }
+}
+
+impl TryFrom<String> for Username {
+    type Error = UsernameError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_new(value)
+    }
+}
+
+impl From<UsernameError> for FieldError {
+    fn from(error: UsernameError) -> Self {
+        let field_error = FieldError::new("username", error.code(), error.to_string());
+
+        match error {
+            UsernameError::TooShort(min) => field_error.with_param("min", min),
+            UsernameError::TooLong(max) => field_error.with_param("max", max),
+            _ => field_error,
+        }
+    }
+}
+
+impl From<UsernameError> for ProfileError {
+    fn from(error: UsernameError) -> Self {
+        ProfileError::InvalidFields(vec![error.into()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn when_username_is_valid_should_store_it_in_lowercase() {
+        let username = Username::try_new("  John.Doe_42 ".to_string()).unwrap();
+        assert_eq!(username.as_ref(), "john.doe_42");
+    }
+
+    #[test]
+    fn when_username_empty_should_empty_error() {
+        let username = Username::try_new("   ".to_string());
+        assert!(matches!(username, Err(UsernameError::Empty)));
+    }
+
+    #[test]
+    fn when_username_too_short_should_min_length_error() {
+        let username = Username::try_new("jd".to_string());
+        assert!(matches!(username, Err(UsernameError::TooShort(3))));
+    }
+
+    #[test]
+    fn when_username_too_long_should_max_length_error() {
+        let username = Username::try_new("a_really_long_handle_name".to_string());
+        assert!(matches!(username, Err(UsernameError::TooLong(20))));
+    }
+
+    #[test]
+    fn when_username_have_invalid_characters_should_invalid_characters_error() {
+        for value in ["john doe", "john-doe", "joão", "john@doe"] {
+            let username = Username::try_new(value.to_string());
+            assert!(matches!(username, Err(UsernameError::InvalidCharacters)));
+        }
+    }
+
+    #[test]
+    fn when_username_start_or_end_with_separator_should_invalid_edge_characters_error() {
+        for value in [".john", "john_"] {
+            let username = Username::try_new(value.to_string());
+            assert!(matches!(
+                username,
+                Err(UsernameError::InvalidEdgeCharacters)
+            ));
+        }
+    }
+
+    #[test]
+    fn when_username_imitates_latin_letters_should_confusable_error() {
+        // "јоhn" com j e o cirílicos, "ｊｏｈｎ" em largura total
+        for value in ["\u{0458}\u{043E}hn", "ｊｏｈｎ", "paypa\u{0399}"] {
+            let username = Username::try_new(value.to_string());
+            assert!(matches!(username, Err(UsernameError::Confusable)));
+        }
+    }
+
+    #[test]
+    fn when_username_is_reserved_or_imitates_reserved_should_reserved_error() {
+        for value in ["admin", "ADMIN", "r00t", "supp0rt", "by.username"] {
+            let username = Username::try_new(value.to_string());
+            assert!(matches!(username, Err(UsernameError::Reserved)));
+        }
+    }
+
+    #[test]
+    fn when_handles_read_alike_should_share_key() {
+        let key = |value: &str| Username::try_from(value).unwrap().key();
+
+        assert_eq!(key("john.doe"), key("j0hn_doe"));
+        assert_eq!(key("modern"), key("modem"));
+        assert_eq!(key("wall"), key("vva11"));
+        assert_ne!(key("john.doe"), key("jane.doe"));
+    }
+}
@@ -0,0 +1,72 @@
+use std::time::Duration;
+
+use thiserror::Error;
+
+#[cfg(test)]
+use mockall::automock;
+
+/// Response recorded for an idempotency key, replayed verbatim on retries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdempotentResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IdempotencyRecord {
+    /// The first request with the key is still being handled.
+    InProgress { fingerprint: String },
+    Completed {
+        fingerprint: String,
+        response: IdempotentResponse,
+    },
+}
+
+impl IdempotencyRecord {
+    pub fn fingerprint(&self) -> &str {
+        match self {
+            IdempotencyRecord::InProgress { fingerprint } => fingerprint,
+            IdempotencyRecord::Completed { fingerprint, .. } => fingerprint,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IdempotencyClaim {
+    /// The key was free (or expired) and is now held by the caller.
+    Acquired,
+    Existing(IdempotencyRecord),
+}
+
+#[cfg_attr(test, automock)]
+#[async_trait::async_trait]
+pub trait IdempotencyRepository: Send + Sync + 'static {
+    /// Atomically takes `key` for a request with `fingerprint`, or returns
+    /// what is already recorded for it. The claim lapses after `lock` so a
+    /// crashed request does not hold the key forever.
+    async fn claim(
+        &self,
+        key: &str,
+        fingerprint: &str,
+        lock: Duration,
+    ) -> Result<IdempotencyClaim, IdempotencyRepositoryError>;
+
+    /// Records the response of a claimed key, kept for `ttl`.
+    async fn complete(
+        &self,
+        key: &str,
+        fingerprint: &str,
+        response: IdempotentResponse,
+        ttl: Duration,
+    ) -> Result<(), IdempotencyRepositoryError>;
+
+    /// Frees a claimed key so the request can be retried.
+    async fn release(&self, key: &str) -> Result<(), IdempotencyRepositoryError>;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Error)]
+pub enum IdempotencyRepositoryError {
+    #[error("Unknown error: {0}")]
+    Unknown(String),
+}
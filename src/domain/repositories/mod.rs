@@ -1 +1,4 @@
+pub mod idempotency_repo;
+pub mod profile_cache;
 pub mod profile_repo;
+pub mod rate_limit_repo;
@@ -0,0 +1,31 @@
+use thiserror::Error;
+
+#[cfg(test)]
+use mockall::automock;
+
+use crate::domain::{models::profile::Profile, object_values::id::Id};
+
+/// Short-lived copies of profiles kept in front of a [`ProfileRepository`].
+/// Entries expire on their own. `invalidate` leaves the slot marked empty
+/// for one TTL and `put` only fills a free slot, so a copy loaded before a
+/// write is never stored after it.
+///
+/// [`ProfileRepository`]: crate::domain::repositories::profile_repo::ProfileRepository
+#[cfg_attr(test, automock)]
+#[async_trait::async_trait]
+pub trait ProfileCache: Send + Sync + 'static {
+    async fn get(&self, id: &Id) -> Result<Option<Profile>, ProfileCacheError>;
+
+    async fn put(&self, profile: &Profile) -> Result<(), ProfileCacheError>;
+
+    async fn invalidate(&self, id: &Id) -> Result<(), ProfileCacheError>;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Error)]
+pub enum ProfileCacheError {
+    #[error("Invalid cached data: {0}")]
+    InvalidData(String),
+
+    #[error("Unknown error: {0}")]
+    Unknown(String),
+}
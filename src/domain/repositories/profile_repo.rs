@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use thiserror::Error;
 
 #[cfg(test)]
@@ -5,25 +6,88 @@ use mockall::automock;
 
 use crate::domain::{
     models::profile::{Profile, ProfileError},
-    object_values::id::Id,
+    object_values::{id::Id, username::Username},
 };
 
+/// Outcome of each profile of a batch write, in the order they were given.
+pub type BatchWriteResult = Vec<Result<(), ProfileRepositoryError>>;
+
+/// A handle released by a rename, held for its previous owner until
+/// `expires_at` so nobody else can impersonate them with it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UsernameReservation {
+    pub username: Username,
+    pub profile_id: Id,
+    pub expires_at: DateTime<Utc>,
+}
+
 #[cfg_attr(test, automock)]
 #[async_trait::async_trait]
 pub trait ProfileRepository: Send + Sync + 'static {
     async fn save(&self, profile: &Profile) -> Result<(), ProfileRepositoryError>;
 
     async fn get_profile_by_id(&self, id: &Id) -> Result<Option<Profile>, ProfileRepositoryError>;
+
+    /// Like `get_profile_by_id`, but never leaves a stored field out: a
+    /// profile that cannot be read whole fails with `InvalidData`.
+    async fn get_complete_profile_by_id(
+        &self,
+        id: &Id,
+    ) -> Result<Option<Profile>, ProfileRepositoryError>;
+
+    /// Profiles among `ids` that exist, in no particular order.
+    async fn get_profiles_by_ids(&self, ids: &[Id])
+    -> Result<Vec<Profile>, ProfileRepositoryError>;
+
+    /// Inserts new profiles in one round trip. A profile that fails, e.g.
+    /// because its id is taken, does not stop the others.
+    async fn insert_profiles(
+        &self,
+        profiles: &[Profile],
+    ) -> Result<BatchWriteResult, ProfileRepositoryError>;
+
+    /// Replaces existing profiles in one round trip, each guarded by its
+    /// version as in `save`.
+    async fn update_profiles(
+        &self,
+        profiles: &[Profile],
+    ) -> Result<BatchWriteResult, ProfileRepositoryError>;
+
+    /// Removes a profile for good; `false` when there was none.
+    async fn delete_profile(&self, id: &Id) -> Result<bool, ProfileRepositoryError>;
+
+    async fn get_profile_by_username(
+        &self,
+        username: &Username,
+    ) -> Result<Option<Profile>, ProfileRepositoryError>;
+
+    /// Unexpired reservation of `username`, or of a handle sharing its key.
+    async fn get_username_reservation(
+        &self,
+        username: &Username,
+    ) -> Result<Option<UsernameReservation>, ProfileRepositoryError>;
+
+    /// Stores `reservation`, replacing any other of the same key.
+    async fn reserve_username(
+        &self,
+        reservation: &UsernameReservation,
+    ) -> Result<(), ProfileRepositoryError>;
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Error)]
 pub enum ProfileRepositoryError {
+    #[error("Profile already exists with id: {0}")]
+    AlreadyExists(String),
+
     #[error("Version conflict for profile with id: {0}")]
     VersionConflict(String),
 
     #[error("Invalid data: {0}")]
     InvalidData(String),
 
+    #[error("Username already taken: {0}")]
+    UsernameTaken(String),
+
     #[error("Unknown error: {0}")]
     Unknown(String),
 }
@@ -31,8 +95,12 @@ pub enum ProfileRepositoryError {
 impl From<ProfileRepositoryError> for ProfileError {
     fn from(error: ProfileRepositoryError) -> Self {
         match error {
+            ProfileRepositoryError::AlreadyExists(id) => ProfileError::AlreadyExists(id),
             ProfileRepositoryError::VersionConflict(id) => ProfileError::VersionConflict(id),
             ProfileRepositoryError::InvalidData(msg) => ProfileError::InvalidData(msg),
+            ProfileRepositoryError::UsernameTaken(username) => {
+                ProfileError::UsernameTaken(username)
+            }
             ProfileRepositoryError::Unknown(msg) => ProfileError::Unknown(msg),
         }
     }
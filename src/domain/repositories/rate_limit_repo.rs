@@ -0,0 +1,54 @@
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use thiserror::Error;
+
+#[cfg(test)]
+use mockall::automock;
+
+/// Hits counted for a key inside the current fixed window.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RateLimitWindow {
+    pub hits: u64,
+    pub resets_at: DateTime<Utc>,
+}
+
+#[cfg_attr(test, automock)]
+#[async_trait::async_trait]
+pub trait RateLimitRepository: Send + Sync + 'static {
+    /// Records one hit for `key` and returns the hits of the window it fell in.
+    async fn hit(
+        &self,
+        key: &str,
+        window: Duration,
+    ) -> Result<RateLimitWindow, RateLimitRepositoryError>;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Error)]
+pub enum RateLimitRepositoryError {
+    #[error("Unknown error: {0}")]
+    Unknown(String),
+}
+
+/// Start of the fixed window `now` falls in, so every replica computes the
+/// same window boundaries.
+pub fn window_start(now: DateTime<Utc>, window: Duration) -> DateTime<Utc> {
+    let window_millis = window.as_millis().max(1) as i64;
+    let millis = now.timestamp_millis();
+
+    DateTime::from_timestamp_millis(millis - millis.rem_euclid(window_millis)).unwrap_or(now)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn when_now_is_inside_window_should_round_down_to_window_start() {
+        let now = DateTime::from_timestamp(125, 0).unwrap();
+
+        let start = window_start(now, Duration::from_secs(60));
+
+        assert_eq!(start, DateTime::from_timestamp(120, 0).unwrap());
+    }
+}
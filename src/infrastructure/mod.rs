@@ -1 +1 @@
-pub mod repositories;
\ No newline at end of file
+pub mod repositories;
@@ -0,0 +1,471 @@
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::Duration,
+};
+
+use crate::domain::{
+    models::profile::Profile,
+    object_values::{id::Id, username::Username},
+    repositories::{
+        profile_cache::ProfileCache,
+        profile_repo::{
+            BatchWriteResult, ProfileRepository, ProfileRepositoryError, UsernameReservation,
+        },
+    },
+};
+
+/// Counters of a [`CachedProfileRepository`], shared with whoever reports
+/// them.
+#[derive(Debug, Default)]
+pub struct ProfileCacheMetrics {
+    hits: AtomicU64,
+    misses: AtomicU64,
+    errors: AtomicU64,
+}
+
+impl ProfileCacheMetrics {
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    pub fn misses(&self) -> u64 {
+        self.misses.load(Ordering::Relaxed)
+    }
+
+    /// Cache operations that failed; reads are counted as misses as well.
+    pub fn errors(&self) -> u64 {
+        self.errors.load(Ordering::Relaxed)
+    }
+
+    pub fn hit_ratio(&self) -> f64 {
+        let (hits, misses) = (self.hits(), self.misses());
+
+        match hits + misses {
+            0 => 0.0,
+            total => hits as f64 / total as f64,
+        }
+    }
+
+    /// Logs the counters every `interval` for as long as the process runs.
+    pub fn report_every(self: Arc<Self>, interval: Duration) {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.tick().await;
+
+            loop {
+                ticker.tick().await;
+                tracing::info!(
+                    hits = self.hits(),
+                    misses = self.misses(),
+                    errors = self.errors(),
+                    hit_ratio = self.hit_ratio(),
+                    "Profile cache metrics"
+                );
+            }
+        });
+    }
+}
+
+/// Read-through cache in front of any [`ProfileRepository`]. Reads are served
+/// from the cache when possible and stored on a miss. Every write, whether it
+/// succeeded or not, invalidates the cached copy; the profile is cached again
+/// by the first read once the invalidation expires, so a read racing with the
+/// write cannot put the old version back. A failed invalidation leaves the
+/// old copy until its TTL runs out. The cache is best effort: when it fails,
+/// the inner repository answers.
+pub struct CachedProfileRepository<R: ProfileRepository> {
+    inner: R,
+    cache: Arc<dyn ProfileCache>,
+    metrics: Arc<ProfileCacheMetrics>,
+}
+
+impl<R: ProfileRepository> CachedProfileRepository<R> {
+    async fn invalidate(&self, id: &Id) {
+        if let Err(e) = self.cache.invalidate(id).await {
+            self.metrics.errors.fetch_add(1, Ordering::Relaxed);
+            tracing::warn!("Profile cache invalidation failed: {}", e);
+        }
+    }
+
+    pub fn new(inner: R, cache: Arc<dyn ProfileCache>) -> Self {
+        Self {
+            inner,
+            cache,
+            metrics: Arc::default(),
+        }
+    }
+
+    pub fn metrics(&self) -> Arc<ProfileCacheMetrics> {
+        Arc::clone(&self.metrics)
+    }
+}
+
+#[async_trait::async_trait]
+impl<R: ProfileRepository> ProfileRepository for CachedProfileRepository<R> {
+    async fn save(&self, profile: &Profile) -> Result<(), ProfileRepositoryError> {
+        let result = self.inner.save(profile).await;
+
+        self.invalidate(profile.id()).await;
+
+        result
+    }
+
+    async fn get_profile_by_id(&self, id: &Id) -> Result<Option<Profile>, ProfileRepositoryError> {
+        match self.cache.get(id).await {
+            Ok(Some(profile)) => {
+                self.metrics.hits.fetch_add(1, Ordering::Relaxed);
+                return Ok(Some(profile));
+            }
+            Ok(None) => {}
+            Err(e) => {
+                self.metrics.errors.fetch_add(1, Ordering::Relaxed);
+                tracing::warn!("Profile cache read failed: {}", e);
+            }
+        }
+
+        self.metrics.misses.fetch_add(1, Ordering::Relaxed);
+
+        let profile = self.inner.get_profile_by_id(id).await?;
+
+        // Ausências não são guardadas: o perfil pode ser criado a seguir
+        if let Some(profile) = &profile
+            && let Err(e) = self.cache.put(profile).await
+        {
+            self.metrics.errors.fetch_add(1, Ordering::Relaxed);
+            tracing::warn!("Profile cache write failed: {}", e);
+        }
+
+        Ok(profile)
+    }
+
+    async fn get_complete_profile_by_id(
+        &self,
+        id: &Id,
+    ) -> Result<Option<Profile>, ProfileRepositoryError> {
+        // A cópia em cache pode ter vindo de uma leitura tolerante
+        self.inner.get_complete_profile_by_id(id).await
+    }
+
+    async fn get_profiles_by_ids(
+        &self,
+        ids: &[Id],
+    ) -> Result<Vec<Profile>, ProfileRepositoryError> {
+        let mut profiles = Vec::with_capacity(ids.len());
+        let mut missing = Vec::new();
+
+        for id in ids {
+            match self.cache.get(id).await {
+                Ok(Some(profile)) => {
+                    self.metrics.hits.fetch_add(1, Ordering::Relaxed);
+                    profiles.push(profile);
+                    continue;
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    self.metrics.errors.fetch_add(1, Ordering::Relaxed);
+                    tracing::warn!("Profile cache read failed: {}", e);
+                }
+            }
+
+            self.metrics.misses.fetch_add(1, Ordering::Relaxed);
+            missing.push(id.clone());
+        }
+
+        if missing.is_empty() {
+            return Ok(profiles);
+        }
+
+        // Uma única consulta para todos os perfis fora do cache
+        for profile in self.inner.get_profiles_by_ids(&missing).await? {
+            if let Err(e) = self.cache.put(&profile).await {
+                self.metrics.errors.fetch_add(1, Ordering::Relaxed);
+                tracing::warn!("Profile cache write failed: {}", e);
+            }
+
+            profiles.push(profile);
+        }
+
+        Ok(profiles)
+    }
+
+    // Ausências não são guardadas, então não há o que invalidar
+    async fn insert_profiles(
+        &self,
+        profiles: &[Profile],
+    ) -> Result<BatchWriteResult, ProfileRepositoryError> {
+        self.inner.insert_profiles(profiles).await
+    }
+
+    async fn update_profiles(
+        &self,
+        profiles: &[Profile],
+    ) -> Result<BatchWriteResult, ProfileRepositoryError> {
+        let result = self.inner.update_profiles(profiles).await;
+
+        for profile in profiles {
+            self.invalidate(profile.id()).await;
+        }
+
+        result
+    }
+
+    async fn delete_profile(&self, id: &Id) -> Result<bool, ProfileRepositoryError> {
+        let result = self.inner.delete_profile(id).await;
+
+        self.invalidate(id).await;
+
+        result
+    }
+
+    // Buscas por nome de usuário não passam pelo cache: uma troca de nome
+    // exigiria invalidar também a chave do nome anterior
+    async fn get_profile_by_username(
+        &self,
+        username: &Username,
+    ) -> Result<Option<Profile>, ProfileRepositoryError> {
+        self.inner.get_profile_by_username(username).await
+    }
+
+    async fn get_username_reservation(
+        &self,
+        username: &Username,
+    ) -> Result<Option<UsernameReservation>, ProfileRepositoryError> {
+        self.inner.get_username_reservation(username).await
+    }
+
+    async fn reserve_username(
+        &self,
+        reservation: &UsernameReservation,
+    ) -> Result<(), ProfileRepositoryError> {
+        self.inner.reserve_username(reservation).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use mockall::predicate::eq;
+
+    use super::*;
+    use crate::{
+        domain::{
+            object_values::email::Email,
+            repositories::{
+                profile_cache::{MockProfileCache, ProfileCacheError},
+                profile_repo::MockProfileRepository,
+            },
+        },
+        infrastructure::repositories::memory_profile_cache::MemoryProfileCache,
+    };
+
+    fn profile() -> Profile {
+        Profile::new(
+            Id::generate(),
+            Email::try_from("john.doe@example.com").unwrap(),
+        )
+    }
+
+    fn memory_cache() -> Arc<dyn ProfileCache> {
+        Arc::new(MemoryProfileCache::new(
+            NonZeroUsize::new(10).unwrap(),
+            Duration::from_secs(60),
+        ))
+    }
+
+    #[tokio::test]
+    async fn when_profile_is_read_twice_should_hit_repository_once() {
+        let profile = profile();
+        let mut inner = MockProfileRepository::new();
+
+        let stored = profile.clone();
+        inner
+            .expect_get_profile_by_id()
+            .with(eq(profile.id().clone()))
+            .times(1)
+            .returning(move |_| Ok(Some(stored.clone())));
+
+        let repository = CachedProfileRepository::new(inner, memory_cache());
+
+        let first = repository.get_profile_by_id(profile.id()).await.unwrap();
+        let second = repository.get_profile_by_id(profile.id()).await.unwrap();
+
+        assert_eq!(first.unwrap().id(), profile.id());
+        assert_eq!(second.unwrap().id(), profile.id());
+        assert_eq!(repository.metrics().hits(), 1);
+        assert_eq!(repository.metrics().misses(), 1);
+        assert_eq!(repository.metrics().hit_ratio(), 0.5);
+    }
+
+    #[tokio::test]
+    async fn when_profile_does_not_exist_should_not_cache_absence() {
+        let mut inner = MockProfileRepository::new();
+
+        inner
+            .expect_get_profile_by_id()
+            .times(2)
+            .returning(|_| Ok(None));
+
+        let repository = CachedProfileRepository::new(inner, memory_cache());
+        let id = Id::generate();
+
+        assert!(repository.get_profile_by_id(&id).await.unwrap().is_none());
+        assert!(repository.get_profile_by_id(&id).await.unwrap().is_none());
+        assert_eq!(repository.metrics().misses(), 2);
+    }
+
+    #[tokio::test]
+    async fn when_profile_is_saved_should_invalidate_cached_copy() {
+        let profile = profile();
+        let mut inner = MockProfileRepository::new();
+
+        let stored = profile.clone();
+        inner
+            .expect_get_profile_by_id()
+            .times(2)
+            .returning(move |_| Ok(Some(stored.clone())));
+        inner.expect_save().times(1).returning(|_| Ok(()));
+
+        let repository = CachedProfileRepository::new(inner, memory_cache());
+
+        repository.get_profile_by_id(profile.id()).await.unwrap();
+        repository.save(&profile).await.unwrap();
+        repository.get_profile_by_id(profile.id()).await.unwrap();
+
+        assert_eq!(repository.metrics().hits(), 0);
+        assert_eq!(repository.metrics().misses(), 2);
+    }
+
+    #[tokio::test]
+    async fn when_read_loaded_before_save_should_not_cache_old_version() {
+        let profile = profile();
+        let mut inner = MockProfileRepository::new();
+
+        // A leitura buscou a versão anterior antes da escrita terminar
+        let stored = profile.clone();
+        inner
+            .expect_get_profile_by_id()
+            .times(2)
+            .returning(move |_| Ok(Some(stored.clone())));
+        inner.expect_save().times(1).returning(|_| Ok(()));
+
+        let repository = CachedProfileRepository::new(inner, memory_cache());
+
+        repository.save(&profile).await.unwrap();
+        repository.get_profile_by_id(profile.id()).await.unwrap();
+        repository.get_profile_by_id(profile.id()).await.unwrap();
+
+        assert_eq!(repository.metrics().hits(), 0);
+        assert_eq!(repository.metrics().misses(), 2);
+    }
+
+    #[tokio::test]
+    async fn when_save_fails_should_still_invalidate_and_return_error() {
+        let profile = profile();
+        let mut inner = MockProfileRepository::new();
+        let mut cache = MockProfileCache::new();
+
+        inner.expect_save().returning(|profile| {
+            Err(ProfileRepositoryError::VersionConflict(
+                profile.id().to_string(),
+            ))
+        });
+        cache
+            .expect_invalidate()
+            .with(eq(profile.id().clone()))
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let repository = CachedProfileRepository::new(inner, Arc::new(cache));
+
+        let result = repository.save(&profile).await;
+
+        assert!(matches!(
+            result,
+            Err(ProfileRepositoryError::VersionConflict(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn when_profile_is_deleted_should_invalidate_cached_copy() {
+        let profile = profile();
+        let mut inner = MockProfileRepository::new();
+        let mut cache = MockProfileCache::new();
+
+        inner
+            .expect_delete_profile()
+            .times(1)
+            .returning(|_| Ok(true));
+        cache
+            .expect_invalidate()
+            .with(eq(profile.id().clone()))
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let repository = CachedProfileRepository::new(inner, Arc::new(cache));
+
+        assert!(repository.delete_profile(profile.id()).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn when_batch_is_partly_cached_should_only_fetch_the_rest() {
+        let (cached, uncached) = (profile(), profile());
+        let mut inner = MockProfileRepository::new();
+
+        let stored = cached.clone();
+        inner
+            .expect_get_profile_by_id()
+            .times(1)
+            .returning(move |_| Ok(Some(stored.clone())));
+
+        let stored = uncached.clone();
+        let expected = vec![uncached.id().clone()];
+        inner
+            .expect_get_profiles_by_ids()
+            .withf(move |ids| ids == expected.as_slice())
+            .times(1)
+            .returning(move |_| Ok(vec![stored.clone()]));
+
+        let repository = CachedProfileRepository::new(inner, memory_cache());
+
+        repository.get_profile_by_id(cached.id()).await.unwrap();
+        let profiles = repository
+            .get_profiles_by_ids(&[cached.id().clone(), uncached.id().clone()])
+            .await
+            .unwrap();
+
+        assert_eq!(profiles.len(), 2);
+        assert_eq!(repository.metrics().hits(), 1);
+        assert_eq!(repository.metrics().misses(), 2);
+    }
+
+    #[tokio::test]
+    async fn when_cache_fails_should_fall_back_to_repository() {
+        let profile = profile();
+        let mut inner = MockProfileRepository::new();
+        let mut cache = MockProfileCache::new();
+
+        let stored = profile.clone();
+        inner
+            .expect_get_profile_by_id()
+            .times(1)
+            .returning(move |_| Ok(Some(stored.clone())));
+        cache
+            .expect_get()
+            .returning(|_| Err(ProfileCacheError::Unknown("connection refused".into())));
+        cache
+            .expect_put()
+            .returning(|_| Err(ProfileCacheError::Unknown("connection refused".into())));
+
+        let repository = CachedProfileRepository::new(inner, Arc::new(cache));
+
+        let result = repository.get_profile_by_id(profile.id()).await.unwrap();
+
+        assert_eq!(result.unwrap().id(), profile.id());
+        assert_eq!(repository.metrics().misses(), 1);
+        assert_eq!(repository.metrics().errors(), 2);
+    }
+}
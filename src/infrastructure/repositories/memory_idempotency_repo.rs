@@ -0,0 +1,217 @@
+use std::{collections::HashMap, sync::Mutex, time::Duration};
+
+use chrono::{DateTime, Utc};
+
+use crate::domain::repositories::idempotency_repo::{
+    IdempotencyClaim, IdempotencyRecord, IdempotencyRepository, IdempotencyRepositoryError,
+    IdempotentResponse,
+};
+
+/// How often expired keys are dropped. Between sweeps, an expired key is
+/// only replaced when it is claimed again.
+const SWEEP_INTERVAL: Duration = Duration::from_secs(60);
+
+#[derive(Default)]
+struct Records {
+    by_key: HashMap<String, (IdempotencyRecord, DateTime<Utc>)>,
+    swept_at: DateTime<Utc>,
+}
+
+/// Keys local to this process. A retry routed to another replica is not
+/// recognised, so multi-replica deployments should use the Mongo store.
+#[derive(Default)]
+pub struct MemoryIdempotencyRepository {
+    records: Mutex<Records>,
+}
+
+impl MemoryIdempotencyRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn claim_at(
+        &self,
+        key: &str,
+        fingerprint: &str,
+        lock: Duration,
+        now: DateTime<Utc>,
+    ) -> Result<IdempotencyClaim, IdempotencyRepositoryError> {
+        let mut records = self
+            .records
+            .lock()
+            .map_err(|e| IdempotencyRepositoryError::Unknown(e.to_string()))?;
+
+        // Varrer a cada requisição seguraria o lock por todas as chaves
+        if (now - records.swept_at).to_std().unwrap_or_default() >= SWEEP_INTERVAL {
+            records
+                .by_key
+                .retain(|_, (_, expires_at)| *expires_at > now);
+            records.swept_at = now;
+        }
+
+        if let Some((record, expires_at)) = records.by_key.get(key)
+            && *expires_at > now
+        {
+            return Ok(IdempotencyClaim::Existing(record.clone()));
+        }
+
+        records.by_key.insert(
+            key.to_string(),
+            (
+                IdempotencyRecord::InProgress {
+                    fingerprint: fingerprint.to_string(),
+                },
+                now + chrono::Duration::from_std(lock).unwrap_or(chrono::Duration::zero()),
+            ),
+        );
+
+        Ok(IdempotencyClaim::Acquired)
+    }
+}
+
+#[async_trait::async_trait]
+impl IdempotencyRepository for MemoryIdempotencyRepository {
+    async fn claim(
+        &self,
+        key: &str,
+        fingerprint: &str,
+        lock: Duration,
+    ) -> Result<IdempotencyClaim, IdempotencyRepositoryError> {
+        self.claim_at(key, fingerprint, lock, Utc::now())
+    }
+
+    async fn complete(
+        &self,
+        key: &str,
+        fingerprint: &str,
+        response: IdempotentResponse,
+        ttl: Duration,
+    ) -> Result<(), IdempotencyRepositoryError> {
+        let mut records = self
+            .records
+            .lock()
+            .map_err(|e| IdempotencyRepositoryError::Unknown(e.to_string()))?;
+
+        records.by_key.insert(
+            key.to_string(),
+            (
+                IdempotencyRecord::Completed {
+                    fingerprint: fingerprint.to_string(),
+                    response,
+                },
+                Utc::now() + chrono::Duration::from_std(ttl).unwrap_or(chrono::Duration::zero()),
+            ),
+        );
+
+        Ok(())
+    }
+
+    async fn release(&self, key: &str) -> Result<(), IdempotencyRepositoryError> {
+        self.records
+            .lock()
+            .map_err(|e| IdempotencyRepositoryError::Unknown(e.to_string()))?
+            .by_key
+            .remove(key);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response() -> IdempotentResponse {
+        IdempotentResponse {
+            status: 201,
+            headers: vec![("etag".into(), "\"1\"".into())],
+            body: b"{}".to_vec(),
+        }
+    }
+
+    #[tokio::test]
+    async fn when_key_is_claimed_twice_should_return_existing_record() {
+        let repository = MemoryIdempotencyRepository::new();
+        let lock = Duration::from_secs(60);
+
+        let first = repository.claim("key", "abc", lock).await.unwrap();
+        let second = repository.claim("key", "abc", lock).await.unwrap();
+
+        assert_eq!(first, IdempotencyClaim::Acquired);
+        assert_eq!(
+            second,
+            IdempotencyClaim::Existing(IdempotencyRecord::InProgress {
+                fingerprint: "abc".into()
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn when_key_is_completed_should_return_stored_response() {
+        let repository = MemoryIdempotencyRepository::new();
+        let ttl = Duration::from_secs(60);
+
+        repository.claim("key", "abc", ttl).await.unwrap();
+        repository
+            .complete("key", "abc", response(), ttl)
+            .await
+            .unwrap();
+
+        let claim = repository.claim("key", "abc", ttl).await.unwrap();
+
+        assert_eq!(
+            claim,
+            IdempotencyClaim::Existing(IdempotencyRecord::Completed {
+                fingerprint: "abc".into(),
+                response: response(),
+            })
+        );
+    }
+
+    #[test]
+    fn when_claim_has_expired_should_acquire_key_again() {
+        let repository = MemoryIdempotencyRepository::new();
+        let now = DateTime::from_timestamp(120, 0).unwrap();
+        let lock = Duration::from_secs(60);
+
+        repository.claim_at("key", "abc", lock, now).unwrap();
+        let claim = repository
+            .claim_at("key", "def", lock, now + chrono::Duration::seconds(61))
+            .unwrap();
+
+        assert_eq!(claim, IdempotencyClaim::Acquired);
+    }
+
+    #[test]
+    fn when_sweep_interval_passes_should_drop_expired_keys() {
+        let repository = MemoryIdempotencyRepository::new();
+        let now = DateTime::from_timestamp(120, 0).unwrap();
+        let lock = Duration::from_secs(10);
+
+        repository.claim_at("a", "abc", lock, now).unwrap();
+        repository
+            .claim_at("b", "abc", lock, now + chrono::Duration::seconds(30))
+            .unwrap();
+
+        assert_eq!(repository.records.lock().unwrap().by_key.len(), 2);
+
+        repository
+            .claim_at("c", "abc", lock, now + chrono::Duration::seconds(61))
+            .unwrap();
+
+        assert_eq!(repository.records.lock().unwrap().by_key.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn when_key_is_released_should_acquire_key_again() {
+        let repository = MemoryIdempotencyRepository::new();
+        let lock = Duration::from_secs(60);
+
+        repository.claim("key", "abc", lock).await.unwrap();
+        repository.release("key").await.unwrap();
+
+        let claim = repository.claim("key", "abc", lock).await.unwrap();
+
+        assert_eq!(claim, IdempotencyClaim::Acquired);
+    }
+}
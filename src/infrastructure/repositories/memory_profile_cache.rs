@@ -0,0 +1,189 @@
+use std::{num::NonZeroUsize, sync::Mutex, time::Duration};
+
+use chrono::{DateTime, Utc};
+use lru::LruCache;
+
+use crate::domain::{
+    models::profile::Profile,
+    object_values::id::Id,
+    repositories::profile_cache::{ProfileCache, ProfileCacheError},
+};
+
+/// Cached profile, or `None` after an invalidation, and its expiry.
+type Entry = (Option<Profile>, DateTime<Utc>);
+
+/// Profiles cached in this process, bounded to `capacity` entries with the
+/// least recently used evicted first. Each replica keeps its own copy, so a
+/// write on one replica is only seen by the others once `ttl` runs out.
+///
+/// An invalidated profile keeps an empty entry until `ttl` runs out, and
+/// `put` only fills a free slot, so a read started before the write cannot
+/// store the old version after it.
+pub struct MemoryProfileCache {
+    entries: Mutex<LruCache<String, Entry>>,
+    ttl: Duration,
+}
+
+impl MemoryProfileCache {
+    pub fn new(capacity: NonZeroUsize, ttl: Duration) -> Self {
+        Self {
+            entries: Mutex::new(LruCache::new(capacity)),
+            ttl,
+        }
+    }
+
+    fn get_at(&self, id: &Id, now: DateTime<Utc>) -> Result<Option<Profile>, ProfileCacheError> {
+        let mut entries = self
+            .entries
+            .lock()
+            .map_err(|e| ProfileCacheError::Unknown(e.to_string()))?;
+
+        let key = id.to_string();
+
+        match entries.get(&key) {
+            Some((profile, expires_at)) if *expires_at > now => Ok(profile.clone()),
+            Some(_) => {
+                entries.pop(&key);
+                Ok(None)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn expires_at(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        now + chrono::Duration::from_std(self.ttl).unwrap_or(chrono::Duration::zero())
+    }
+
+    fn put_at(&self, profile: &Profile, now: DateTime<Utc>) -> Result<(), ProfileCacheError> {
+        let mut entries = self
+            .entries
+            .lock()
+            .map_err(|e| ProfileCacheError::Unknown(e.to_string()))?;
+
+        let key = profile.id().to_string();
+
+        if entries
+            .peek(&key)
+            .is_some_and(|(_, expires_at)| *expires_at > now)
+        {
+            return Ok(());
+        }
+
+        entries.put(key, (Some(profile.clone()), self.expires_at(now)));
+
+        Ok(())
+    }
+
+    fn invalidate_at(&self, id: &Id, now: DateTime<Utc>) -> Result<(), ProfileCacheError> {
+        self.entries
+            .lock()
+            .map_err(|e| ProfileCacheError::Unknown(e.to_string()))?
+            .put(id.to_string(), (None, self.expires_at(now)));
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl ProfileCache for MemoryProfileCache {
+    async fn get(&self, id: &Id) -> Result<Option<Profile>, ProfileCacheError> {
+        self.get_at(id, Utc::now())
+    }
+
+    async fn put(&self, profile: &Profile) -> Result<(), ProfileCacheError> {
+        self.put_at(profile, Utc::now())
+    }
+
+    async fn invalidate(&self, id: &Id) -> Result<(), ProfileCacheError> {
+        self.invalidate_at(id, Utc::now())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::object_values::email::Email;
+
+    fn profile() -> Profile {
+        Profile::new(
+            Id::generate(),
+            Email::try_from("john.doe@example.com").unwrap(),
+        )
+    }
+
+    fn cache(capacity: usize) -> MemoryProfileCache {
+        MemoryProfileCache::new(
+            NonZeroUsize::new(capacity).unwrap(),
+            Duration::from_secs(60),
+        )
+    }
+
+    #[tokio::test]
+    async fn when_profile_is_cached_should_return_it_until_invalidated() {
+        let cache = cache(10);
+        let profile = profile();
+
+        cache.put(&profile).await.unwrap();
+        let cached = cache.get(profile.id()).await.unwrap();
+
+        cache.invalidate(profile.id()).await.unwrap();
+        let invalidated = cache.get(profile.id()).await.unwrap();
+
+        assert_eq!(cached.unwrap().id(), profile.id());
+        assert!(invalidated.is_none());
+    }
+
+    #[test]
+    fn when_entry_is_older_than_ttl_should_be_a_miss() {
+        let cache = cache(10);
+        let profile = profile();
+        let now = Utc::now();
+
+        cache.put_at(&profile, now).unwrap();
+
+        assert!(
+            cache
+                .get_at(profile.id(), now + chrono::Duration::seconds(59))
+                .unwrap()
+                .is_some()
+        );
+        assert!(
+            cache
+                .get_at(profile.id(), now + chrono::Duration::seconds(61))
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn when_profile_was_invalidated_should_not_cache_it_until_ttl_runs_out() {
+        let cache = cache(10);
+        let profile = profile();
+        let now = Utc::now();
+
+        cache.invalidate_at(profile.id(), now).unwrap();
+        cache.put_at(&profile, now).unwrap();
+
+        assert!(cache.get_at(profile.id(), now).unwrap().is_none());
+
+        let later = now + chrono::Duration::seconds(61);
+        cache.put_at(&profile, later).unwrap();
+
+        assert!(cache.get_at(profile.id(), later).unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn when_capacity_is_reached_should_evict_least_recently_used() {
+        let cache = cache(2);
+        let (first, second, third) = (profile(), profile(), profile());
+
+        cache.put(&first).await.unwrap();
+        cache.put(&second).await.unwrap();
+        cache.get(first.id()).await.unwrap();
+        cache.put(&third).await.unwrap();
+
+        assert!(cache.get(first.id()).await.unwrap().is_some());
+        assert!(cache.get(second.id()).await.unwrap().is_none());
+        assert!(cache.get(third.id()).await.unwrap().is_some());
+    }
+}
@@ -0,0 +1,152 @@
+use std::{collections::HashMap, sync::Mutex, time::Duration};
+
+use chrono::{DateTime, Utc};
+
+use crate::domain::repositories::rate_limit_repo::{
+    RateLimitRepository, RateLimitRepositoryError, RateLimitWindow, window_start,
+};
+
+/// How often expired windows are dropped. Between sweeps, an expired
+/// window is only reset when its key is hit again.
+const SWEEP_INTERVAL: Duration = Duration::from_secs(60);
+
+#[derive(Default)]
+struct Windows {
+    by_key: HashMap<String, RateLimitWindow>,
+    swept_at: DateTime<Utc>,
+}
+
+/// Counters local to this process. Each replica enforces its own quota.
+#[derive(Default)]
+pub struct MemoryRateLimitRepository {
+    windows: Mutex<Windows>,
+}
+
+impl MemoryRateLimitRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn hit_at(
+        &self,
+        key: &str,
+        window: Duration,
+        now: DateTime<Utc>,
+    ) -> Result<RateLimitWindow, RateLimitRepositoryError> {
+        let mut windows = self
+            .windows
+            .lock()
+            .map_err(|e| RateLimitRepositoryError::Unknown(e.to_string()))?;
+
+        // Varrer a cada requisição seguraria o lock por todas as chaves
+        if (now - windows.swept_at).to_std().unwrap_or_default() >= SWEEP_INTERVAL {
+            windows.by_key.retain(|_, current| current.resets_at > now);
+            windows.swept_at = now;
+        }
+
+        let resets_at = window_start(now, window)
+            + chrono::Duration::from_std(window).unwrap_or(chrono::Duration::zero());
+        let fresh = RateLimitWindow { hits: 0, resets_at };
+
+        let current = windows
+            .by_key
+            .entry(key.to_string())
+            .or_insert(fresh.clone());
+        if current.resets_at <= now {
+            *current = fresh;
+        }
+        current.hits += 1;
+
+        Ok(current.clone())
+    }
+}
+
+#[async_trait::async_trait]
+impl RateLimitRepository for MemoryRateLimitRepository {
+    async fn hit(
+        &self,
+        key: &str,
+        window: Duration,
+    ) -> Result<RateLimitWindow, RateLimitRepositoryError> {
+        self.hit_at(key, window, Utc::now())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn when_hits_fall_in_same_window_should_accumulate() {
+        let repository = MemoryRateLimitRepository::new();
+        let now = DateTime::from_timestamp(120, 0).unwrap();
+
+        repository
+            .hit_at("key", Duration::from_secs(60), now)
+            .unwrap();
+        let window = repository
+            .hit_at(
+                "key",
+                Duration::from_secs(60),
+                now + chrono::Duration::seconds(30),
+            )
+            .unwrap();
+
+        assert_eq!(window.hits, 2);
+        assert_eq!(window.resets_at, DateTime::from_timestamp(180, 0).unwrap());
+    }
+
+    #[test]
+    fn when_window_expires_should_start_counting_again() {
+        let repository = MemoryRateLimitRepository::new();
+        let now = DateTime::from_timestamp(120, 0).unwrap();
+
+        repository
+            .hit_at("key", Duration::from_secs(60), now)
+            .unwrap();
+        let window = repository
+            .hit_at(
+                "key",
+                Duration::from_secs(60),
+                now + chrono::Duration::seconds(60),
+            )
+            .unwrap();
+
+        assert_eq!(window.hits, 1);
+    }
+
+    #[test]
+    fn when_keys_differ_should_count_separately() {
+        let repository = MemoryRateLimitRepository::new();
+        let now = DateTime::from_timestamp(120, 0).unwrap();
+
+        repository
+            .hit_at("a", Duration::from_secs(60), now)
+            .unwrap();
+        let window = repository
+            .hit_at("b", Duration::from_secs(60), now)
+            .unwrap();
+
+        assert_eq!(window.hits, 1);
+    }
+
+    #[test]
+    fn when_sweep_interval_passes_should_drop_expired_windows() {
+        let repository = MemoryRateLimitRepository::new();
+        let now = DateTime::from_timestamp(120, 0).unwrap();
+        let window = Duration::from_secs(10);
+
+        repository.hit_at("a", window, now).unwrap();
+        repository
+            .hit_at("b", window, now + chrono::Duration::seconds(30))
+            .unwrap();
+
+        assert_eq!(repository.windows.lock().unwrap().by_key.len(), 2);
+
+        repository
+            .hit_at("b", window, now + chrono::Duration::seconds(61))
+            .unwrap();
+
+        assert_eq!(repository.windows.lock().unwrap().by_key.len(), 1);
+    }
+}
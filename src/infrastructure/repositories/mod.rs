@@ -1,2 +1,11 @@
+pub mod cached_profile_repo;
+pub mod memory_idempotency_repo;
+pub mod memory_profile_cache;
+pub mod memory_rate_limit_repo;
+pub mod mongo_idempotency_repo;
 pub mod mongo_profile_repo;
+pub mod mongo_rate_limit_repo;
 pub mod mongo_service;
+pub mod profile_integrity;
+pub mod profile_schema;
+pub mod redis_profile_cache;
@@ -0,0 +1,171 @@
+use std::{sync::Arc, time::Duration};
+
+use chrono::Utc;
+use mongodb::{
+    Collection, IndexModel,
+    bson::{Binary, doc, spec::BinarySubtype},
+    options::IndexOptions,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    domain::repositories::idempotency_repo::{
+        IdempotencyClaim, IdempotencyRecord, IdempotencyRepository, IdempotencyRepositoryError,
+        IdempotentResponse,
+    },
+    infrastructure::repositories::mongo_service::{MongoService, is_duplicate_key},
+};
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct IdempotencyDocument {
+    #[serde(rename = "_id")]
+    id: String,
+    fingerprint: String,
+    response: Option<ResponseDocument>,
+    expires_at: mongodb::bson::DateTime,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ResponseDocument {
+    status: i32,
+    headers: Vec<(String, String)>,
+    body: Binary,
+}
+
+impl From<IdempotentResponse> for ResponseDocument {
+    fn from(response: IdempotentResponse) -> Self {
+        ResponseDocument {
+            status: response.status as i32,
+            headers: response.headers,
+            body: Binary {
+                subtype: BinarySubtype::Generic,
+                bytes: response.body,
+            },
+        }
+    }
+}
+
+impl From<IdempotencyDocument> for IdempotencyRecord {
+    fn from(document: IdempotencyDocument) -> Self {
+        match document.response {
+            Some(response) => IdempotencyRecord::Completed {
+                fingerprint: document.fingerprint,
+                response: IdempotentResponse {
+                    status: response.status as u16,
+                    headers: response.headers,
+                    body: response.body.bytes,
+                },
+            },
+            None => IdempotencyRecord::InProgress {
+                fingerprint: document.fingerprint,
+            },
+        }
+    }
+}
+
+fn expires_at(duration: Duration) -> mongodb::bson::DateTime {
+    let expires_at =
+        Utc::now() + chrono::Duration::from_std(duration).unwrap_or(chrono::Duration::zero());
+
+    mongodb::bson::DateTime::from_millis(expires_at.timestamp_millis())
+}
+
+/// Keys shared by every replica through the `idempotency_keys` collection.
+/// Expired keys are removed by a TTL index on `expires_at`.
+#[derive(Clone)]
+pub struct MongoIdempotencyRepository {
+    collection: Collection<IdempotencyDocument>,
+}
+
+impl MongoIdempotencyRepository {
+    pub async fn new(mongo_service: Arc<MongoService>) -> anyhow::Result<Self> {
+        let collection = mongo_service
+            .get_database()
+            .collection::<IdempotencyDocument>("idempotency_keys");
+
+        let ttl_index = IndexModel::builder()
+            .keys(doc! {"expires_at": 1})
+            .options(
+                IndexOptions::builder()
+                    .expire_after(Duration::from_secs(0))
+                    .build(),
+            )
+            .build();
+
+        collection.create_index(ttl_index).await?;
+
+        Ok(MongoIdempotencyRepository { collection })
+    }
+}
+
+#[async_trait::async_trait]
+impl IdempotencyRepository for MongoIdempotencyRepository {
+    async fn claim(
+        &self,
+        key: &str,
+        fingerprint: &str,
+        lock: Duration,
+    ) -> Result<IdempotencyClaim, IdempotencyRepositoryError> {
+        let now = mongodb::bson::DateTime::from_millis(Utc::now().timestamp_millis());
+
+        // Só casa com uma chave expirada que o índice TTL ainda não removeu;
+        // uma chave viva faz o upsert colidir no _id
+        let result = self
+            .collection
+            .update_one(
+                doc! {"_id": key, "expires_at": {"$lte": now}},
+                doc! {
+                    "$set": {"fingerprint": fingerprint, "expires_at": expires_at(lock)},
+                    "$unset": {"response": ""},
+                },
+            )
+            .upsert(true)
+            .await;
+
+        match result {
+            Ok(_) => Ok(IdempotencyClaim::Acquired),
+            Err(e) if is_duplicate_key(&e) => self
+                .collection
+                .find_one(doc! {"_id": key})
+                .await
+                .map_err(|e| IdempotencyRepositoryError::Unknown(e.to_string()))?
+                .map(|document| IdempotencyClaim::Existing(document.into()))
+                .ok_or_else(|| {
+                    IdempotencyRepositoryError::Unknown("Key released while claiming".to_string())
+                }),
+            Err(e) => Err(IdempotencyRepositoryError::Unknown(e.to_string())),
+        }
+    }
+
+    async fn complete(
+        &self,
+        key: &str,
+        fingerprint: &str,
+        response: IdempotentResponse,
+        ttl: Duration,
+    ) -> Result<(), IdempotencyRepositoryError> {
+        let document = IdempotencyDocument {
+            id: key.to_string(),
+            fingerprint: fingerprint.to_string(),
+            response: Some(response.into()),
+            expires_at: expires_at(ttl),
+        };
+
+        self.collection
+            .replace_one(doc! {"_id": key}, document)
+            .upsert(true)
+            .await
+            .map_err(|e| IdempotencyRepositoryError::Unknown(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn release(&self, key: &str) -> Result<(), IdempotencyRepositoryError> {
+        self.collection
+            .delete_one(doc! {"_id": key})
+            .await
+            .map_err(|e| IdempotencyRepositoryError::Unknown(e.to_string()))?;
+
+        Ok(())
+    }
+}
@@ -1,4 +1,11 @@
-use std::sync::Arc;
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::Duration,
+};
 
 use serde::{Deserialize, Serialize};
 
@@ -6,89 +13,860 @@ use crate::{
     domain::{
         models::profile::Profile,
         object_values::{
-            bio::Bio, email::Email, first_name::FirstName, id::Id, image_url::ImageUrl,
+            bio::Bio,
+            display_name::{DisplayName, DisplayNameFormat},
+            email::Email,
+            first_name::FirstName,
+            id::Id,
+            image_url::ImageUrl,
             last_name::LastName,
+            privacy_settings::PrivacySettings,
+            username::Username,
+        },
+        repositories::profile_repo::{
+            BatchWriteResult, ProfileRepository, ProfileRepositoryError, UsernameReservation,
         },
-        repositories::profile_repo::{ProfileRepository, ProfileRepositoryError},
     },
-    infrastructure::repositories::mongo_service::MongoService,
+    infrastructure::repositories::{
+        mongo_service::{DUPLICATE_KEY, MongoService, is_duplicate_key},
+        profile_integrity, profile_schema,
+    },
+};
+use futures_util::TryStreamExt;
+use mongodb::{
+    Client, Collection, Cursor, IndexModel,
+    bson::{Bson, Document, doc},
+    error::{ErrorKind, PartialBulkWriteResult},
+    options::IndexOptions,
 };
-use mongodb::{Collection, bson::doc};
 
-#[derive(Debug, Deserialize, Serialize)]
+/// Unique index on `username_key`; duplicate key errors naming it mean the
+/// handle is taken rather than the id.
+const USERNAME_INDEX: &str = "username_key_unique";
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ProfileDocument {
     #[serde(rename = "_id")]
     id: String,
-    email: String,
-    first_name: Option<String>,
-    last_name: Option<String>,
-    bio: Option<String>,
-    profile_image_url: Option<String>,
+    pub(crate) email: String,
+    pub(crate) first_name: Option<String>,
+    pub(crate) last_name: Option<String>,
+    pub(crate) bio: Option<String>,
+    pub(crate) profile_image_url: Option<String>,
+    privacy_settings: PrivacySettingsDocument,
+    pub(crate) username: Option<String>,
+    /// [`Username::key`] of `username`, under a unique index.
+    pub(crate) username_key: Option<String>,
+    username_changed_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub(crate) display_name: Option<String>,
+    display_name_format: String,
     created_at: chrono::DateTime<chrono::Utc>,
-    updated_at: Option<chrono::DateTime<chrono::Utc>>,
-    version: u64,
+    pub(crate) updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub(crate) version: u64,
+    /// See [`profile_schema::CURRENT_SCHEMA_VERSION`].
+    schema_version: u32,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+pub struct PrivacySettingsDocument {
+    first_name_visible: bool,
+    last_name_visible: bool,
+    bio_visible: bool,
+    profile_image_url_visible: bool,
+}
+
+impl ProfileDocument {
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Marks a change made outside of the domain, such as a repair, as a new
+    /// version, so `save` applies it only over the version it was read at.
+    pub fn touch(&mut self) {
+        self.version += 1;
+        self.updated_at = Some(chrono::Utc::now());
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct UsernameReservationDocument {
+    /// [`Username::key`] of the reserved handle.
+    #[serde(rename = "_id")]
+    key: String,
+    username: String,
+    profile_id: String,
+    expires_at: mongodb::bson::DateTime,
+}
+
+/// Size of the `profiles` collection and how many documents fill in each
+/// optional field.
+#[derive(Debug, Default, Serialize)]
+pub struct ProfileCollectionStats {
+    pub documents: u64,
+    pub data_size_bytes: u64,
+    pub average_document_bytes: u64,
+    pub storage_size_bytes: u64,
+    pub indexes: u64,
+    pub index_size_bytes: u64,
+    pub with_first_name: u64,
+    pub with_last_name: u64,
+    pub with_bio: u64,
+    pub with_profile_image_url: u64,
+}
+
+impl Default for PrivacySettingsDocument {
+    fn default() -> Self {
+        PrivacySettings::default().into()
+    }
+}
+
+impl From<PrivacySettings> for PrivacySettingsDocument {
+    fn from(settings: PrivacySettings) -> Self {
+        PrivacySettingsDocument {
+            first_name_visible: settings.first_name_visible(),
+            last_name_visible: settings.last_name_visible(),
+            bio_visible: settings.bio_visible(),
+            profile_image_url_visible: settings.profile_image_url_visible(),
+        }
+    }
+}
+
+impl From<PrivacySettingsDocument> for PrivacySettings {
+    fn from(doc: PrivacySettingsDocument) -> Self {
+        PrivacySettings::new(
+            doc.first_name_visible,
+            doc.last_name_visible,
+            doc.bio_visible,
+            doc.profile_image_url_visible,
+        )
+    }
+}
+
+/// What a read does with a stored document that fails today's validation.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ProfileReadPolicy {
+    /// The read fails with `ProfileRepositoryError::InvalidData`.
+    #[default]
+    Strict,
+    /// Invalid optional fields are left out of the profile, with a warning.
+    /// An invalid id or email still fails the read. Writes keep the omitted
+    /// values in the document unless they set the field again, at the cost
+    /// of reading the stored document first.
+    Lenient,
+}
+
+/// Counters of the fields left out by [`ProfileReadPolicy::Lenient`].
+#[derive(Debug, Default)]
+pub struct ProfileReadMetrics {
+    degraded_reads: AtomicU64,
+    omitted_fields: AtomicU64,
+}
+
+impl ProfileReadMetrics {
+    /// Profiles returned with at least one field left out.
+    pub fn degraded_reads(&self) -> u64 {
+        self.degraded_reads.load(Ordering::Relaxed)
+    }
+
+    pub fn omitted_fields(&self) -> u64 {
+        self.omitted_fields.load(Ordering::Relaxed)
+    }
+
+    /// Logs the counters every `interval` for as long as the process runs.
+    pub fn report_every(self: Arc<Self>, interval: Duration) {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.tick().await;
+
+            loop {
+                ticker.tick().await;
+                tracing::info!(
+                    degraded_reads = self.degraded_reads(),
+                    omitted_fields = self.omitted_fields(),
+                    "Profile lenient read metrics"
+                );
+            }
+        });
+    }
+}
+
+/// Outcome of [`MongoProfileRepository::upgrade_schema`].
+#[derive(Debug, Default, Serialize)]
+pub struct SchemaUpgradeSummary {
+    pub upgraded: u64,
+    /// Documents changed by a concurrent write before they were rewritten.
+    /// That write already stored them in the current shape.
+    pub skipped: u64,
 }
 
 #[derive(Clone)]
 pub struct MongoProfileRepository {
+    client: Client,
     collection: Collection<ProfileDocument>,
+    /// Reads go through the raw documents, so older shapes can be upcast
+    /// before they are deserialized.
+    documents: Collection<Document>,
+    /// Handles released by renames. Expired ones are removed by a TTL index
+    /// on `expires_at`.
+    reservations: Collection<UsernameReservationDocument>,
+    read_policy: ProfileReadPolicy,
+    read_metrics: Arc<ProfileReadMetrics>,
 }
 
 impl MongoProfileRepository {
-    pub fn new(mongo_service: Arc<MongoService>) -> Self {
+    pub fn new(mongo_service: Arc<MongoService>, read_policy: ProfileReadPolicy) -> Self {
         let collection = mongo_service
             .get_database()
             .collection::<ProfileDocument>("profiles");
 
-        MongoProfileRepository { collection }
+        let reservations = mongo_service
+            .get_database()
+            .collection::<UsernameReservationDocument>("username_reservations");
+
+        MongoProfileRepository {
+            client: mongo_service.get_client(),
+            documents: collection.clone_with_type(),
+            reservations,
+            collection,
+            read_policy,
+            read_metrics: Arc::default(),
+        }
+    }
+
+    pub fn read_metrics(&self) -> Arc<ProfileReadMetrics> {
+        Arc::clone(&self.read_metrics)
+    }
+
+    /// Creates the indexes the repository relies on; run once at startup.
+    pub async fn ensure_indexes(&self) -> anyhow::Result<()> {
+        // Parcial: perfis sem nome de usuário não disputam o índice
+        let username_index = IndexModel::builder()
+            .keys(doc! {"username_key": 1})
+            .options(
+                IndexOptions::builder()
+                    .name(USERNAME_INDEX.to_string())
+                    .unique(true)
+                    .partial_filter_expression(doc! {"username_key": {"$type": "string"}})
+                    .build(),
+            )
+            .build();
+
+        let ttl_index = IndexModel::builder()
+            .keys(doc! {"expires_at": 1})
+            .options(
+                IndexOptions::builder()
+                    .expire_after(Duration::from_secs(0))
+                    .build(),
+            )
+            .build();
+
+        self.collection.create_index(username_index).await?;
+        self.reservations.create_index(ttl_index).await?;
+
+        Ok(())
+    }
+
+    fn to_profile(&self, document: ProfileDocument) -> Result<Profile, ProfileRepositoryError> {
+        if self.read_policy == ProfileReadPolicy::Strict {
+            return Profile::try_from(document);
+        }
+
+        // Caminho comum: o documento é válido e não precisa ser inspecionado
+        let error = match Profile::try_from(document.clone()) {
+            Ok(profile) => return Ok(profile),
+            Err(e) => e,
+        };
+
+        let mut document = document;
+        let omitted = profile_integrity::omit_invalid_fields(&mut document);
+        let id = document.id().to_string();
+
+        if omitted.is_empty() {
+            return Err(error);
+        }
+
+        let profile = Profile::try_from(document)?;
+
+        self.read_metrics
+            .degraded_reads
+            .fetch_add(1, Ordering::Relaxed);
+        self.read_metrics
+            .omitted_fields
+            .fetch_add(omitted.len() as u64, Ordering::Relaxed);
+
+        for issue in &omitted {
+            tracing::warn!(
+                profile_id = id,
+                field = issue.field,
+                code = %issue.code,
+                "Invalid stored field left out of profile"
+            );
+        }
+
+        Ok(profile)
+    }
+
+    /// Under the lenient policy, puts back into `documents` the invalid
+    /// fields their profiles were read without. A stored document of
+    /// another version is left alone: the guarded write will reject it.
+    async fn keep_omitted_fields(
+        &self,
+        documents: &mut [ProfileDocument],
+    ) -> Result<(), ProfileRepositoryError> {
+        if self.read_policy == ProfileReadPolicy::Strict {
+            return Ok(());
+        }
+
+        let ids: Vec<&str> = documents.iter().map(ProfileDocument::id).collect();
+
+        let mut cursor = self
+            .documents
+            .find(doc! {"_id": {"$in": ids}})
+            .await
+            .map_err(|e| ProfileRepositoryError::Unknown(e.to_string()))?;
+
+        let mut stored = HashMap::new();
+
+        while cursor
+            .advance()
+            .await
+            .map_err(|e| ProfileRepositoryError::Unknown(e.to_string()))?
+        {
+            let document = cursor
+                .deserialize_current()
+                .map_err(|e| ProfileRepositoryError::InvalidData(e.to_string()))?;
+            let document = profile_schema::read_document(document)?;
+
+            stored.insert(document.id.clone(), document);
+        }
+
+        for document in documents {
+            if let Some(previous) = stored.remove(&document.id)
+                && previous.version + 1 == document.version
+            {
+                let kept = profile_integrity::keep_omitted_fields(document, previous);
+
+                if kept > 0 {
+                    tracing::debug!(
+                        profile_id = document.id,
+                        kept,
+                        "Invalid stored fields kept on write"
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn find_document(
+        &self,
+        id: &Id,
+    ) -> Result<Option<ProfileDocument>, ProfileRepositoryError> {
+        let document = self
+            .documents
+            .find_one(doc! {"_id": id.to_string()})
+            .await
+            .map_err(|e| ProfileRepositoryError::Unknown(e.to_string()))?;
+
+        document.map(profile_schema::read_document).transpose()
+    }
+
+    /// Which of `ids` are stored in a shape newer than this release writes,
+    /// telling those apart from version conflicts when a replace matched
+    /// nothing.
+    async fn newer_schema_ids(
+        &self,
+        ids: &[String],
+    ) -> Result<HashSet<String>, ProfileRepositoryError> {
+        if ids.is_empty() {
+            return Ok(HashSet::new());
+        }
+
+        let filter = doc! {
+            "_id": {"$in": ids},
+            "schema_version": {"$gt": profile_schema::CURRENT_SCHEMA_VERSION as i32},
+        };
+
+        let documents: Vec<Document> = self
+            .documents
+            .find(filter)
+            .projection(doc! {"_id": 1})
+            .await
+            .map_err(|e| ProfileRepositoryError::Unknown(e.to_string()))?
+            .try_collect()
+            .await
+            .map_err(|e| ProfileRepositoryError::Unknown(e.to_string()))?;
+
+        Ok(documents
+            .iter()
+            .filter_map(|document| document.get_str("_id").ok())
+            .map(str::to_string)
+            .collect())
+    }
+
+    /// Every stored document in `_id` order and as stored, for maintenance
+    /// tasks that must also see documents the domain would reject. Read them
+    /// with [`profile_schema::read_document`].
+    pub async fn find_all(&self) -> Result<Cursor<Document>, ProfileRepositoryError> {
+        self.documents
+            .find(doc! {})
+            .sort(doc! {"_id": 1})
+            .await
+            .map_err(|e| ProfileRepositoryError::Unknown(e.to_string()))
+    }
+
+    /// Rewrites every document stored in an older shape in the current one,
+    /// `batch_size` documents at a time with a `pause` in between, to spread
+    /// the load. The profile version is kept, as the content is the same.
+    pub async fn upgrade_schema(
+        &self,
+        batch_size: u64,
+        pause: Duration,
+    ) -> Result<SchemaUpgradeSummary, ProfileRepositoryError> {
+        let mut summary = SchemaUpgradeSummary::default();
+        // Avança por _id, para que cada documento seja visitado uma vez
+        let mut last_id: Option<Bson> = None;
+
+        loop {
+            let mut filter = profile_schema::outdated_filter();
+            if let Some(last_id) = &last_id {
+                filter.insert("_id", doc! {"$gt": last_id.clone()});
+            }
+
+            let batch: Vec<Document> = self
+                .documents
+                .find(filter)
+                .sort(doc! {"_id": 1})
+                .limit(batch_size.max(1) as i64)
+                .await
+                .map_err(|e| ProfileRepositoryError::Unknown(e.to_string()))?
+                .try_collect()
+                .await
+                .map_err(|e| ProfileRepositoryError::Unknown(e.to_string()))?;
+
+            if batch.is_empty() {
+                return Ok(summary);
+            }
+
+            for mut document in batch {
+                let id = document.get("_id").cloned().unwrap_or(Bson::Null);
+                let version = document.get("version").cloned().unwrap_or(Bson::Null);
+                last_id = Some(id.clone());
+
+                profile_schema::upcast(&mut document);
+
+                let result = self
+                    .documents
+                    .replace_one(doc! {"_id": id, "version": version}, document)
+                    .await
+                    .map_err(|e| ProfileRepositoryError::Unknown(e.to_string()))?;
+
+                if result.matched_count > 0 {
+                    summary.upgraded += 1;
+                } else {
+                    summary.skipped += 1;
+                }
+            }
+
+            tokio::time::sleep(pause).await;
+        }
+    }
+
+    /// Runs [`Self::upgrade_schema`] once in the background, logging the
+    /// outcome.
+    pub fn upgrade_schema_in_background(&self, batch_size: u64, pause: Duration) {
+        let repository = self.clone();
+
+        tokio::spawn(async move {
+            match repository.upgrade_schema(batch_size, pause).await {
+                Ok(summary) => tracing::info!(
+                    upgraded = summary.upgraded,
+                    skipped = summary.skipped,
+                    "Profile schema upgrade finished"
+                ),
+                Err(e) => tracing::error!(error = %e, "Profile schema upgrade failed"),
+            }
+        });
+    }
+
+    pub async fn stats(&self) -> Result<ProfileCollectionStats, ProfileRepositoryError> {
+        let mut cursor = self
+            .collection
+            .aggregate([doc! {"$collStats": {"storageStats": {}}}])
+            .await
+            .map_err(|e| ProfileRepositoryError::Unknown(e.to_string()))?;
+
+        let storage = match cursor
+            .try_next()
+            .await
+            .map_err(|e| ProfileRepositoryError::Unknown(e.to_string()))?
+        {
+            Some(result) => result
+                .get_document("storageStats")
+                .cloned()
+                .unwrap_or_default(),
+            None => Document::new(),
+        };
+
+        // O servidor devolve int32, int64 ou double conforme o tamanho
+        let number = |key: &str| match storage.get(key) {
+            Some(Bson::Int32(value)) => *value as u64,
+            Some(Bson::Int64(value)) => *value as u64,
+            Some(Bson::Double(value)) => *value as u64,
+            _ => 0,
+        };
+
+        let mut stats = ProfileCollectionStats {
+            documents: number("count"),
+            data_size_bytes: number("size"),
+            average_document_bytes: number("avgObjSize"),
+            storage_size_bytes: number("storageSize"),
+            indexes: number("nindexes"),
+            index_size_bytes: number("totalIndexSize"),
+            ..Default::default()
+        };
+
+        for (field, count) in [
+            ("first_name", &mut stats.with_first_name),
+            ("last_name", &mut stats.with_last_name),
+            ("bio", &mut stats.with_bio),
+            ("profile_image_url", &mut stats.with_profile_image_url),
+        ] {
+            *count = self
+                .collection
+                .count_documents(doc! {field: {"$ne": null}})
+                .await
+                .map_err(|e| ProfileRepositoryError::Unknown(e.to_string()))?;
+        }
+
+        Ok(stats)
     }
 }
 
 #[async_trait::async_trait]
 impl ProfileRepository for MongoProfileRepository {
     async fn save(&self, profile: &Profile) -> Result<(), ProfileRepositoryError> {
-        let doc: ProfileDocument = profile.clone().into();
+        let mut doc: ProfileDocument = profile.clone().into();
 
+        // A criação depende do índice único de _id: entre inserts
+        // concorrentes do mesmo perfil, apenas um vence
         if doc.version == 1 {
-            return match self
-                .collection
-                .insert_one(doc)
-                .await
-                .map_err(|e| ProfileRepositoryError::Unknown(e.to_string()))
-            {
+            return match self.collection.insert_one(doc).await {
                 Ok(_) => Ok(()),
+                Err(e) if is_username_taken(&e) => Err(username_taken(profile)),
+                Err(e) if is_duplicate_key(&e) => Err(ProfileRepositoryError::AlreadyExists(
+                    profile.id().to_string(),
+                )),
                 Err(e) => Err(ProfileRepositoryError::Unknown(e.to_string())),
             };
         }
 
-        let filter = doc! {"_id": doc.id.clone(), "version": doc.version as i64 - 1};
+        self.keep_omitted_fields(std::slice::from_mut(&mut doc))
+            .await?;
 
-        let result = self
-            .collection
-            .replace_one(filter, doc)
-            .await
-            .map_err(|e| ProfileRepositoryError::Unknown(e.to_string()))?;
+        let mut filter = doc! {"_id": doc.id.clone(), "version": doc.version as i64 - 1};
+        filter.extend(profile_schema::writable_filter());
+
+        let result = match self.collection.replace_one(filter, doc).await {
+            Ok(result) => result,
+            Err(e) if is_username_taken(&e) => return Err(username_taken(profile)),
+            Err(e) => return Err(ProfileRepositoryError::Unknown(e.to_string())),
+        };
 
         if result.matched_count == 0 {
-            return Err(ProfileRepositoryError::VersionConflict(
-                profile.id().to_string(),
-            ));
+            let id = profile.id().to_string();
+
+            if self
+                .newer_schema_ids(std::slice::from_ref(&id))
+                .await?
+                .contains(&id)
+            {
+                return Err(newer_schema(&id));
+            }
+
+            return Err(ProfileRepositoryError::VersionConflict(id));
         }
 
         Ok(())
     }
 
     async fn get_profile_by_id(&self, id: &Id) -> Result<Option<Profile>, ProfileRepositoryError> {
-        let document = self
+        self.find_document(id)
+            .await?
+            .map(|document| self.to_profile(document))
+            .transpose()
+    }
+
+    async fn get_complete_profile_by_id(
+        &self,
+        id: &Id,
+    ) -> Result<Option<Profile>, ProfileRepositoryError> {
+        // Ignora a política de leitura: nada pode ficar de fora
+        self.find_document(id)
+            .await?
+            .map(Profile::try_from)
+            .transpose()
+    }
+
+    async fn get_profiles_by_ids(
+        &self,
+        ids: &[Id],
+    ) -> Result<Vec<Profile>, ProfileRepositoryError> {
+        let ids: Vec<String> = ids.iter().map(Id::to_string).collect();
+
+        let mut cursor = self
+            .documents
+            .find(doc! {"_id": {"$in": ids}})
+            .await
+            .map_err(|e| ProfileRepositoryError::Unknown(e.to_string()))?;
+
+        let mut profiles = Vec::new();
+
+        while cursor
+            .advance()
+            .await
+            .map_err(|e| ProfileRepositoryError::Unknown(e.to_string()))?
+        {
+            let document = cursor
+                .deserialize_current()
+                .map_err(|e| ProfileRepositoryError::InvalidData(e.to_string()))?;
+
+            profiles.push(self.to_profile(profile_schema::read_document(document)?)?);
+        }
+
+        Ok(profiles)
+    }
+
+    async fn insert_profiles(
+        &self,
+        profiles: &[Profile],
+    ) -> Result<BatchWriteResult, ProfileRepositoryError> {
+        let mut outcomes: BatchWriteResult = vec![Ok(()); profiles.len()];
+
+        if profiles.is_empty() {
+            return Ok(outcomes);
+        }
+
+        let documents: Vec<ProfileDocument> = profiles.iter().cloned().map(Into::into).collect();
+
+        // Não ordenado: uma falha não impede a inserção dos demais
+        let Err(e) = self.collection.insert_many(documents).ordered(false).await else {
+            return Ok(outcomes);
+        };
+
+        let ErrorKind::InsertMany(failure) = e.kind.as_ref() else {
+            return Err(ProfileRepositoryError::Unknown(e.to_string()));
+        };
+
+        if let Some(write_concern_error) = &failure.write_concern_error {
+            return Err(ProfileRepositoryError::Unknown(
+                write_concern_error.message.clone(),
+            ));
+        }
+
+        for error in failure.write_errors.iter().flatten() {
+            let Some(outcome) = outcomes.get_mut(error.index) else {
+                continue;
+            };
+
+            *outcome = Err(
+                if error.code == DUPLICATE_KEY && error.message.contains(USERNAME_INDEX) {
+                    username_taken(&profiles[error.index])
+                } else if error.code == DUPLICATE_KEY {
+                    ProfileRepositoryError::AlreadyExists(profiles[error.index].id().to_string())
+                } else {
+                    ProfileRepositoryError::Unknown(error.message.clone())
+                },
+            );
+        }
+
+        Ok(outcomes)
+    }
+
+    /// Requires MongoDB 8.0, where `bulkWrite` reports a result per
+    /// operation.
+    async fn update_profiles(
+        &self,
+        profiles: &[Profile],
+    ) -> Result<BatchWriteResult, ProfileRepositoryError> {
+        if profiles.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut documents: Vec<ProfileDocument> =
+            profiles.iter().cloned().map(Into::into).collect();
+        self.keep_omitted_fields(&mut documents).await?;
+
+        let models = documents
+            .into_iter()
+            .map(|doc| {
+                let mut filter = doc! {"_id": doc.id.clone(), "version": doc.version as i64 - 1};
+                filter.extend(profile_schema::writable_filter());
+
+                self.collection.replace_one_model(filter, doc)
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| ProfileRepositoryError::InvalidData(e.to_string()))?;
+
+        let (results, write_errors) = match self
+            .client
+            .bulk_write(models)
+            .ordered(false)
+            .verbose_results()
+            .await
+        {
+            Ok(result) => (Some(result), Default::default()),
+            Err(e) => match *e.kind {
+                ErrorKind::BulkWrite(failure) if failure.write_concern_errors.is_empty() => {
+                    let results = match failure.partial_result {
+                        Some(PartialBulkWriteResult::Verbose(results)) => Some(results),
+                        _ => None,
+                    };
+                    (results, failure.write_errors)
+                }
+                kind => return Err(ProfileRepositoryError::Unknown(kind.to_string())),
+            },
+        };
+
+        let matched = |index: usize| {
+            results
+                .as_ref()
+                .and_then(|results| results.update_results.get(&index))
+                .is_some_and(|result| result.matched_count > 0)
+        };
+
+        let unmatched: Vec<String> = profiles
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| !write_errors.contains_key(index) && !matched(*index))
+            .map(|(_, profile)| profile.id().to_string())
+            .collect();
+        let newer = self.newer_schema_ids(&unmatched).await?;
+
+        Ok(profiles
+            .iter()
+            .enumerate()
+            .map(|(index, profile)| {
+                if let Some(error) = write_errors.get(&index) {
+                    if error.code == DUPLICATE_KEY && error.message.contains(USERNAME_INDEX) {
+                        return Err(username_taken(profile));
+                    }
+                    return Err(ProfileRepositoryError::Unknown(error.message.clone()));
+                }
+
+                let id = profile.id().to_string();
+
+                if matched(index) {
+                    Ok(())
+                } else if newer.contains(&id) {
+                    Err(newer_schema(&id))
+                } else {
+                    Err(ProfileRepositoryError::VersionConflict(id))
+                }
+            })
+            .collect())
+    }
+
+    async fn delete_profile(&self, id: &Id) -> Result<bool, ProfileRepositoryError> {
+        let result = self
             .collection
-            .find_one(doc! {"_id": id.to_string()})
+            .delete_one(doc! {"_id": id.to_string()})
+            .await
+            .map_err(|e| ProfileRepositoryError::Unknown(e.to_string()))?;
+
+        Ok(result.deleted_count > 0)
+    }
+
+    async fn get_profile_by_username(
+        &self,
+        username: &Username,
+    ) -> Result<Option<Profile>, ProfileRepositoryError> {
+        let document = self
+            .documents
+            .find_one(doc! {"username_key": username.key(), "username": username.as_ref()})
+            .await
+            .map_err(|e| ProfileRepositoryError::Unknown(e.to_string()))?;
+
+        document
+            .map(|document| self.to_profile(profile_schema::read_document(document)?))
+            .transpose()
+    }
+
+    async fn get_username_reservation(
+        &self,
+        username: &Username,
+    ) -> Result<Option<UsernameReservation>, ProfileRepositoryError> {
+        let now = mongodb::bson::DateTime::now();
+
+        // O índice TTL remove os expirados com atraso; o filtro cobre a janela
+        let document = self
+            .reservations
+            .find_one(doc! {"_id": username.key(), "expires_at": {"$gt": now}})
+            .await
+            .map_err(|e| ProfileRepositoryError::Unknown(e.to_string()))?;
+
+        document
+            .map(|document| {
+                Ok(UsernameReservation {
+                    username: Username::try_from(document.username)
+                        .map_err(|e| ProfileRepositoryError::InvalidData(e.to_string()))?,
+                    profile_id: Id::try_from(document.profile_id)
+                        .map_err(|e| ProfileRepositoryError::InvalidData(e.to_string()))?,
+                    expires_at: chrono::DateTime::from_timestamp_millis(
+                        document.expires_at.timestamp_millis(),
+                    )
+                    .unwrap_or_default(),
+                })
+            })
+            .transpose()
+    }
+
+    async fn reserve_username(
+        &self,
+        reservation: &UsernameReservation,
+    ) -> Result<(), ProfileRepositoryError> {
+        let key = reservation.username.key();
+
+        let document = UsernameReservationDocument {
+            key: key.clone(),
+            username: reservation.username.to_string(),
+            profile_id: reservation.profile_id.to_string(),
+            expires_at: mongodb::bson::DateTime::from_millis(
+                reservation.expires_at.timestamp_millis(),
+            ),
+        };
+
+        self.reservations
+            .replace_one(doc! {"_id": key}, document)
+            .upsert(true)
             .await
             .map_err(|e| ProfileRepositoryError::Unknown(e.to_string()))?;
 
-        Ok(document.map(Profile::try_from).transpose()?)
+        Ok(())
     }
 }
 
+fn is_username_taken(error: &mongodb::error::Error) -> bool {
+    is_duplicate_key(error) && error.to_string().contains(USERNAME_INDEX)
+}
+
+fn newer_schema(id: &str) -> ProfileRepositoryError {
+    ProfileRepositoryError::Unknown(format!(
+        "Profile {} was written by a newer release and cannot be replaced by this one",
+        id
+    ))
+}
+
+fn username_taken(profile: &Profile) -> ProfileRepositoryError {
+    ProfileRepositoryError::UsernameTaken(
+        profile
+            .username()
+            .map(Username::to_string)
+            .unwrap_or_default(),
+    )
+}
+
 impl From<Profile> for ProfileDocument {
     fn from(profile: Profile) -> Self {
         ProfileDocument {
@@ -98,9 +876,16 @@ impl From<Profile> for ProfileDocument {
             last_name: profile.last_name().map(|l| l.to_string()),
             bio: profile.bio().map(|b| b.to_string()),
             profile_image_url: profile.profile_image_url().map(|u| u.to_string()),
+            privacy_settings: (*profile.privacy_settings()).into(),
+            username: profile.username().map(Username::to_string),
+            username_key: profile.username().map(Username::key),
+            username_changed_at: profile.username_changed_at(),
+            display_name: profile.display_name_override().map(|d| d.to_string()),
+            display_name_format: profile.display_name_format().to_string(),
             created_at: profile.created_at(),
             updated_at: profile.updated_at(),
             version: profile.version(),
+            schema_version: profile_schema::CURRENT_SCHEMA_VERSION,
         }
     }
 }
@@ -133,6 +918,18 @@ impl TryFrom<ProfileDocument> for Profile {
             .map(ImageUrl::try_from)
             .transpose()
             .map_err(|e| ProfileRepositoryError::InvalidData(e.to_string()))?;
+        let username = doc
+            .username
+            .map(Username::try_from)
+            .transpose()
+            .map_err(|e| ProfileRepositoryError::InvalidData(e.to_string()))?;
+        let display_name = doc
+            .display_name
+            .map(DisplayName::try_from)
+            .transpose()
+            .map_err(|e| ProfileRepositoryError::InvalidData(e.to_string()))?;
+        let display_name_format = DisplayNameFormat::try_new(&doc.display_name_format)
+            .map_err(|e| ProfileRepositoryError::InvalidData(e.to_string()))?;
 
         Ok(Profile::from_parts(
             id,
@@ -141,10 +938,13 @@ impl TryFrom<ProfileDocument> for Profile {
             last_name,
             bio,
             profile_image_url,
+            doc.privacy_settings.into(),
             doc.created_at,
             doc.updated_at,
             doc.version,
-        ))
+        )
+        .with_username(username, doc.username_changed_at)
+        .with_display_name(display_name, display_name_format))
     }
 }
 
@@ -161,6 +961,7 @@ mod tests {
             None,
             None,
             None,
+            PrivacySettings::default(),
             chrono::Utc::now(),
             None,
             0,
@@ -172,4 +973,185 @@ mod tests {
         assert_eq!(doc.email, profile.email().to_string());
         assert_eq!(doc.version, profile.version());
     }
+
+    #[test]
+    fn should_keep_privacy_settings_round_trip() {
+        let settings = PrivacySettings::new(false, true, false, true);
+        let profile = Profile::from_parts(
+            Id::generate(),
+            Email::try_from("test@example.com").unwrap(),
+            None,
+            None,
+            None,
+            None,
+            settings,
+            chrono::Utc::now(),
+            None,
+            1,
+        );
+
+        let doc: ProfileDocument = profile.into();
+        let restored = Profile::try_from(doc).unwrap();
+
+        assert_eq!(restored.privacy_settings(), &settings);
+    }
+
+    #[test]
+    fn should_default_privacy_settings_when_missing_from_document() {
+        let doc: Document = serde_json::from_value(serde_json::json!({
+            "_id": Id::generate().to_string(),
+            "email": "test@example.com",
+            "first_name": null,
+            "last_name": null,
+            "bio": null,
+            "profile_image_url": null,
+            "created_at": chrono::Utc::now(),
+            "updated_at": null,
+            "version": 1
+        }))
+        .unwrap();
+
+        let profile = Profile::try_from(profile_schema::read_document(doc).unwrap()).unwrap();
+
+        assert_eq!(profile.privacy_settings(), &PrivacySettings::default());
+    }
+
+    async fn repository(read_policy: ProfileReadPolicy) -> MongoProfileRepository {
+        // O cliente só conecta na primeira operação
+        MongoProfileRepository::new(Arc::new(MongoService::new().await.unwrap()), read_policy)
+    }
+
+    fn stored(email: &str, bio: &str) -> ProfileDocument {
+        serde_json::from_value(serde_json::json!({
+            "_id": Id::generate().to_string(),
+            "email": email,
+            "first_name": "John",
+            "last_name": null,
+            "bio": bio,
+            "profile_image_url": null,
+            "privacy_settings": PrivacySettingsDocument::default(),
+            "display_name_format": DisplayNameFormat::default().to_string(),
+            "created_at": chrono::Utc::now(),
+            "updated_at": null,
+            "version": 1,
+            "schema_version": profile_schema::CURRENT_SCHEMA_VERSION
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn when_policy_is_strict_should_reject_invalid_field() {
+        let repository = repository(ProfileReadPolicy::Strict).await;
+
+        let result = repository.to_profile(stored("test@example.com", "short"));
+
+        assert!(matches!(
+            result,
+            Err(ProfileRepositoryError::InvalidData(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn when_policy_is_lenient_should_omit_invalid_field_and_count_it() {
+        let repository = repository(ProfileReadPolicy::Lenient).await;
+
+        let profile = repository
+            .to_profile(stored("test@example.com", "short"))
+            .unwrap();
+
+        assert!(profile.bio().is_none());
+        assert_eq!(profile.first_name().unwrap().to_string(), "John");
+        assert_eq!(repository.read_metrics().degraded_reads(), 1);
+        assert_eq!(repository.read_metrics().omitted_fields(), 1);
+    }
+
+    #[tokio::test]
+    async fn when_policy_is_lenient_and_document_is_valid_should_not_count_it() {
+        let repository = repository(ProfileReadPolicy::Lenient).await;
+
+        let profile = repository
+            .to_profile(stored("test@example.com", "A perfectly valid bio"))
+            .unwrap();
+
+        assert!(profile.bio().is_some());
+        assert_eq!(repository.read_metrics().degraded_reads(), 0);
+    }
+
+    #[tokio::test]
+    async fn when_policy_is_lenient_and_email_is_invalid_should_still_fail() {
+        let repository = repository(ProfileReadPolicy::Lenient).await;
+
+        let result = repository.to_profile(stored("not-an-email", "short"));
+
+        assert!(matches!(
+            result,
+            Err(ProfileRepositoryError::InvalidData(_))
+        ));
+    }
+
+    #[tokio::test]
+    #[ignore = "requires MongoDB (MONGO_* variables)"]
+    async fn when_document_is_from_a_newer_release_should_refuse_to_replace_it() {
+        let repository = repository(ProfileReadPolicy::Strict).await;
+
+        let mut document =
+            mongodb::bson::to_document(&stored("test@example.com", "A perfectly valid bio"))
+                .unwrap();
+        document.insert(
+            "schema_version",
+            profile_schema::CURRENT_SCHEMA_VERSION as i32 + 1,
+        );
+        document.insert("pronouns", "they/them");
+        let id = Id::try_from(document.get_str("_id").unwrap().to_string()).unwrap();
+        repository.documents.insert_one(document).await.unwrap();
+
+        let mut profile = repository.get_profile_by_id(&id).await.unwrap().unwrap();
+        profile.update_profile(Some(FirstName::try_from("Jane").unwrap()), None, None, None);
+
+        let saved = repository.save(&profile).await;
+        let updated = repository.update_profiles(&[profile]).await;
+        let kept = repository
+            .documents
+            .find_one(doc! {"_id": id.to_string()})
+            .await
+            .unwrap()
+            .unwrap();
+        repository.delete_profile(&id).await.unwrap();
+
+        assert!(matches!(saved, Err(ProfileRepositoryError::Unknown(_))));
+        assert!(matches!(
+            updated.unwrap().as_slice(),
+            [Err(ProfileRepositoryError::Unknown(_))]
+        ));
+        assert_eq!(kept.get_str("pronouns"), Ok("they/them"));
+        assert_eq!(kept.get_str("first_name"), Ok("John"));
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    #[ignore = "requires MongoDB (MONGO_* variables)"]
+    async fn when_same_profile_is_inserted_concurrently_should_have_single_winner() {
+        let repository = repository(ProfileReadPolicy::Strict).await;
+        let profile = Profile::new(Id::generate(), Email::try_from("test@example.com").unwrap());
+
+        let (first, second) = tokio::join!(
+            tokio::spawn({
+                let (repository, profile) = (repository.clone(), profile.clone());
+                async move { repository.save(&profile).await }
+            }),
+            tokio::spawn({
+                let (repository, profile) = (repository.clone(), profile.clone());
+                async move { repository.save(&profile).await }
+            }),
+        );
+        repository.delete_profile(profile.id()).await.unwrap();
+
+        let mut outcomes = [first.unwrap(), second.unwrap()];
+        outcomes.sort_by_key(Result::is_err);
+
+        assert!(outcomes[0].is_ok());
+        assert!(matches!(
+            outcomes[1],
+            Err(ProfileRepositoryError::AlreadyExists(_))
+        ));
+    }
 }
@@ -0,0 +1,92 @@
+use std::{sync::Arc, time::Duration};
+
+use chrono::Utc;
+use mongodb::{
+    Collection, IndexModel,
+    bson::doc,
+    options::{IndexOptions, ReturnDocument},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    domain::repositories::rate_limit_repo::{
+        RateLimitRepository, RateLimitRepositoryError, RateLimitWindow, window_start,
+    },
+    infrastructure::repositories::mongo_service::MongoService,
+};
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RateLimitDocument {
+    #[serde(rename = "_id")]
+    id: String,
+    hits: i64,
+    expires_at: mongodb::bson::DateTime,
+}
+
+/// Counters shared by every replica through the `rate_limits` collection.
+/// Expired windows are removed by a TTL index on `expires_at`.
+#[derive(Clone)]
+pub struct MongoRateLimitRepository {
+    collection: Collection<RateLimitDocument>,
+}
+
+impl MongoRateLimitRepository {
+    pub async fn new(mongo_service: Arc<MongoService>) -> anyhow::Result<Self> {
+        let collection = mongo_service
+            .get_database()
+            .collection::<RateLimitDocument>("rate_limits");
+
+        let ttl_index = IndexModel::builder()
+            .keys(doc! {"expires_at": 1})
+            .options(
+                IndexOptions::builder()
+                    .expire_after(Duration::from_secs(0))
+                    .build(),
+            )
+            .build();
+
+        collection.create_index(ttl_index).await?;
+
+        Ok(MongoRateLimitRepository { collection })
+    }
+}
+
+#[async_trait::async_trait]
+impl RateLimitRepository for MongoRateLimitRepository {
+    async fn hit(
+        &self,
+        key: &str,
+        window: Duration,
+    ) -> Result<RateLimitWindow, RateLimitRepositoryError> {
+        let start = window_start(Utc::now(), window);
+        let resets_at =
+            start + chrono::Duration::from_std(window).unwrap_or(chrono::Duration::zero());
+
+        let id = format!("{}:{}", key, start.timestamp_millis());
+
+        let document = self
+            .collection
+            .find_one_and_update(
+                doc! {"_id": id},
+                doc! {
+                    "$inc": {"hits": 1_i64},
+                    "$setOnInsert": {
+                        "expires_at": mongodb::bson::DateTime::from_millis(resets_at.timestamp_millis()),
+                    },
+                },
+            )
+            .upsert(true)
+            .return_document(ReturnDocument::After)
+            .await
+            .map_err(|e| RateLimitRepositoryError::Unknown(e.to_string()))?
+            .ok_or_else(|| RateLimitRepositoryError::Unknown("Missing counter".to_string()))?;
+
+        Ok(RateLimitWindow {
+            hits: document.hits.max(0) as u64,
+            resets_at: chrono::DateTime::from_timestamp_millis(
+                document.expires_at.timestamp_millis(),
+            )
+            .unwrap_or(resets_at),
+        })
+    }
+}
@@ -1,5 +1,11 @@
 use anyhow::Result;
-use mongodb::{Client, Database, options::ClientOptions};
+use mongodb::{
+    Client, Database,
+    error::{ErrorKind, WriteFailure},
+    options::ClientOptions,
+};
+
+pub const DUPLICATE_KEY: i32 = 11000;
 
 pub struct MongoService {
     client: mongodb::Client,
@@ -33,4 +39,69 @@ impl MongoService {
     pub fn get_database(&self) -> Database {
         self.client.database(&self.database)
     }
+
+    /// Needed for operations spanning collections, such as `bulk_write`.
+    pub fn get_client(&self) -> Client {
+        self.client.clone()
+    }
+}
+
+/// Whether `error` was raised by a unique index (E11000), e.g. an insert
+/// racing another one for the same `_id`.
+pub fn is_duplicate_key(error: &mongodb::error::Error) -> bool {
+    match error.kind.as_ref() {
+        ErrorKind::Write(WriteFailure::WriteError(e)) => e.code == DUPLICATE_KEY,
+        ErrorKind::InsertMany(e) => e
+            .write_errors
+            .iter()
+            .flatten()
+            .any(|e| e.code == DUPLICATE_KEY),
+        ErrorKind::Command(e) => e.code == DUPLICATE_KEY,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mongodb::{
+        bson::{doc, from_document},
+        error::{Error, InsertManyError, WriteError},
+    };
+
+    use super::*;
+
+    fn duplicate(code: i32) -> mongodb::bson::Document {
+        doc! {
+            "code": code,
+            "errmsg": "E11000 duplicate key error collection: profile_service.profiles index: _id_",
+        }
+    }
+
+    #[test]
+    fn when_insert_hits_unique_index_should_be_duplicate_key() {
+        let write_error: WriteError = from_document(duplicate(DUPLICATE_KEY)).unwrap();
+        let error = Error::from(ErrorKind::Write(WriteFailure::WriteError(write_error)));
+
+        assert!(is_duplicate_key(&error));
+    }
+
+    #[test]
+    fn when_any_insert_of_a_batch_hits_unique_index_should_be_duplicate_key() {
+        let mut failed = duplicate(DUPLICATE_KEY);
+        failed.insert("index", 1);
+        let batch: InsertManyError = from_document(doc! {"writeErrors": [failed]}).unwrap();
+
+        assert!(is_duplicate_key(&Error::from(ErrorKind::InsertMany(batch))));
+    }
+
+    #[test]
+    fn when_write_fails_for_another_reason_should_not_be_duplicate_key() {
+        let write_error: WriteError = from_document(duplicate(121)).unwrap();
+        let error = Error::from(ErrorKind::Write(WriteFailure::WriteError(write_error)));
+
+        assert!(!is_duplicate_key(&error));
+        assert!(!is_duplicate_key(&Error::from(
+            std::io::ErrorKind::TimedOut
+        )));
+    }
 }
@@ -0,0 +1,573 @@
+use serde::Serialize;
+use strum_macros::{Display, EnumString};
+
+use crate::{
+    domain::{
+        models::error_code::ErrorCode,
+        object_values::{
+            bio::{Bio, BioError},
+            display_name::{DisplayName, DisplayNameError},
+            email::{Email, EmailError},
+            first_name::{FirstName, FirstNameError},
+            image_url::{ImageUrl, ImageUrlError},
+            last_name::{LastName, LastNameError},
+            username::{Username, UsernameError},
+        },
+    },
+    infrastructure::repositories::mongo_profile_repo::ProfileDocument,
+};
+
+/// How far a repair may go. Each mode also applies the milder ones: a field
+/// is only truncated when normalizing is not enough, and only nulled when
+/// neither fixes it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, EnumString, Display)]
+#[strum(serialize_all = "lowercase")]
+pub enum RepairMode {
+    /// Trims whitespace and fixes case and edge characters
+    Normalize,
+    /// Cuts names and bios down to the maximum length
+    Truncate,
+    /// Removes optional fields that cannot be fixed otherwise
+    Null,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RepairAction {
+    Normalized,
+    Truncated,
+    Nulled,
+    Unrepaired,
+}
+
+/// A stored field that fails today's value-object rules.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldIssue {
+    pub field: &'static str,
+    pub code: ErrorCode,
+    pub value: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<RepairAction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repaired_value: Option<String>,
+}
+
+/// The code of a failed check and, when the value was too long, the
+/// maximum length it must be cut to.
+type Check = fn(&str) -> Result<(), (ErrorCode, Option<usize>)>;
+
+struct Rule {
+    field: &'static str,
+    check: Check,
+    normalize: fn(&str) -> String,
+    // Um email ou URL truncado aponta para outro lugar: melhor anular
+    truncatable: bool,
+    optional: bool,
+}
+
+const EMAIL: Rule = Rule {
+    field: "email",
+    check: |value| {
+        Email::try_new(value.to_string()).map(drop).map_err(|e| {
+            let max = match e {
+                EmailError::TooLong(max) => Some(max),
+                _ => None,
+            };
+            (e.code(), max)
+        })
+    },
+    normalize: |value| value.trim().to_lowercase(),
+    truncatable: false,
+    optional: false,
+};
+
+const FIRST_NAME: Rule = Rule {
+    field: "first_name",
+    check: |value| {
+        FirstName::try_new(value.to_string())
+            .map(drop)
+            .map_err(|e| {
+                let max = match e {
+                    FirstNameError::TooLong(max) => Some(max),
+                    _ => None,
+                };
+                (e.code(), max)
+            })
+    },
+    normalize: normalize_name,
+    truncatable: true,
+    optional: true,
+};
+
+const LAST_NAME: Rule = Rule {
+    field: "last_name",
+    check: |value| {
+        LastName::try_new(value.to_string()).map(drop).map_err(|e| {
+            let max = match e {
+                LastNameError::TooLong(max) => Some(max),
+                _ => None,
+            };
+            (e.code(), max)
+        })
+    },
+    normalize: normalize_name,
+    truncatable: true,
+    optional: true,
+};
+
+const BIO: Rule = Rule {
+    field: "bio",
+    check: |value| {
+        Bio::try_new(value.to_string()).map(drop).map_err(|e| {
+            let max = match e {
+                BioError::TooLong(max) => Some(max),
+                _ => None,
+            };
+            (e.code(), max)
+        })
+    },
+    normalize: |value| value.trim().to_string(),
+    truncatable: true,
+    optional: true,
+};
+
+const PROFILE_IMAGE_URL: Rule = Rule {
+    field: "profile_image_url",
+    check: |value| {
+        ImageUrl::try_new(value.to_string()).map(drop).map_err(|e| {
+            let max = match e {
+                ImageUrlError::TooLong(max) => Some(max),
+                _ => None,
+            };
+            (e.code(), max)
+        })
+    },
+    normalize: normalize_image_url,
+    truncatable: false,
+    optional: true,
+};
+
+const DISPLAY_NAME: Rule = Rule {
+    field: "display_name",
+    check: |value| {
+        DisplayName::try_new(value.to_string())
+            .map(drop)
+            .map_err(|e| {
+                let max = match e {
+                    DisplayNameError::TooLong(max) => Some(max),
+                    _ => None,
+                };
+                (e.code(), max)
+            })
+    },
+    normalize: |value| value.split_whitespace().collect::<Vec<_>>().join(" "),
+    truncatable: true,
+    optional: true,
+};
+
+// Anular o nome liberaria um handle protegido pelo índice único; só o dono
+// o troca. Por isso nunca é reparado, embora a leitura tolerante o omita.
+const USERNAME: Rule = Rule {
+    field: "username",
+    check: |value| {
+        Username::try_new(value.to_string()).map(drop).map_err(|e| {
+            let max = match e {
+                UsernameError::TooLong(max) => Some(max),
+                _ => None,
+            };
+            (e.code(), max)
+        })
+    },
+    normalize: |value| value.to_string(),
+    truncatable: false,
+    optional: false,
+};
+
+/// Checks the validated fields of `document` and, with a `mode`, repairs
+/// them in place. The document is left untouched without one. Documents
+/// whose every issue was repaired convert into a valid `Profile`.
+pub fn inspect(document: &mut ProfileDocument, mode: Option<RepairMode>) -> Vec<FieldIssue> {
+    let mut issues = Vec::new();
+
+    let mut email = Some(std::mem::take(&mut document.email));
+    check_field(&EMAIL, &mut email, mode, &mut issues);
+    document.email = email.unwrap_or_default();
+
+    for (rule, slot) in optional_fields(document) {
+        check_field(rule, slot, mode, &mut issues);
+    }
+
+    issues
+}
+
+/// Removes every optional field that fails validation, for reads that would
+/// rather return part of a profile than none of it. The email is required,
+/// so it is left for the caller to reject.
+pub fn omit_invalid_fields(document: &mut ProfileDocument) -> Vec<FieldIssue> {
+    let mut issues = Vec::new();
+
+    for (rule, slot) in optional_fields(document) {
+        let Some(Err((code, _))) = slot.as_deref().map(rule.check) else {
+            continue;
+        };
+
+        issues.push(FieldIssue {
+            field: rule.field,
+            code,
+            value: slot.take().unwrap_or_default(),
+            action: Some(RepairAction::Nulled),
+            repaired_value: None,
+        });
+    }
+
+    issues
+}
+
+/// Copies back from `stored` the invalid optional fields a lenient read left
+/// out of `document` and the write did not set again, so saving a degraded
+/// profile does not erase them. Returns how many were kept.
+pub fn keep_omitted_fields(document: &mut ProfileDocument, mut stored: ProfileDocument) -> usize {
+    let mut kept = 0;
+
+    for ((rule, slot), (_, stored_slot)) in optional_fields(document)
+        .into_iter()
+        .zip(optional_fields(&mut stored))
+    {
+        let omitted = stored_slot
+            .as_deref()
+            .is_some_and(|value| (rule.check)(value).is_err());
+
+        if slot.is_none() && omitted {
+            *slot = stored_slot.take();
+            kept += 1;
+        }
+    }
+
+    // A chave do índice único acompanha o nome mantido
+    if document.username.is_some() && document.username_key.is_none() {
+        document.username_key = stored.username_key;
+    }
+
+    kept
+}
+
+fn optional_fields(document: &mut ProfileDocument) -> [(&'static Rule, &mut Option<String>); 6] {
+    [
+        (&USERNAME, &mut document.username),
+        (&FIRST_NAME, &mut document.first_name),
+        (&LAST_NAME, &mut document.last_name),
+        (&BIO, &mut document.bio),
+        (&PROFILE_IMAGE_URL, &mut document.profile_image_url),
+        (&DISPLAY_NAME, &mut document.display_name),
+    ]
+}
+
+fn check_field(
+    rule: &Rule,
+    slot: &mut Option<String>,
+    mode: Option<RepairMode>,
+    issues: &mut Vec<FieldIssue>,
+) {
+    let Some(value) = slot.as_deref() else {
+        return;
+    };
+
+    let Err((code, _)) = (rule.check)(value) else {
+        return;
+    };
+
+    let mut issue = FieldIssue {
+        field: rule.field,
+        code,
+        value: value.to_string(),
+        action: None,
+        repaired_value: None,
+    };
+
+    if let Some(mode) = mode {
+        let (action, repaired) = repair(rule, value, mode);
+
+        match action {
+            RepairAction::Nulled => *slot = None,
+            RepairAction::Unrepaired => {}
+            RepairAction::Normalized | RepairAction::Truncated => {
+                issue.repaired_value = repaired.clone();
+                *slot = repaired;
+            }
+        }
+
+        issue.action = Some(action);
+    }
+
+    issues.push(issue);
+}
+
+fn repair(rule: &Rule, value: &str, mode: RepairMode) -> (RepairAction, Option<String>) {
+    let normalized = (rule.normalize)(value);
+
+    match (rule.check)(&normalized) {
+        Ok(()) => return (RepairAction::Normalized, Some(normalized)),
+        Err((_, Some(max))) if mode >= RepairMode::Truncate && rule.truncatable => {
+            let truncated = (rule.normalize)(truncate(&normalized, max));
+
+            if (rule.check)(&truncated).is_ok() {
+                return (RepairAction::Truncated, Some(truncated));
+            }
+        }
+        Err(_) => {}
+    }
+
+    if mode >= RepairMode::Null && rule.optional {
+        (RepairAction::Nulled, None)
+    } else {
+        (RepairAction::Unrepaired, None)
+    }
+}
+
+/// Longest prefix of `value` within `max` bytes, the unit the value objects
+/// measure, that does not split a character.
+fn truncate(value: &str, max: usize) -> &str {
+    let mut end = max.min(value.len());
+
+    while !value.is_char_boundary(end) {
+        end -= 1;
+    }
+
+    &value[..end]
+}
+
+fn normalize_name(value: &str) -> String {
+    value
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .trim_matches(|c: char| !c.is_alphanumeric())
+        .to_string()
+}
+
+/// Lowercases the scheme and the extension, so `HTTPS://…/Me.JPG` passes.
+fn normalize_image_url(value: &str) -> String {
+    let mut url = value.trim().to_string();
+
+    for scheme in ["http://", "https://"] {
+        if url
+            .get(..scheme.len())
+            .is_some_and(|prefix| prefix.eq_ignore_ascii_case(scheme))
+        {
+            url.replace_range(..scheme.len(), scheme);
+        }
+    }
+
+    if let Some(dot) = url.rfind('.') {
+        let extension = url[dot..].to_ascii_lowercase();
+        url.replace_range(dot.., &extension);
+    }
+
+    url
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        domain::{models::profile::Profile, object_values::id::Id},
+        infrastructure::repositories::profile_schema,
+    };
+
+    fn document(fields: serde_json::Value) -> ProfileDocument {
+        let mut document = json!({
+            "_id": Id::generate().to_string(),
+            "email": "john.doe@example.com",
+            "first_name": null,
+            "last_name": null,
+            "bio": null,
+            "profile_image_url": null,
+            "created_at": chrono::Utc::now(),
+            "updated_at": null,
+            "version": 1
+        });
+
+        for (key, value) in fields.as_object().unwrap() {
+            document[key] = value.clone();
+        }
+
+        profile_schema::read_document(serde_json::from_value(document).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn when_document_is_valid_should_report_nothing() {
+        let mut document = document(json!({"first_name": "John", "bio": "A perfectly valid bio"}));
+
+        assert!(inspect(&mut document, Some(RepairMode::Null)).is_empty());
+    }
+
+    #[test]
+    fn when_scanning_without_mode_should_report_and_leave_document_alone() {
+        let mut document = document(json!({"first_name": "Maximilianus Augustus"}));
+
+        let issues = inspect(&mut document, None);
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].field, "first_name");
+        assert_eq!(issues[0].code, ErrorCode::FirstNameTooLong);
+        assert_eq!(issues[0].action, None);
+        assert_eq!(
+            document.first_name.as_deref(),
+            Some("Maximilianus Augustus")
+        );
+    }
+
+    #[test]
+    fn when_normalizing_is_enough_should_not_truncate() {
+        let mut document = document(json!({
+            "email": "  John.Doe@Example.com",
+            "last_name": "-Doe-",
+            "profile_image_url": "HTTPS://cdn.example.com/me.JPG"
+        }));
+
+        let issues = inspect(&mut document, Some(RepairMode::Truncate));
+
+        assert_eq!(issues.len(), 2);
+        assert!(
+            issues
+                .iter()
+                .all(|issue| issue.action == Some(RepairAction::Normalized))
+        );
+        assert_eq!(document.last_name.as_deref(), Some("Doe"));
+        assert_eq!(
+            document.profile_image_url.as_deref(),
+            Some("https://cdn.example.com/me.jpg")
+        );
+        // Já era válido: a validação ignora os espaços
+        assert_eq!(document.email, "  John.Doe@Example.com");
+    }
+
+    #[test]
+    fn when_value_is_too_long_should_truncate_on_a_char_boundary() {
+        let mut document = document(json!({"first_name": "Aaaaaaaaaaaaaaãbb"}));
+
+        let issues = inspect(&mut document, Some(RepairMode::Truncate));
+
+        assert_eq!(issues[0].action, Some(RepairAction::Truncated));
+        assert_eq!(document.first_name.as_deref(), Some("Aaaaaaaaaaaaaa"));
+        assert!(FirstName::try_new(document.first_name.clone().unwrap()).is_ok());
+    }
+
+    #[test]
+    fn when_display_name_is_too_long_should_truncate_it() {
+        let mut document = document(json!({"display_name": format!("Johnny  {}", "D".repeat(50))}));
+
+        let issues = inspect(&mut document, Some(RepairMode::Truncate));
+
+        assert_eq!(issues[0].field, "display_name");
+        assert_eq!(issues[0].action, Some(RepairAction::Truncated));
+        assert_eq!(document.display_name.as_ref().map(String::len), Some(50));
+        assert!(Profile::try_from(document).is_ok());
+    }
+
+    #[test]
+    fn when_mode_does_not_reach_the_fix_should_report_unrepaired() {
+        let mut document = document(json!({"bio": "short"}));
+
+        let issues = inspect(&mut document, Some(RepairMode::Truncate));
+
+        assert_eq!(issues[0].action, Some(RepairAction::Unrepaired));
+        assert_eq!(document.bio.as_deref(), Some("short"));
+    }
+
+    #[test]
+    fn when_nulling_is_allowed_should_remove_unfixable_optional_fields() {
+        let mut document = document(json!({
+            "bio": "short",
+            "profile_image_url": "ftp://example.com/me.png"
+        }));
+
+        let issues = inspect(&mut document, Some(RepairMode::Null));
+
+        assert!(
+            issues
+                .iter()
+                .all(|issue| issue.action == Some(RepairAction::Nulled))
+        );
+        assert!(Profile::try_from(document).is_ok());
+    }
+
+    #[test]
+    fn when_omitting_should_drop_only_invalid_optional_fields() {
+        let mut document = document(json!({
+            "email": "not-an-email",
+            "first_name": "John",
+            "bio": "short"
+        }));
+
+        let issues = omit_invalid_fields(&mut document);
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].code, ErrorCode::BioTooShort);
+        assert_eq!(document.bio, None);
+        assert_eq!(document.first_name.as_deref(), Some("John"));
+        assert_eq!(document.email, "not-an-email");
+    }
+
+    #[test]
+    fn when_writing_degraded_profile_should_keep_omitted_fields_it_did_not_set() {
+        let stored =
+            document(json!({"bio": "short", "profile_image_url": "ftp://example.com/me.png"}));
+        let mut written = stored.clone();
+        omit_invalid_fields(&mut written);
+        written.profile_image_url = Some("https://cdn.example.com/me.png".to_string());
+
+        let kept = keep_omitted_fields(&mut written, stored);
+
+        assert_eq!(kept, 1);
+        assert_eq!(written.bio.as_deref(), Some("short"));
+        assert_eq!(
+            written.profile_image_url.as_deref(),
+            Some("https://cdn.example.com/me.png")
+        );
+    }
+
+    #[test]
+    fn when_username_breaks_current_rules_should_report_but_never_release_it() {
+        let mut document = document(json!({"username": "admin", "username_key": "admin"}));
+
+        let issues = inspect(&mut document, Some(RepairMode::Null));
+
+        assert_eq!(issues[0].field, "username");
+        assert_eq!(issues[0].code, ErrorCode::UsernameReserved);
+        assert_eq!(issues[0].action, Some(RepairAction::Unrepaired));
+        assert_eq!(document.username.as_deref(), Some("admin"));
+    }
+
+    #[test]
+    fn when_username_is_omitted_should_keep_it_and_its_key_on_write() {
+        let stored = document(json!({"username": "admin", "username_key": "admin"}));
+        let mut read = stored.clone();
+
+        let issues = omit_invalid_fields(&mut read);
+
+        assert_eq!(issues[0].field, "username");
+        assert!(Profile::try_from(read.clone()).is_ok());
+
+        let mut written = ProfileDocument::from(Profile::try_from(read).unwrap());
+        let kept = keep_omitted_fields(&mut written, stored);
+
+        assert_eq!(kept, 1);
+        assert_eq!(written.username.as_deref(), Some("admin"));
+        assert_eq!(written.username_key.as_deref(), Some("admin"));
+    }
+
+    #[test]
+    fn when_email_cannot_be_fixed_should_never_null_it() {
+        let mut document = document(json!({"email": "not-an-email"}));
+
+        let issues = inspect(&mut document, Some(RepairMode::Null));
+
+        assert_eq!(issues[0].code, ErrorCode::EmailInvalid);
+        assert_eq!(issues[0].action, Some(RepairAction::Unrepaired));
+        assert_eq!(document.email, "not-an-email");
+    }
+}
@@ -0,0 +1,270 @@
+use mongodb::bson::{self, Bson, Document, doc};
+
+use crate::{
+    domain::{
+        object_values::{display_name::DisplayNameFormat, privacy_settings::PrivacySettings},
+        repositories::profile_repo::ProfileRepositoryError,
+    },
+    infrastructure::repositories::mongo_profile_repo::{PrivacySettingsDocument, ProfileDocument},
+};
+
+/// Shape of the documents written by this release. Bump it, and add an
+/// upcaster from the previous shape, whenever `ProfileDocument` changes.
+///
+/// 1. Documents written before privacy settings existed, and before
+///    `schema_version` itself: no `privacy_settings`.
+/// 2. `privacy_settings` holds the visibility of each optional field.
+/// 3. `username`, `username_key` and `username_changed_at` hold the handle.
+/// 4. `display_name` and `display_name_format` hold how the profile is named.
+pub const CURRENT_SCHEMA_VERSION: u32 = 4;
+
+type Upcaster = fn(&mut Document);
+
+/// `UPCASTERS[n]` turns a version `n + 1` document into version `n + 2`.
+const UPCASTERS: [Upcaster; (CURRENT_SCHEMA_VERSION - 1) as usize] = [v1_to_v2, v2_to_v3, v3_to_v4];
+
+/// Version of a stored document; those without one predate versioning.
+pub fn schema_version(document: &Document) -> u32 {
+    match document.get("schema_version") {
+        Some(Bson::Int32(version)) => *version as u32,
+        Some(Bson::Int64(version)) => *version as u32,
+        _ => 1,
+    }
+}
+
+/// Brings `document` up to the current shape, one version at a time, and
+/// tells whether it changed. A document from a newer release is left as it
+/// is: additive changes still read, as unknown fields are ignored. Writing
+/// it back would drop those fields, so writes go through
+/// [`writable_filter`].
+pub fn upcast(document: &mut Document) -> bool {
+    let from = schema_version(document);
+
+    if from >= CURRENT_SCHEMA_VERSION {
+        return false;
+    }
+
+    for upcaster in &UPCASTERS[(from.max(1) - 1) as usize..] {
+        upcaster(document);
+    }
+
+    document.insert("schema_version", CURRENT_SCHEMA_VERSION as i32);
+
+    true
+}
+
+/// Upcasts a stored document and deserializes it.
+pub fn read_document(mut document: Document) -> Result<ProfileDocument, ProfileRepositoryError> {
+    upcast(&mut document);
+
+    bson::from_document(document).map_err(|e| ProfileRepositoryError::InvalidData(e.to_string()))
+}
+
+/// Filter matching the documents `upcast` would change.
+pub fn outdated_filter() -> Document {
+    doc! {
+        "$or": [
+            {"schema_version": {"$exists": false}},
+            {"schema_version": {"$lt": CURRENT_SCHEMA_VERSION as i32}},
+        ]
+    }
+}
+
+/// Filter matching the documents this release may replace. One written by a
+/// newer release, e.g. during a rolling deploy or after a rollback, holds
+/// fields this release would drop.
+pub fn writable_filter() -> Document {
+    // $not também casa documentos sem schema_version
+    doc! {"schema_version": {"$not": {"$gt": CURRENT_SCHEMA_VERSION as i32}}}
+}
+
+fn v1_to_v2(document: &mut Document) {
+    // Documentos gravados logo após a criação das configurações já as têm
+    if document.contains_key("privacy_settings") {
+        return;
+    }
+
+    let settings = PrivacySettingsDocument::from(PrivacySettings::default());
+
+    if let Ok(settings) = bson::to_bson(&settings) {
+        document.insert("privacy_settings", settings);
+    }
+}
+
+fn v2_to_v3(document: &mut Document) {
+    for field in ["username", "username_key", "username_changed_at"] {
+        if !document.contains_key(field) {
+            document.insert(field, Bson::Null);
+        }
+    }
+}
+
+fn v3_to_v4(document: &mut Document) {
+    if !document.contains_key("display_name") {
+        document.insert("display_name", Bson::Null);
+    }
+    if !document.contains_key("display_name_format") {
+        document.insert(
+            "display_name_format",
+            DisplayNameFormat::default().to_string(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{models::profile::Profile, object_values::id::Id};
+
+    /// As written by the first release.
+    fn v1_document() -> Document {
+        doc! {
+            "_id": Id::generate().to_string(),
+            "email": "john.doe@example.com",
+            "first_name": "John",
+            "last_name": null,
+            "bio": null,
+            "profile_image_url": null,
+            "created_at": "2025-01-10T12:00:00Z",
+            "updated_at": null,
+            "version": 3_i64,
+        }
+    }
+
+    /// As written once privacy settings existed, before `schema_version`.
+    fn v2_untagged_document() -> Document {
+        let mut document = v1_document();
+        document.insert(
+            "privacy_settings",
+            doc! {
+                "first_name_visible": false,
+                "last_name_visible": true,
+                "bio_visible": false,
+                "profile_image_url_visible": true,
+            },
+        );
+        document
+    }
+
+    /// As written once `schema_version` existed, before usernames.
+    fn v2_document() -> Document {
+        let mut document = v2_untagged_document();
+        document.insert("schema_version", 2_i32);
+        document
+    }
+
+    /// As written once usernames existed, before display names.
+    fn v3_document() -> Document {
+        let mut document = v2_document();
+        document.insert("username", "john.doe");
+        document.insert("username_key", "john_doe");
+        document.insert("username_changed_at", "2025-02-01T08:30:00Z");
+        document.insert("schema_version", 3_i32);
+        document
+    }
+
+    /// As written by this release.
+    fn v4_document() -> Document {
+        let mut document = v3_document();
+        document.insert("display_name", "Johnny");
+        document.insert("display_name_format", "family_given");
+        document.insert("schema_version", 4_i32);
+        document
+    }
+
+    #[test]
+    fn when_document_is_v1_should_add_default_privacy_settings() {
+        let mut document = v1_document();
+
+        assert_eq!(schema_version(&document), 1);
+        assert!(upcast(&mut document));
+        assert_eq!(schema_version(&document), CURRENT_SCHEMA_VERSION);
+
+        let profile = Profile::try_from(read_document(v1_document()).unwrap()).unwrap();
+
+        assert_eq!(profile.privacy_settings(), &PrivacySettings::default());
+        assert_eq!(profile.version(), 3);
+    }
+
+    #[test]
+    fn when_v2_document_is_untagged_should_keep_its_privacy_settings() {
+        let mut document = v2_untagged_document();
+
+        assert!(upcast(&mut document));
+
+        let profile = Profile::try_from(read_document(document).unwrap()).unwrap();
+
+        assert!(!profile.privacy_settings().first_name_visible());
+        assert!(!profile.privacy_settings().bio_visible());
+    }
+
+    #[test]
+    fn when_document_is_v2_should_have_no_username() {
+        let mut document = v2_document();
+
+        assert_eq!(schema_version(&document), 2);
+        assert!(upcast(&mut document));
+        assert_eq!(document.get("username"), Some(&Bson::Null));
+
+        let profile = Profile::try_from(read_document(document).unwrap()).unwrap();
+
+        assert!(profile.username().is_none());
+        assert!(!profile.privacy_settings().first_name_visible());
+    }
+
+    #[test]
+    fn when_document_is_v3_should_compose_display_name_from_names() {
+        let mut document = v3_document();
+
+        assert_eq!(schema_version(&document), 3);
+        assert!(upcast(&mut document));
+
+        let profile = Profile::try_from(read_document(document).unwrap()).unwrap();
+
+        assert!(profile.display_name_override().is_none());
+        assert_eq!(
+            profile.display_name_format(),
+            DisplayNameFormat::GivenFamily
+        );
+        assert_eq!(profile.display_name().as_ref(), "John");
+        assert_eq!(profile.username().unwrap().as_ref(), "john.doe");
+    }
+
+    #[test]
+    fn when_document_is_current_should_leave_it_alone() {
+        let mut document = v4_document();
+        let stored = document.clone();
+
+        assert!(!upcast(&mut document));
+        assert_eq!(document, stored);
+
+        let profile = Profile::try_from(read_document(document).unwrap()).unwrap();
+
+        assert_eq!(profile.display_name().as_ref(), "Johnny");
+        assert_eq!(
+            profile.display_name_format(),
+            DisplayNameFormat::FamilyGiven
+        );
+    }
+
+    #[test]
+    fn when_document_is_from_a_newer_release_should_read_it_as_is() {
+        let mut document = v4_document();
+        document.insert("schema_version", 5_i32);
+        document.insert("pronouns", "they/them");
+
+        assert!(!upcast(&mut document));
+        assert!(read_document(document).is_ok());
+    }
+
+    #[test]
+    fn when_profile_is_written_should_carry_current_version() {
+        let document = read_document(v1_document()).unwrap();
+        let profile = Profile::try_from(document).unwrap();
+
+        let written = bson::to_document(&ProfileDocument::from(profile)).unwrap();
+
+        assert_eq!(schema_version(&written), CURRENT_SCHEMA_VERSION);
+        assert!(!outdated_filter().is_empty());
+    }
+}
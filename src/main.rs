@@ -1,30 +1,106 @@
-use std::sync::Arc;
+use std::{num::NonZeroUsize, sync::Arc};
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
 use dotenvy::dotenv;
-use presentation::api::service::Service;
-
-use crate::infrastructure::repositories::{
-    mongo_profile_repo::MongoProfileRepository, mongo_service::MongoService,
+use profile_service::{
+    domain::repositories::{
+        idempotency_repo::IdempotencyRepository, profile_cache::ProfileCache,
+        rate_limit_repo::RateLimitRepository,
+    },
+    infrastructure::repositories::{
+        cached_profile_repo::CachedProfileRepository,
+        memory_idempotency_repo::MemoryIdempotencyRepository,
+        memory_profile_cache::MemoryProfileCache,
+        memory_rate_limit_repo::MemoryRateLimitRepository,
+        mongo_idempotency_repo::MongoIdempotencyRepository,
+        mongo_profile_repo::{MongoProfileRepository, ProfileReadPolicy},
+        mongo_rate_limit_repo::MongoRateLimitRepository,
+        mongo_service::MongoService,
+        redis_profile_cache::RedisProfileCache,
+    },
+    presentation::api::{
+        service::Service,
+        settings::{IdempotencyBackend, ProfileCacheBackend, RateLimitBackend, ServiceSettings},
+    },
 };
 
-pub mod application;
-pub mod domain;
-pub mod infrastructure;
-pub mod presentation;
-
 #[tokio::main]
 async fn main() -> Result<()> {
     dotenv().ok();
 
-    let mongo_service = MongoService::new().await?;
-    let profile_repository = MongoProfileRepository::new(Arc::new(mongo_service));
+    let settings = ServiceSettings::from_env()?;
+
+    let mongo_service = Arc::new(MongoService::new().await?);
+    let profile_repository =
+        MongoProfileRepository::new(Arc::clone(&mongo_service), settings.profile_read.policy);
+    profile_repository.ensure_indexes().await?;
+
+    if settings.profile_read.policy == ProfileReadPolicy::Lenient {
+        profile_repository
+            .read_metrics()
+            .report_every(settings.profile_read.metrics_interval);
+    }
+
+    if settings.profile_schema.upgrade_in_background {
+        profile_repository.upgrade_schema_in_background(
+            settings.profile_schema.upgrade_batch_size,
+            settings.profile_schema.upgrade_pause,
+        );
+    }
+
+    let rate_limit_repository: Arc<dyn RateLimitRepository> = match settings.rate_limit.backend {
+        RateLimitBackend::Memory => Arc::new(MemoryRateLimitRepository::new()),
+        RateLimitBackend::Mongo => {
+            Arc::new(MongoRateLimitRepository::new(Arc::clone(&mongo_service)).await?)
+        }
+    };
+
+    let idempotency_repository: Arc<dyn IdempotencyRepository> = match settings.idempotency.backend
+    {
+        IdempotencyBackend::Memory => Arc::new(MemoryIdempotencyRepository::new()),
+        IdempotencyBackend::Mongo => {
+            Arc::new(MongoIdempotencyRepository::new(Arc::clone(&mongo_service)).await?)
+        }
+    };
+
+    let profile_cache: Option<Arc<dyn ProfileCache>> = match &settings.profile_cache.backend {
+        ProfileCacheBackend::Disabled => None,
+        ProfileCacheBackend::Memory => Some(Arc::new(MemoryProfileCache::new(
+            NonZeroUsize::new(settings.profile_cache.capacity)
+                .ok_or_else(|| anyhow!("PROFILE_CACHE_CAPACITY must be greater than zero"))?,
+            settings.profile_cache.ttl,
+        ))),
+        ProfileCacheBackend::Redis { url } => Some(Arc::new(RedisProfileCache::new(
+            url,
+            settings.profile_cache.ttl,
+        )?)),
+    };
 
-    let request_host =
-        std::env::var("REQUEST_HOST").unwrap_or_else(|_| "http://localhost:3000".into());
-    let service_addr = std::env::var("SERVICE_ADDR").unwrap_or_else(|_| "0.0.0.0:3000".into());
+    match profile_cache {
+        Some(cache) => {
+            let profile_repository = CachedProfileRepository::new(profile_repository, cache);
+            profile_repository
+                .metrics()
+                .report_every(settings.profile_cache.metrics_interval);
 
-    Service::run(profile_repository, request_host, service_addr).await;
+            Service::run(
+                profile_repository,
+                rate_limit_repository,
+                idempotency_repository,
+                settings,
+            )
+            .await
+        }
+        None => {
+            Service::run(
+                profile_repository,
+                rate_limit_repository,
+                idempotency_repository,
+                settings,
+            )
+            .await
+        }
+    }
 
     Ok(())
 }
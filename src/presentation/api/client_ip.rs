@@ -0,0 +1,279 @@
+use std::{
+    net::{IpAddr, SocketAddr},
+    str::FromStr,
+    sync::Arc,
+};
+
+use axum::{
+    extract::{ConnectInfo, FromRequestParts},
+    http::{Extensions, HeaderMap, StatusCode, request::Parts},
+};
+use ipnet::IpNet;
+
+use crate::{domain::models::error_code::ErrorCode, presentation::api::utils::AppErrorResponse};
+
+const FORWARDED: &str = "forwarded";
+const X_FORWARDED_FOR: &str = "x-forwarded-for";
+
+/// Networks of the load balancers and proxies allowed to report the client
+/// address through `Forwarded` or `X-Forwarded-For`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TrustedProxies(Vec<IpNet>);
+
+impl TrustedProxies {
+    pub fn new(networks: Vec<IpNet>) -> Self {
+        Self(networks)
+    }
+
+    pub fn contains(&self, ip: &IpAddr) -> bool {
+        self.0.iter().any(|network| network.contains(ip))
+    }
+}
+
+impl FromStr for TrustedProxies {
+    type Err = ipnet::AddrParseError;
+
+    /// Parses a comma separated list of CIDRs; plain addresses are accepted
+    /// as single host networks.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        value
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(|entry| {
+                entry
+                    .parse::<IpNet>()
+                    .or_else(|e| entry.parse::<IpAddr>().map(IpNet::from).map_err(|_| e))
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self)
+    }
+}
+
+/// Address of the client that originated the request, resolved through the
+/// trusted proxies in front of the service.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClientIp(pub IpAddr);
+
+impl ClientIp {
+    /// Needs the peer address from `ConnectInfo`; the trusted proxies are read
+    /// from the `Arc<TrustedProxies>` extension installed by the service.
+    pub fn resolve(headers: &HeaderMap, extensions: &Extensions) -> Option<Self> {
+        let ConnectInfo(peer) = extensions.get::<ConnectInfo<SocketAddr>>()?;
+
+        let client = match extensions.get::<Arc<TrustedProxies>>() {
+            Some(trusted) => resolve_client_ip(peer.ip(), headers, trusted),
+            None => peer.ip(),
+        };
+
+        Some(Self(client))
+    }
+}
+
+impl<S> FromRequestParts<S> for ClientIp
+where
+    S: Send + Sync,
+{
+    type Rejection = AppErrorResponse;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Self::resolve(&parts.headers, &parts.extensions).ok_or_else(|| {
+            AppErrorResponse::new(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                ErrorCode::ClientAddressUnavailable,
+                "Client address unavailable",
+            )
+        })
+    }
+}
+
+/// Walks the forwarding chain from the closest hop back, skipping trusted
+/// proxies. The first untrusted address is the client; headers are ignored
+/// entirely when the peer itself is not a trusted proxy, since anyone can
+/// send them.
+pub fn resolve_client_ip(peer: IpAddr, headers: &HeaderMap, trusted: &TrustedProxies) -> IpAddr {
+    if !trusted.contains(&peer) {
+        return peer;
+    }
+
+    let mut client = peer;
+
+    for hop in forwarded_chain(headers).into_iter().rev() {
+        let Some(ip) = hop else {
+            break;
+        };
+
+        client = ip;
+
+        if !trusted.contains(&ip) {
+            break;
+        }
+    }
+
+    client
+}
+
+/// Hops reported by the proxies, from the original client to the last proxy.
+/// `Forwarded` takes precedence over `X-Forwarded-For`; hops that are not IP
+/// addresses (`unknown`, obfuscated identifiers) are `None`.
+fn forwarded_chain(headers: &HeaderMap) -> Vec<Option<IpAddr>> {
+    let forwarded = header_values(headers, FORWARDED);
+
+    if !forwarded.is_empty() {
+        return forwarded
+            .iter()
+            .flat_map(|value| value.split(','))
+            .map(|element| {
+                element
+                    .split(';')
+                    .filter_map(|pair| pair.split_once('='))
+                    .find(|(name, _)| name.trim().eq_ignore_ascii_case("for"))
+                    .and_then(|(_, value)| parse_node(value))
+            })
+            .collect();
+    }
+
+    header_values(headers, X_FORWARDED_FOR)
+        .iter()
+        .flat_map(|value| value.split(','))
+        .map(parse_node)
+        .collect()
+}
+
+fn header_values<'a>(headers: &'a HeaderMap, name: &str) -> Vec<&'a str> {
+    headers
+        .get_all(name)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .collect()
+}
+
+/// Parses `192.0.2.1`, `192.0.2.1:8080`, `"[2001:db8::1]:4711"` or `2001:db8::1`.
+fn parse_node(value: &str) -> Option<IpAddr> {
+    let value = value.trim().trim_matches('"');
+
+    if let Some(rest) = value.strip_prefix('[') {
+        return rest.split_once(']')?.0.parse().ok();
+    }
+
+    value.parse::<IpAddr>().ok().or_else(|| {
+        value
+            .parse::<SocketAddr>()
+            .ok()
+            .map(|socket_addr| socket_addr.ip())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::HeaderValue;
+
+    use super::*;
+
+    fn trusted() -> TrustedProxies {
+        "10.0.0.0/8, 192.168.1.1".parse().unwrap()
+    }
+
+    fn ip(value: &str) -> IpAddr {
+        value.parse().unwrap()
+    }
+
+    fn headers(name: &'static str, values: &[&'static str]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for value in values {
+            headers.append(name, HeaderValue::from_static(value));
+        }
+        headers
+    }
+
+    #[test]
+    fn when_trusted_proxies_are_invalid_should_return_error() {
+        assert!("10.0.0.0/33".parse::<TrustedProxies>().is_err());
+        assert!("not-an-ip".parse::<TrustedProxies>().is_err());
+    }
+
+    #[test]
+    fn when_peer_is_not_trusted_should_ignore_forwarded_headers() {
+        let headers = headers(X_FORWARDED_FOR, &["203.0.113.7"]);
+
+        let client = resolve_client_ip(ip("198.51.100.1"), &headers, &trusted());
+
+        assert_eq!(client, ip("198.51.100.1"));
+    }
+
+    #[test]
+    fn when_peer_is_trusted_should_use_x_forwarded_for() {
+        let headers = headers(X_FORWARDED_FOR, &["203.0.113.7"]);
+
+        let client = resolve_client_ip(ip("10.0.0.5"), &headers, &trusted());
+
+        assert_eq!(client, ip("203.0.113.7"));
+    }
+
+    #[test]
+    fn when_chain_has_several_trusted_proxies_should_skip_them() {
+        let headers = headers(X_FORWARDED_FOR, &["203.0.113.7, 192.168.1.1", "10.1.2.3"]);
+
+        let client = resolve_client_ip(ip("10.0.0.5"), &headers, &trusted());
+
+        assert_eq!(client, ip("203.0.113.7"));
+    }
+
+    #[test]
+    fn when_client_spoofs_leftmost_entry_should_use_first_untrusted_hop() {
+        let headers = headers(X_FORWARDED_FOR, &["1.2.3.4, 203.0.113.7"]);
+
+        let client = resolve_client_ip(ip("10.0.0.5"), &headers, &trusted());
+
+        assert_eq!(client, ip("203.0.113.7"));
+    }
+
+    #[test]
+    fn when_forwarded_header_is_present_should_take_precedence() {
+        let mut headers = headers(
+            FORWARDED,
+            &["for=\"[2001:db8:cafe::17]:4711\";proto=https, For=10.0.0.9"],
+        );
+        headers.append(X_FORWARDED_FOR, HeaderValue::from_static("203.0.113.7"));
+
+        let client = resolve_client_ip(ip("10.0.0.5"), &headers, &trusted());
+
+        assert_eq!(client, ip("2001:db8:cafe::17"));
+    }
+
+    #[test]
+    fn when_hop_is_not_an_address_should_stop_at_last_known_hop() {
+        let headers = headers(FORWARDED, &["for=203.0.113.7, for=unknown, for=10.0.0.9"]);
+
+        let client = resolve_client_ip(ip("10.0.0.5"), &headers, &trusted());
+
+        assert_eq!(client, ip("10.0.0.9"));
+    }
+
+    #[test]
+    fn when_request_has_no_connect_info_should_not_resolve() {
+        let (parts, _) = axum::http::Request::new(()).into_parts();
+
+        assert_eq!(ClientIp::resolve(&parts.headers, &parts.extensions), None);
+    }
+
+    #[test]
+    fn when_request_comes_through_trusted_proxy_should_resolve_client() {
+        let mut request = axum::http::Request::new(());
+        request.headers_mut().insert(
+            X_FORWARDED_FOR,
+            HeaderValue::from_static("203.0.113.7:5555"),
+        );
+        request
+            .extensions_mut()
+            .insert(ConnectInfo(SocketAddr::from(([10, 0, 0, 5], 443))));
+        request.extensions_mut().insert(Arc::new(trusted()));
+
+        let (parts, _) = request.into_parts();
+
+        assert_eq!(
+            ClientIp::resolve(&parts.headers, &parts.extensions),
+            Some(ClientIp(ip("203.0.113.7")))
+        );
+    }
+}
@@ -0,0 +1,133 @@
+use axum::http::{
+    HeaderMap, HeaderValue,
+    header::{ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED},
+};
+use chrono::{DateTime, Utc};
+
+/// Full representation, containing fields hidden from other callers.
+pub const PRIVATE_CACHE: &str = "private, no-cache";
+/// Public representation; shared caches may keep it but must revalidate.
+pub const PUBLIC_CACHE: &str = "public, no-cache";
+
+/// Formats `date` as an IMF-fixdate (`Wed, 01 Jul 2026 00:00:00 GMT`).
+pub fn http_date(date: DateTime<Utc>) -> String {
+    date.format("%a, %d %b %Y %H:%M:%S GMT").to_string()
+}
+
+/// Validators of one representation, compared against the conditional
+/// headers of a request to decide whether the client copy is still current.
+#[derive(Debug, Clone)]
+pub struct Validators {
+    pub etag: HeaderValue,
+    pub last_modified: DateTime<Utc>,
+}
+
+impl Validators {
+    pub fn new(etag: HeaderValue, last_modified: DateTime<Utc>) -> Self {
+        Self {
+            etag,
+            last_modified,
+        }
+    }
+
+    /// Whether a 304 can be returned. `If-None-Match` wins over
+    /// `If-Modified-Since` when both are sent (RFC 9110, section 13.2.2).
+    pub fn not_modified(&self, headers: &HeaderMap) -> bool {
+        if headers.contains_key(IF_NONE_MATCH) {
+            return self.matches_any(headers);
+        }
+
+        headers
+            .get(IF_MODIFIED_SINCE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| DateTime::parse_from_rfc2822(value).ok())
+            .is_some_and(|since| self.last_modified.timestamp() <= since.timestamp())
+    }
+
+    /// Weak comparison, as required for `If-None-Match`.
+    fn matches_any(&self, headers: &HeaderMap) -> bool {
+        let etag = weak(self.etag.to_str().unwrap_or_default());
+
+        headers
+            .get_all(IF_NONE_MATCH)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .map(str::trim)
+            .any(|candidate| candidate == "*" || weak(candidate) == etag)
+    }
+
+    pub fn apply(&self, headers: &mut HeaderMap) {
+        headers.insert(ETAG, self.etag.clone());
+
+        if let Ok(last_modified) = HeaderValue::from_str(&http_date(self.last_modified)) {
+            headers.insert(LAST_MODIFIED, last_modified);
+        }
+    }
+}
+
+fn weak(etag: &str) -> &str {
+    etag.strip_prefix("W/").unwrap_or(etag)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn validators() -> Validators {
+        Validators::new(
+            HeaderValue::from_static("\"3\""),
+            Utc.with_ymd_and_hms(2026, 7, 1, 12, 30, 15).unwrap(),
+        )
+    }
+
+    fn headers(name: axum::http::HeaderName, value: &'static str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(name, HeaderValue::from_static(value));
+        headers
+    }
+
+    #[test]
+    fn when_if_none_match_lists_current_etag_should_be_not_modified() {
+        assert!(validators().not_modified(&headers(IF_NONE_MATCH, "\"3\"")));
+        assert!(validators().not_modified(&headers(IF_NONE_MATCH, "\"1\", W/\"3\"")));
+        assert!(validators().not_modified(&headers(IF_NONE_MATCH, "*")));
+        assert!(!validators().not_modified(&headers(IF_NONE_MATCH, "\"2\"")));
+        assert!(!validators().not_modified(&HeaderMap::new()));
+    }
+
+    #[test]
+    fn when_if_modified_since_is_not_older_than_last_change_should_be_not_modified() {
+        assert!(
+            validators().not_modified(&headers(IF_MODIFIED_SINCE, "Wed, 01 Jul 2026 12:30:15 GMT"))
+        );
+        assert!(
+            !validators()
+                .not_modified(&headers(IF_MODIFIED_SINCE, "Wed, 01 Jul 2026 12:30:14 GMT"))
+        );
+        assert!(!validators().not_modified(&headers(IF_MODIFIED_SINCE, "yesterday")));
+    }
+
+    #[test]
+    fn when_both_conditions_are_sent_should_only_use_if_none_match() {
+        let mut headers = headers(IF_NONE_MATCH, "\"2\"");
+        headers.insert(
+            IF_MODIFIED_SINCE,
+            HeaderValue::from_static("Wed, 01 Jul 2026 12:30:15 GMT"),
+        );
+
+        assert!(!validators().not_modified(&headers));
+    }
+
+    #[test]
+    fn when_validators_are_applied_should_set_etag_and_last_modified() {
+        let mut headers = HeaderMap::new();
+
+        validators().apply(&mut headers);
+
+        assert_eq!(headers[ETAG], "\"3\"");
+        assert_eq!(headers[LAST_MODIFIED], "Wed, 01 Jul 2026 12:30:15 GMT");
+    }
+}
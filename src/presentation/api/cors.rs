@@ -0,0 +1,238 @@
+use std::{str::FromStr, sync::Arc};
+
+use axum::http::{
+    HeaderName, HeaderValue,
+    header::{
+        ACCEPT, ACCEPT_LANGUAGE, AUTHORIZATION, CONTENT_TYPE, IF_MODIFIED_SINCE, IF_NONE_MATCH,
+    },
+};
+use thiserror::Error;
+use tower_http::cors::{AllowOrigin, CorsLayer};
+
+use crate::presentation::api::{idempotency::IDEMPOTENCY_KEY, settings::CorsSettings};
+
+/// An allowed origin, either exact (`https://app.example.com`) or a wildcard
+/// over subdomains (`https://*.example.com`). Wildcards never match the apex
+/// domain itself.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OriginPattern {
+    Exact(String),
+    Subdomains { scheme: String, suffix: String },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum OriginPatternError {
+    #[error("Origin must include the scheme: {0}")]
+    MissingScheme(String),
+
+    #[error("Wildcard is only allowed as the leftmost label: {0}")]
+    InvalidWildcard(String),
+}
+
+impl OriginPattern {
+    pub fn matches(&self, origin: &str) -> bool {
+        match self {
+            OriginPattern::Exact(allowed) => allowed.eq_ignore_ascii_case(origin),
+            OriginPattern::Subdomains { scheme, suffix } => {
+                let origin = origin.to_ascii_lowercase();
+
+                let Some(host) = origin
+                    .strip_prefix(scheme.as_str())
+                    .and_then(|rest| rest.strip_suffix(suffix.as_str()))
+                else {
+                    return false;
+                };
+
+                !host.is_empty()
+                    && !host.starts_with('.')
+                    && !host.ends_with('.')
+                    && host
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '.')
+            }
+        }
+    }
+}
+
+impl FromStr for OriginPattern {
+    type Err = OriginPatternError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.trim().trim_end_matches('/').to_ascii_lowercase();
+
+        let Some((scheme, host)) = value.split_once("://") else {
+            return Err(OriginPatternError::MissingScheme(value));
+        };
+
+        match host.strip_prefix('*') {
+            Some(suffix) if suffix.starts_with('.') && !suffix.contains('*') => {
+                Ok(OriginPattern::Subdomains {
+                    scheme: format!("{}://", scheme),
+                    suffix: suffix.to_string(),
+                })
+            }
+            Some(_) => Err(OriginPatternError::InvalidWildcard(value)),
+            None if host.contains('*') => Err(OriginPatternError::InvalidWildcard(value)),
+            None => Ok(OriginPattern::Exact(value)),
+        }
+    }
+}
+
+pub fn cors_layer(settings: &CorsSettings) -> CorsLayer {
+    let origins = Arc::new(settings.allowed_origins.clone());
+
+    CorsLayer::new()
+        .allow_origin(AllowOrigin::predicate(
+            move |origin: &HeaderValue, _request_parts| {
+                origin
+                    .to_str()
+                    .map(|origin| origins.iter().any(|pattern| pattern.matches(origin)))
+                    .unwrap_or(false)
+            },
+        ))
+        .allow_methods(settings.allowed_methods.clone())
+        .allow_headers([
+            AUTHORIZATION,
+            CONTENT_TYPE,
+            ACCEPT,
+            ACCEPT_LANGUAGE,
+            IF_NONE_MATCH,
+            IF_MODIFIED_SINCE,
+            HeaderName::from_static(IDEMPOTENCY_KEY),
+        ])
+        .expose_headers(settings.exposed_headers.clone())
+        .max_age(settings.max_age)
+        .allow_credentials(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use axum::{
+        Router,
+        body::Body,
+        http::{
+            Method, Request, StatusCode,
+            header::{
+                ACCESS_CONTROL_ALLOW_METHODS, ACCESS_CONTROL_ALLOW_ORIGIN,
+                ACCESS_CONTROL_EXPOSE_HEADERS, ACCESS_CONTROL_MAX_AGE, ETAG, ORIGIN,
+            },
+        },
+        routing::get,
+    };
+    use tower::ServiceExt;
+
+    use super::*;
+
+    fn pattern(value: &str) -> OriginPattern {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn when_origin_is_exact_should_match_only_that_origin() {
+        let pattern = pattern("https://app.example.com/");
+
+        assert!(pattern.matches("https://app.example.com"));
+        assert!(!pattern.matches("https://other.example.com"));
+        assert!(!pattern.matches("http://app.example.com"));
+    }
+
+    #[test]
+    fn when_origin_is_wildcard_should_match_subdomains() {
+        let pattern = pattern("https://*.preview.example.com");
+
+        assert!(pattern.matches("https://pr-42.preview.example.com"));
+        assert!(pattern.matches("https://a.b.preview.example.com"));
+        assert!(!pattern.matches("https://preview.example.com"));
+        assert!(!pattern.matches("http://pr-42.preview.example.com"));
+        assert!(!pattern.matches("https://evil.com/.preview.example.com"));
+        assert!(!pattern.matches("https://pr-42.preview.example.com.evil.com"));
+    }
+
+    #[test]
+    fn when_wildcard_is_not_leftmost_label_should_return_error() {
+        assert!(
+            "https://app.*.example.com"
+                .parse::<OriginPattern>()
+                .is_err()
+        );
+        assert!("https://*example.com".parse::<OriginPattern>().is_err());
+        assert!("app.example.com".parse::<OriginPattern>().is_err());
+    }
+
+    fn app() -> Router {
+        let settings = CorsSettings {
+            allowed_origins: vec![
+                pattern("https://app.example.com"),
+                pattern("https://*.staging.example.com"),
+            ],
+            allowed_methods: vec![Method::GET, Method::DELETE],
+            exposed_headers: vec![ETAG],
+            max_age: Duration::from_secs(600),
+        };
+
+        Router::new()
+            .route("/profiles", get(|| async { StatusCode::OK }))
+            .layer(cors_layer(&settings))
+    }
+
+    fn preflight(origin: &str) -> Request<Body> {
+        Request::builder()
+            .method(Method::OPTIONS)
+            .uri("/profiles")
+            .header(ORIGIN, origin)
+            .header("access-control-request-method", "DELETE")
+            .body(Body::empty())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn when_preflight_from_allowed_origin_should_allow_configured_methods() {
+        let response = app()
+            .oneshot(preflight("https://pr-1.staging.example.com"))
+            .await
+            .unwrap();
+
+        let headers = response.headers();
+        assert_eq!(
+            headers[ACCESS_CONTROL_ALLOW_ORIGIN],
+            "https://pr-1.staging.example.com"
+        );
+        assert!(
+            headers[ACCESS_CONTROL_ALLOW_METHODS]
+                .to_str()
+                .unwrap()
+                .contains("DELETE")
+        );
+        assert_eq!(headers[ACCESS_CONTROL_MAX_AGE], "600");
+    }
+
+    #[tokio::test]
+    async fn when_preflight_from_unknown_origin_should_not_allow_origin() {
+        let response = app()
+            .oneshot(preflight("https://evil.example.org"))
+            .await
+            .unwrap();
+
+        assert!(!response.headers().contains_key(ACCESS_CONTROL_ALLOW_ORIGIN));
+    }
+
+    #[tokio::test]
+    async fn when_request_from_allowed_origin_should_expose_headers() {
+        let request = Request::builder()
+            .uri("/profiles")
+            .header(ORIGIN, "https://app.example.com")
+            .body(Body::empty())
+            .unwrap();
+
+        let response = app().oneshot(request).await.unwrap();
+
+        assert_eq!(
+            response.headers()[ACCESS_CONTROL_EXPOSE_HEADERS]
+                .to_str()
+                .unwrap(),
+            "etag"
+        );
+    }
+}
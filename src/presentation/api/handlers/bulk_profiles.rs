@@ -0,0 +1,561 @@
+use std::{future::Future, sync::Arc};
+
+use axum::{
+    body::{Body, Bytes},
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    response::Response,
+};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use tokio::sync::mpsc;
+use validify::Validify;
+
+use crate::{
+    application::dtos::{
+        create_profile_input::CreateProfileInput, update_profile_input::UpdateProfileInput,
+    },
+    domain::{
+        models::{
+            error_code::ErrorCode,
+            profile::{Profile, ProfileError},
+        },
+        repositories::profile_repo::ProfileRepository,
+    },
+    presentation::api::{
+        handlers::{
+            requests::{BulkUpdateProfileRequest, CreateProfileRequest},
+            responses::{BulkLineResponse, BulkLineStatus},
+        },
+        i18n::Locale,
+        ndjson::{Line, MAX_LINE_BYTES, NdjsonLines, ndjson_response, to_line},
+        security::AdminClaims,
+        service::AppState,
+        utils::{AppErrorResponse, validation_error},
+    },
+};
+
+/// Operations written per round trip to the database.
+const BATCH_SIZE: usize = 500;
+
+type BatchOutcome = Result<Vec<Result<Profile, ProfileError>>, ProfileError>;
+
+#[utoipa::path(
+    post,
+    path = "/bulk-create",
+    tag = "profiles",
+    request_body(
+        content = CreateProfileRequest,
+        content_type = "application/x-ndjson",
+        description = "One profile to create per line"
+    ),
+    responses(
+        (status = 200, description = "One result per non-blank input line, in order, streamed as they are written", body = BulkLineResponse, content_type = "application/x-ndjson"),
+        (status = 401, description = "Missing or invalid token", body = AppErrorResponse),
+        (status = 403, description = "Missing profile:admin scope", body = AppErrorResponse),
+        (status = 429, description = "Rate limit exceeded", body = AppErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn bulk_create_profiles_handler<R: ProfileRepository>(
+    _: AdminClaims,
+    State(state): State<AppState<R>>,
+    headers: HeaderMap,
+    body: Body,
+) -> Response {
+    let locale = Locale::negotiate(&headers);
+    let (lines, response) = mpsc::channel(BATCH_SIZE);
+    let use_case = Arc::clone(&state.bulk_create_profiles_use_case);
+
+    tokio::spawn(run_bulk(
+        NdjsonLines::new(body),
+        BulkReport { lines, locale },
+        BATCH_SIZE,
+        BulkLineStatus::Created,
+        |mut request: CreateProfileRequest| {
+            // As mesmas regras de POST /profiles, minúsculas inclusive
+            request.validify().map_err(validation_error)?;
+
+            Ok(CreateProfileInput::try_new(request.id, request.email)?)
+        },
+        move |inputs| {
+            let use_case = Arc::clone(&use_case);
+            async move { use_case.execute(inputs).await }
+        },
+    ));
+
+    ndjson_response(response)
+}
+
+#[utoipa::path(
+    post,
+    path = "/bulk-update",
+    tag = "profiles",
+    request_body(
+        content = BulkUpdateProfileRequest,
+        content_type = "application/x-ndjson",
+        description = "One profile id and the fields to change per line"
+    ),
+    responses(
+        (status = 200, description = "One result per non-blank input line, in order, streamed as they are written", body = BulkLineResponse, content_type = "application/x-ndjson"),
+        (status = 401, description = "Missing or invalid token", body = AppErrorResponse),
+        (status = 403, description = "Missing profile:admin scope", body = AppErrorResponse),
+        (status = 429, description = "Rate limit exceeded", body = AppErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn bulk_update_profiles_handler<R: ProfileRepository>(
+    _: AdminClaims,
+    State(state): State<AppState<R>>,
+    headers: HeaderMap,
+    body: Body,
+) -> Response {
+    let locale = Locale::negotiate(&headers);
+    let (lines, response) = mpsc::channel(BATCH_SIZE);
+    let use_case = Arc::clone(&state.bulk_update_profiles_use_case);
+
+    tokio::spawn(run_bulk(
+        NdjsonLines::new(body),
+        BulkReport { lines, locale },
+        BATCH_SIZE,
+        BulkLineStatus::Updated,
+        |mut request: BulkUpdateProfileRequest| {
+            request.fields.validify().map_err(validation_error)?;
+
+            Ok(UpdateProfileInput::try_new(
+                request.id,
+                request.fields.first_name,
+                request.fields.last_name,
+                request.fields.bio,
+                request.fields.profile_image_url,
+            )?)
+        },
+        move |inputs| {
+            let use_case = Arc::clone(&use_case);
+            async move { use_case.execute(inputs).await }
+        },
+    ));
+
+    ndjson_response(response)
+}
+
+/// Where per-line results go, in the caller's language.
+struct BulkReport {
+    lines: mpsc::Sender<Bytes>,
+    locale: Locale,
+}
+
+impl BulkReport {
+    fn error(&self, error: impl Into<AppErrorResponse>) -> AppErrorResponse {
+        let mut error = error.into();
+        self.locale.localize(&mut error);
+        error
+    }
+
+    /// Returns `false` once the client has gone away.
+    async fn send(&self, line: BulkLineResponse) -> bool {
+        self.lines.send(to_line(&line)).await.is_ok()
+    }
+}
+
+enum Pending<I> {
+    Done(BulkLineResponse),
+    Input {
+        line: usize,
+        id: Option<String>,
+        input: I,
+    },
+}
+
+/// Reads operations line by line, validates each, and writes them in
+/// batches of `batch_size`. Every line gets a result, in input order; a
+/// failing line or batch never stops the ones after it.
+async fn run_bulk<T, I, P, E, F>(
+    mut lines: NdjsonLines,
+    report: BulkReport,
+    batch_size: usize,
+    status: BulkLineStatus,
+    parse: P,
+    execute: E,
+) where
+    T: DeserializeOwned,
+    P: Fn(T) -> Result<I, AppErrorResponse>,
+    E: Fn(Vec<I>) -> F,
+    F: Future<Output = BatchOutcome>,
+{
+    let mut pending: Vec<Pending<I>> = Vec::new();
+    let mut inputs = 0;
+
+    while let Some(line) = lines.next().await {
+        let entry = match line {
+            Ok(Line::Data(line, data)) => parse_line(&report, line, &data, &parse),
+            Ok(Line::TooLong(line)) => Pending::Done(BulkLineResponse::failed(
+                line,
+                None,
+                report.error(
+                    AppErrorResponse::new(
+                        StatusCode::PAYLOAD_TOO_LARGE,
+                        ErrorCode::PayloadTooLarge,
+                        "Request body is too large",
+                    )
+                    .with_details(format!("Lines are limited to {} bytes", MAX_LINE_BYTES)),
+                ),
+            )),
+            Err(e) => {
+                tracing::warn!("Bulk request body could not be read: {}", e);
+                break;
+            }
+        };
+
+        if matches!(entry, Pending::Input { .. }) {
+            inputs += 1;
+        }
+        pending.push(entry);
+
+        if inputs == batch_size {
+            if !flush(&report, std::mem::take(&mut pending), status, &execute).await {
+                return;
+            }
+            inputs = 0;
+        }
+    }
+
+    flush(&report, pending, status, &execute).await;
+}
+
+fn parse_line<T, I, P>(report: &BulkReport, line: usize, data: &[u8], parse: &P) -> Pending<I>
+where
+    T: DeserializeOwned,
+    P: Fn(T) -> Result<I, AppErrorResponse>,
+{
+    let invalid_json = |e: serde_json::Error| {
+        report.error(
+            AppErrorResponse::new(
+                StatusCode::BAD_REQUEST,
+                ErrorCode::InvalidJson,
+                "Invalid JSON",
+            )
+            .with_details(e.to_string()),
+        )
+    };
+
+    let value: Value = match serde_json::from_slice(data) {
+        Ok(value) => value,
+        Err(e) => return Pending::Done(BulkLineResponse::failed(line, None, invalid_json(e))),
+    };
+
+    // O id é ecoado mesmo quando a linha é inválida, para facilitar a conferência
+    let id = value.get("id").and_then(Value::as_str).map(str::to_string);
+
+    let request = match serde_json::from_value::<T>(value) {
+        Ok(request) => request,
+        Err(e) => return Pending::Done(BulkLineResponse::failed(line, id, invalid_json(e))),
+    };
+
+    match parse(request) {
+        Ok(input) => Pending::Input { line, id, input },
+        Err(e) => Pending::Done(BulkLineResponse::failed(line, id, report.error(e))),
+    }
+}
+
+async fn flush<I, E, F>(
+    report: &BulkReport,
+    pending: Vec<Pending<I>>,
+    status: BulkLineStatus,
+    execute: &E,
+) -> bool
+where
+    E: Fn(Vec<I>) -> F,
+    F: Future<Output = BatchOutcome>,
+{
+    let mut slots = Vec::with_capacity(pending.len());
+    let mut inputs = Vec::new();
+
+    for entry in pending {
+        match entry {
+            Pending::Done(result) => slots.push(Ok(result)),
+            Pending::Input { line, id, input } => {
+                slots.push(Err((line, id)));
+                inputs.push(input);
+            }
+        }
+    }
+
+    let mut outcomes = match inputs.is_empty() {
+        true => Ok(Vec::new()),
+        false => execute(inputs).await,
+    }
+    .map(Vec::into_iter);
+
+    for slot in slots {
+        let result = match slot {
+            Ok(result) => result,
+            Err((line, id)) => match outcomes.as_mut().map(Iterator::next) {
+                Ok(Some(Ok(profile))) => BulkLineResponse::succeeded(line, status, &profile),
+                Ok(Some(Err(e))) => BulkLineResponse::failed(line, id, report.error(e)),
+                Ok(None) => BulkLineResponse::failed(
+                    line,
+                    id,
+                    report.error(ProfileError::Unknown("Missing batch result".to_string())),
+                ),
+                Err(e) => BulkLineResponse::failed(line, id, report.error(e.clone())),
+            },
+        };
+
+        if !report.send(result).await {
+            return false;
+        }
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        Router,
+        extract::Request,
+        http::header::{ACCEPT_LANGUAGE, AUTHORIZATION},
+        routing::post,
+    };
+    use serde_json::json;
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::{
+        domain::{
+            object_values::email::Email,
+            repositories::profile_repo::{MockProfileRepository, ProfileRepositoryError},
+        },
+        presentation::api::{
+            handlers::tests::{SharedMockRepository, create_test_token_for, get_test_decoding_key},
+            security::Scope,
+        },
+    };
+
+    fn app(mock_repo: MockProfileRepository) -> Router {
+        let app_state = AppState::new(
+            Arc::new(SharedMockRepository(Arc::new(mock_repo))),
+            Arc::new(get_test_decoding_key()),
+        );
+
+        Router::new()
+            .route("/profiles/bulk-create", post(bulk_create_profiles_handler))
+            .route("/profiles/bulk-update", post(bulk_update_profiles_handler))
+            .with_state(app_state)
+    }
+
+    async fn send(
+        app: Router,
+        path: &str,
+        scopes: &[Scope],
+        body: String,
+    ) -> (StatusCode, Vec<Value>) {
+        let request = Request::builder()
+            .method("POST")
+            .uri(path)
+            .header(
+                AUTHORIZATION,
+                format!("Bearer {}", create_test_token_for("migration", scopes)),
+            )
+            .header(ACCEPT_LANGUAGE, "en")
+            .body(Body::from(body))
+            .unwrap();
+
+        let response = app.oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+
+        let lines = body
+            .split(|byte| *byte == b'\n')
+            .filter(|line| !line.is_empty())
+            .map(|line| serde_json::from_slice(line).unwrap())
+            .collect();
+
+        (status, lines)
+    }
+
+    #[tokio::test]
+    async fn when_some_lines_fail_should_report_every_line_in_order() {
+        let (created, taken) = (Uuid::now_v7().to_string(), Uuid::now_v7().to_string());
+        let mut mock_repo = MockProfileRepository::new();
+
+        mock_repo
+            .expect_insert_profiles()
+            .withf(|profiles| profiles.len() == 2)
+            .times(1)
+            .returning(|profiles| {
+                Ok(vec![
+                    Ok(()),
+                    Err(ProfileRepositoryError::AlreadyExists(
+                        profiles[1].id().to_string(),
+                    )),
+                ])
+            });
+
+        let body = [
+            json!({"id": created, "email": "john.doe@example.com"}).to_string(),
+            "{not json".to_string(),
+            json!({"id": "nope", "email": "jane.doe@example.com"}).to_string(),
+            String::new(),
+            json!({"id": taken, "email": "joe.doe@example.com"}).to_string(),
+        ]
+        .join("\n");
+
+        let (status, lines) = send(
+            app(mock_repo),
+            "/profiles/bulk-create",
+            &[Scope::ProfileAdmin],
+            body,
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[0]["line"], 1);
+        assert_eq!(lines[0]["status"], "created");
+        assert_eq!(lines[0]["id"], created);
+        assert_eq!(lines[0]["version"], 1);
+        assert_eq!(lines[1]["line"], 2);
+        assert_eq!(lines[1]["error"]["code"], "INVALID_JSON");
+        assert_eq!(lines[2]["line"], 3);
+        assert_eq!(lines[2]["id"], "nope");
+        assert_eq!(lines[2]["error"]["code"], "ID_INVALID");
+        assert_eq!(lines[3]["line"], 5);
+        assert_eq!(lines[3]["status"], "failed");
+        assert_eq!(lines[3]["error"]["code"], "PROFILE_ALREADY_EXISTS");
+    }
+
+    #[tokio::test]
+    async fn when_email_has_capitals_should_store_it_lowercased() {
+        let mut mock_repo = MockProfileRepository::new();
+
+        mock_repo
+            .expect_insert_profiles()
+            .withf(|profiles| profiles[0].email().to_string() == "john.doe@example.com")
+            .times(1)
+            .returning(|profiles| Ok(vec![Ok(()); profiles.len()]));
+
+        let body = json!({"id": Uuid::now_v7().to_string(), "email": " John.Doe@Example.COM "})
+            .to_string();
+
+        let (_, lines) = send(
+            app(mock_repo),
+            "/profiles/bulk-create",
+            &[Scope::ProfileAdmin],
+            body,
+        )
+        .await;
+
+        assert_eq!(lines[0]["status"], "created");
+    }
+
+    #[tokio::test]
+    async fn when_profiles_are_updated_should_report_new_versions() {
+        let id = Uuid::now_v7().to_string();
+        let mut mock_repo = MockProfileRepository::new();
+
+        mock_repo.expect_get_profiles_by_ids().returning(|ids| {
+            Ok(vec![Profile::new(
+                ids[0].clone(),
+                Email::try_from("john.doe@example.com").unwrap(),
+            )])
+        });
+        mock_repo
+            .expect_update_profiles()
+            .returning(|profiles| Ok(vec![Ok(()); profiles.len()]));
+
+        let body = json!({"id": id, "firstName": "John", "bio": "short"}).to_string()
+            + "\n"
+            + &json!({"id": id, "firstName": "John"}).to_string();
+
+        let (_, lines) = send(
+            app(mock_repo),
+            "/profiles/bulk-update",
+            &[Scope::ProfileAdmin],
+            body,
+        )
+        .await;
+
+        assert_eq!(lines[0]["status"], "failed");
+        assert_eq!(lines[0]["error"]["code"], "VALIDATION_FAILED");
+        assert_eq!(lines[1]["status"], "updated");
+        assert_eq!(lines[1]["version"], 2);
+    }
+
+    #[tokio::test]
+    async fn when_caller_is_not_admin_should_return_forbidden() {
+        let (status, lines) = send(
+            app(MockProfileRepository::new()),
+            "/profiles/bulk-create",
+            &[Scope::ProfileCreate],
+            String::new(),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert_eq!(lines[0]["code"], "ACCESS_DENIED");
+    }
+
+    #[tokio::test]
+    async fn when_batch_fails_should_report_its_lines_and_continue_with_the_next() {
+        let (tx, mut rx) = mpsc::channel(16);
+        let calls = std::sync::atomic::AtomicUsize::new(0);
+
+        let body = (0..3)
+            .map(|n| {
+                json!({"id": Uuid::now_v7().to_string(), "email": format!("user{}@example.com", n)})
+                    .to_string()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        run_bulk(
+            NdjsonLines::new(Body::from(body)),
+            BulkReport {
+                lines: tx,
+                locale: Locale::En,
+            },
+            2,
+            BulkLineStatus::Created,
+            |mut request: CreateProfileRequest| {
+                // As mesmas regras de POST /profiles, minúsculas inclusive
+                request.validify().map_err(validation_error)?;
+
+                Ok(CreateProfileInput::try_new(request.id, request.email)?)
+            },
+            |inputs: Vec<CreateProfileInput>| {
+                let first = calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst) == 0;
+                async move {
+                    if first {
+                        return Err(ProfileError::Unknown("connection reset".into()));
+                    }
+                    Ok(inputs
+                        .into_iter()
+                        .map(|input| Ok(Profile::new(input.id, input.email)))
+                        .collect())
+                }
+            },
+        )
+        .await;
+
+        let mut statuses = Vec::new();
+        while let Some(line) = rx.recv().await {
+            let line: Value = serde_json::from_slice(&line).unwrap();
+            statuses.push((line["line"].as_u64().unwrap(), line["status"].clone()));
+        }
+
+        assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 2);
+        assert_eq!(
+            statuses,
+            vec![
+                (1, json!("failed")),
+                (2, json!("failed")),
+                (3, json!("created")),
+            ]
+        );
+    }
+}
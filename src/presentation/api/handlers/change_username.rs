@@ -0,0 +1,212 @@
+use axum::{
+    extract::{Path, State},
+    http::{HeaderValue, header::RETRY_AFTER},
+    response::{IntoResponse, Response},
+};
+use chrono::Utc;
+
+use crate::{
+    application::dtos::change_username_input::ChangeUsernameInput,
+    domain::{models::profile::ProfileError, repositories::profile_repo::ProfileRepository},
+    presentation::api::{
+        handlers::{
+            get_profile_by_id::updated_profile_representation, requests::ChangeUsernameRequest,
+            responses::ProfileResponse,
+        },
+        security::UpdateClaims,
+        service::AppState,
+        utils::{AppErrorResponse, AppJson},
+    },
+};
+
+#[utoipa::path(
+    put,
+    path = "/{id}/username",
+    tag = "profiles",
+    params(
+        ("id" = String, Path, description = "Profile id (UUID)")
+    ),
+    request_body = ChangeUsernameRequest,
+    responses(
+        (status = 200, description = "Username changed; the previous one stays reserved for this profile. Fields hidden by its privacy settings are omitted unless the caller owns it or is an admin", body = ProfileResponse, headers(
+            ("ETag" = String, description = "Version of this representation")
+        )),
+        (status = 400, description = "Invalid id or username", body = AppErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = AppErrorResponse),
+        (status = 403, description = "Not the owner and missing profile:update scope", body = AppErrorResponse),
+        (status = 404, description = "Profile not found", body = AppErrorResponse),
+        (status = 409, description = "Username held or reserved by another profile", body = AppErrorResponse),
+        (status = 429, description = "Username changed too recently, or rate limit exceeded", body = AppErrorResponse, headers(
+            ("Retry-After" = u64, description = "Seconds until the username may be changed again")
+        ))
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn change_username_handler<R: ProfileRepository>(
+    UpdateClaims(claims): UpdateClaims,
+    State(state): State<AppState<R>>,
+    Path(id): Path<String>,
+    AppJson(input): AppJson<ChangeUsernameRequest>,
+) -> Result<Response, Response> {
+    let command = ChangeUsernameInput::try_new(id, input.username).map_err(error_response)?;
+
+    state
+        .change_username_use_case
+        .execute(command)
+        .await
+        .map(|profile| updated_profile_representation(&claims, profile))
+        .map_err(error_response)
+}
+
+fn error_response(error: ProfileError) -> Response {
+    let retry_at = match error {
+        ProfileError::UsernameChangeTooSoon(at) => Some(at),
+        _ => None,
+    };
+
+    let mut response = AppErrorResponse::from(error).into_response();
+
+    if let Some(at) = retry_at {
+        let seconds = (at - Utc::now()).num_seconds().max(1) as u64;
+        response
+            .headers_mut()
+            .insert(RETRY_AFTER, HeaderValue::from(seconds));
+    }
+
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{Router, body::Body, http::Request, http::StatusCode, routing::put};
+    use serde_json::json;
+    use tower::ServiceExt;
+
+    use crate::{
+        domain::{
+            models::profile::Profile,
+            object_values::{email::Email, username::Username},
+            repositories::profile_repo::{MockProfileRepository, ProfileRepositoryError},
+        },
+        presentation::api::handlers::tests::{
+            SharedMockRepository, create_test_token, get_test_decoding_key,
+        },
+    };
+
+    use super::*;
+
+    fn app(mock_repo: MockProfileRepository) -> Router {
+        let shared_repo = SharedMockRepository(Arc::new(mock_repo));
+
+        let app_state = AppState::new(Arc::new(shared_repo), Arc::new(get_test_decoding_key()));
+
+        Router::new()
+            .route("/profiles/{id}/username", put(change_username_handler))
+            .with_state(app_state)
+    }
+
+    fn request(username: &str) -> Request<Body> {
+        Request::builder()
+            .method("PUT")
+            .uri("/profiles/123e4567-e89b-12d3-a456-426614174000/username")
+            .header("content-type", "application/json")
+            .header("authorization", format!("Bearer {}", create_test_token()))
+            .body(Body::from(json!({ "username": username }).to_string()))
+            .unwrap()
+    }
+
+    fn stored(mock_repo: &mut MockProfileRepository, changed_days_ago: i64) {
+        mock_repo
+            .expect_get_profile_by_id()
+            .times(1)
+            .returning(move |id| {
+                Ok(Some(
+                    Profile::new(id.clone(), Email::try_from("john.doe@example.com").unwrap())
+                        .with_username(
+                            Some(Username::try_from("johnny").unwrap()),
+                            Some(Utc::now() - chrono::Duration::days(changed_days_ago)),
+                        ),
+                ))
+            });
+        mock_repo
+            .expect_get_username_reservation()
+            .returning(|_| Ok(None));
+    }
+
+    #[tokio::test]
+    async fn when_username_is_free_should_return_renamed_profile() {
+        let mut mock_repo = MockProfileRepository::new();
+        stored(&mut mock_repo, 40);
+        mock_repo
+            .expect_reserve_username()
+            .times(1)
+            .returning(|_| Ok(()));
+        mock_repo.expect_save().times(1).returning(|_| Ok(()));
+
+        let response = app(mock_repo).oneshot(request("John.Doe")).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["username"], "john.doe");
+    }
+
+    #[tokio::test]
+    async fn when_username_is_taken_should_return_conflict() {
+        let mut mock_repo = MockProfileRepository::new();
+        stored(&mut mock_repo, 40);
+        mock_repo.expect_reserve_username().returning(|_| Ok(()));
+        mock_repo
+            .expect_save()
+            .returning(|_| Err(ProfileRepositoryError::UsernameTaken("john.doe".into())));
+
+        let response = app(mock_repo).oneshot(request("john.doe")).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["code"], "USERNAME_TAKEN");
+    }
+
+    #[tokio::test]
+    async fn when_renamed_recently_should_return_too_many_requests_with_retry_after() {
+        let mut mock_repo = MockProfileRepository::new();
+        stored(&mut mock_repo, 5);
+        mock_repo.expect_save().never();
+
+        let response = app(mock_repo).oneshot(request("john.doe")).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+
+        let retry_after: u64 = response.headers()[RETRY_AFTER]
+            .to_str()
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert!(retry_after > 24 * 24 * 3600);
+    }
+
+    #[tokio::test]
+    async fn when_username_is_reserved_word_should_return_bad_request() {
+        let mut mock_repo = MockProfileRepository::new();
+        mock_repo.expect_get_profile_by_id().never();
+
+        let response = app(mock_repo).oneshot(request("admin")).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["errors"][0]["code"], "USERNAME_RESERVED");
+    }
+}
@@ -1,29 +1,78 @@
-use axum::{extract::State, http::StatusCode};
+use axum::{
+    extract::{OriginalUri, State},
+    http::{
+        HeaderValue, StatusCode,
+        header::{ETAG, LOCATION},
+    },
+    response::{IntoResponse, Response},
+};
 
 use crate::{
     application::dtos::create_profile_input::CreateProfileInput,
-    domain::repositories::profile_repo::ProfileRepository,
+    domain::{models::error_code::ErrorCode, repositories::profile_repo::ProfileRepository},
     presentation::api::{
-        handlers::requests::CreateProfileRequest,
+        handlers::{requests::CreateProfileRequest, responses::ProfileResponse},
         security::CreateClaims,
         service::AppState,
         utils::{AppErrorResponse, ValidatedJson},
     },
 };
 
+#[utoipa::path(
+    post,
+    path = "/",
+    tag = "profiles",
+    params(
+        ("Idempotency-Key" = Option<String>, Header, description = "Replays the first successful response to retries with the same key")
+    ),
+    request_body = CreateProfileRequest,
+    responses(
+        (status = 201, description = "Profile created", body = ProfileResponse, headers(
+            ("Location" = String, description = "URL of the created profile"),
+            ("ETag" = String, description = "Version of the created profile")
+        )),
+        (status = 400, description = "Invalid request body", body = AppErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = AppErrorResponse),
+        (status = 403, description = "Missing profile:create scope", body = AppErrorResponse),
+        (status = 409, description = "Profile already exists, or a request with the same idempotency key is in progress", body = AppErrorResponse),
+        (status = 422, description = "Idempotency key reused with a different request", body = AppErrorResponse),
+        (status = 429, description = "Rate limit exceeded", body = AppErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
 pub async fn create_profile_handler<R: ProfileRepository>(
     _: CreateClaims,
     State(state): State<AppState<R>>,
+    OriginalUri(uri): OriginalUri,
     ValidatedJson(input): ValidatedJson<CreateProfileRequest>,
-) -> Result<StatusCode, AppErrorResponse> {
+) -> Result<Response, AppErrorResponse> {
     let command = CreateProfileInput::try_new(input.id, input.email)?;
 
-    state
+    let profile = state
         .create_profile_use_case
         .execute(command)
         .await
-        .map_err(AppErrorResponse::from)
-        .map(|_| StatusCode::CREATED)
+        .map_err(AppErrorResponse::from)?;
+
+    let response = ProfileResponse::from(profile);
+
+    // Relativo à coleção usada na requisição, preservando o prefixo de versão
+    let location = format!("{}/{}", uri.path().trim_end_matches('/'), response.id);
+    let location = HeaderValue::from_str(&location).map_err(|e| {
+        AppErrorResponse::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            ErrorCode::InternalError,
+            "Internal error",
+        )
+        .with_details(e.to_string())
+    })?;
+
+    Ok((
+        StatusCode::CREATED,
+        [(LOCATION, location), (ETAG, response.etag())],
+        response,
+    )
+        .into_response())
 }
 
 #[cfg(test)]
@@ -38,11 +87,7 @@ mod tests {
     use super::*;
 
     use crate::{
-        domain::{
-            models::profile::Profile,
-            object_values::{email::Email, id::Id},
-            repositories::profile_repo::MockProfileRepository,
-        },
+        domain::repositories::profile_repo::{MockProfileRepository, ProfileRepositoryError},
         presentation::api::handlers::tests::{
             SharedMockRepository, create_test_token, get_test_decoding_key,
         },
@@ -52,11 +97,6 @@ mod tests {
     async fn when_valid_profile_data_is_provided_then_profile_is_created() {
         let mut mock_repo = MockProfileRepository::new();
 
-        mock_repo
-            .expect_get_profile_by_id()
-            .times(1)
-            .returning(|_| Ok(None));
-
         mock_repo.expect_save().times(1).returning(|_| Ok(()));
 
         // Envolve o mock no Wrapper clonável
@@ -71,6 +111,7 @@ mod tests {
             .with_state(app_state);
 
         let token = create_test_token();
+        let id = Uuid::now_v7().to_string();
 
         // Constrói a requisição HTTP simulada
         let request = Request::builder()
@@ -80,7 +121,7 @@ mod tests {
             .header("authorization", format!("Bearer {}", token))
             .body(Body::from(
                 json!({
-                    "id": Uuid::now_v7().to_string(),
+                    "id": id,
                     "email": "teste@email.com"
                 })
                 .to_string(),
@@ -93,18 +134,28 @@ mod tests {
 
         // 5. Assertions
         assert_eq!(response.status(), StatusCode::CREATED);
+        assert_eq!(response.headers()[LOCATION], format!("/profiles/{}", id));
+        assert_eq!(response.headers()[ETAG], "\"1\"");
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body["id"], id);
+        assert_eq!(body["email"], "teste@email.com");
+        assert_eq!(body["version"], 1);
     }
 
     #[tokio::test]
     async fn when_profile_already_exists_then_returns_conflict() {
         let mut mock_repo = MockProfileRepository::new();
 
-        let existing_profile =
-            Profile::new(Id::generate(), Email::try_from("teste@email.com").unwrap());
-        mock_repo
-            .expect_get_profile_by_id()
-            .times(1)
-            .returning(move |_| Ok(Some(existing_profile.clone())));
+        mock_repo.expect_save().times(1).returning(|profile| {
+            Err(ProfileRepositoryError::AlreadyExists(
+                profile.id().to_string(),
+            ))
+        });
 
         let shared_repo = SharedMockRepository(Arc::new(mock_repo));
 
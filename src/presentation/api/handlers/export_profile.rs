@@ -0,0 +1,250 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::{
+        HeaderValue, StatusCode,
+        header::{CACHE_CONTROL, CONTENT_DISPOSITION, CONTENT_TYPE},
+    },
+    response::{IntoResponse, Response},
+};
+
+use crate::{
+    application::dtos::get_profile_by_id_input::GetProfileByIdInput,
+    domain::{models::error_code::ErrorCode, repositories::profile_repo::ProfileRepository},
+    presentation::api::{
+        handlers::{requests::ExportProfileQuery, responses::ProfileExportResponse},
+        security::OwnerClaims,
+        service::AppState,
+        utils::AppErrorResponse,
+    },
+};
+
+#[utoipa::path(
+    get,
+    path = "/{id}/export",
+    tag = "profiles",
+    params(
+        ("id" = String, Path, description = "Profile id (UUID)"),
+        ExportProfileQuery
+    ),
+    responses(
+        (status = 200, description = "Every stored field of the profile, private ones included, as a download", content(
+            (ProfileExportResponse = "application/json"),
+            (String = "text/csv")
+        )),
+        (status = 400, description = "Invalid id or export format", body = AppErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = AppErrorResponse),
+        (status = 403, description = "Not the owner of the profile", body = AppErrorResponse),
+        (status = 404, description = "Profile not found", body = AppErrorResponse),
+        (status = 429, description = "Rate limit exceeded", body = AppErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn export_profile_handler<R: ProfileRepository>(
+    _: OwnerClaims,
+    State(state): State<AppState<R>>,
+    Path(id): Path<String>,
+    Query(query): Query<ExportProfileQuery>,
+) -> Result<Response, AppErrorResponse> {
+    let (content_type, extension) = match query.format.as_deref() {
+        None | Some("json") => ("application/json", "json"),
+        Some("csv") => ("text/csv; charset=utf-8", "csv"),
+        Some(_) => {
+            return Err(AppErrorResponse::new(
+                StatusCode::BAD_REQUEST,
+                ErrorCode::ExportFormatUnsupported,
+                "Unsupported export format",
+            )
+            .with_details("Supported formats are json and csv"));
+        }
+    };
+
+    let command = GetProfileByIdInput::try_new(id)?;
+
+    let export = state
+        .export_profile_use_case
+        .execute(command)
+        .await
+        .map_err(AppErrorResponse::from)?;
+
+    let filename = format!("profile-{}.{}", export.profile.id(), extension);
+    let export = ProfileExportResponse::from(export);
+
+    let body = match extension {
+        "csv" => export.to_csv(),
+        _ => serde_json::to_string_pretty(&export).unwrap_or_default(),
+    };
+
+    let mut response = body.into_response();
+    let headers = response.headers_mut();
+
+    headers.insert(CONTENT_TYPE, HeaderValue::from_static(content_type));
+    // Dados pessoais: nenhum cache intermediário deve guardar a cópia
+    headers.insert(CACHE_CONTROL, HeaderValue::from_static("no-store"));
+    if let Ok(disposition) =
+        HeaderValue::from_str(&format!("attachment; filename=\"{}\"", filename))
+    {
+        headers.insert(CONTENT_DISPOSITION, disposition);
+    }
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{Router, body::Body, extract::Request, routing::get};
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::{
+        domain::{
+            models::profile::Profile,
+            object_values::{bio::Bio, email::Email, id::Id, privacy_settings::PrivacySettings},
+            repositories::profile_repo::MockProfileRepository,
+        },
+        presentation::api::{
+            handlers::tests::{SharedMockRepository, create_test_token_for, get_test_decoding_key},
+            security::Scope,
+        },
+    };
+
+    fn private_profile(id: &Id, bio: &str) -> Profile {
+        Profile::from_parts(
+            id.clone(),
+            Email::try_from("john.doe@example.com").unwrap(),
+            None,
+            None,
+            Some(Bio::try_from(bio).unwrap()),
+            None,
+            PrivacySettings::new(false, false, false, false),
+            chrono::Utc::now(),
+            None,
+            2,
+        )
+    }
+
+    async fn send(mock_repo: MockProfileRepository, token: String, uri: String) -> Response {
+        let app_state = AppState::new(
+            Arc::new(SharedMockRepository(Arc::new(mock_repo))),
+            Arc::new(get_test_decoding_key()),
+        );
+
+        let app = Router::new()
+            .route("/profiles/{id}/export", get(export_profile_handler))
+            .with_state(app_state);
+
+        let request = Request::builder()
+            .method("GET")
+            .uri(uri)
+            .header("authorization", format!("Bearer {}", token))
+            .body(Body::empty())
+            .unwrap();
+
+        app.oneshot(request).await.unwrap()
+    }
+
+    async fn body_text(response: Response) -> String {
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+
+        String::from_utf8(body.to_vec()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn when_owner_exports_should_download_every_field_as_json() {
+        let id = Id::generate();
+        let mut mock_repo = MockProfileRepository::new();
+
+        let stored = private_profile(&id, "A bio that is long enough");
+        mock_repo
+            .expect_get_complete_profile_by_id()
+            .times(1)
+            .returning(move |_| Ok(Some(stored.clone())));
+
+        let token = create_test_token_for(&id.to_string(), &[]);
+        let response = send(mock_repo, token, format!("/profiles/{}/export", id)).await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[CONTENT_TYPE], "application/json");
+        assert_eq!(response.headers()[CACHE_CONTROL], "no-store");
+        assert_eq!(
+            response.headers()[CONTENT_DISPOSITION],
+            format!("attachment; filename=\"profile-{}.json\"", id).as_str()
+        );
+
+        let body: Value = serde_json::from_str(&body_text(response).await).unwrap();
+
+        assert_eq!(body["profile"]["id"], id.to_string());
+        assert_eq!(body["profile"]["bio"], "A bio that is long enough");
+        assert_eq!(body["profile"]["privacySettings"]["bioVisible"], false);
+        assert_eq!(body["metadata"]["version"], 2);
+        assert!(body["exportedAt"].is_string());
+    }
+
+    #[tokio::test]
+    async fn when_csv_is_requested_should_quote_and_neutralize_cells() {
+        let id = Id::generate();
+        let mut mock_repo = MockProfileRepository::new();
+
+        let stored = private_profile(&id, "-cmd, said \"hi\" to everyone");
+        mock_repo
+            .expect_get_complete_profile_by_id()
+            .returning(move |_| Ok(Some(stored.clone())));
+
+        let token = create_test_token_for(&id.to_string(), &[]);
+        let response = send(
+            mock_repo,
+            token,
+            format!("/profiles/{}/export?format=csv", id),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[CONTENT_TYPE], "text/csv; charset=utf-8");
+
+        let csv = body_text(response).await;
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("id,email,first_name,last_name,bio,"));
+        assert!(lines[1].starts_with(&format!("{},john.doe@example.com,,,", id)));
+        assert!(lines[1].contains(",\"'-cmd, said \"\"hi\"\" to everyone\","));
+        assert!(lines[1].contains(",false,false,false,false,"));
+    }
+
+    #[tokio::test]
+    async fn when_format_is_unknown_should_return_bad_request() {
+        let id = Id::generate();
+        let token = create_test_token_for(&id.to_string(), &[]);
+
+        let response = send(
+            MockProfileRepository::new(),
+            token,
+            format!("/profiles/{}/export?format=xml", id),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let body: Value = serde_json::from_str(&body_text(response).await).unwrap();
+
+        assert_eq!(body["code"], "EXPORT_FORMAT_UNSUPPORTED");
+    }
+
+    #[tokio::test]
+    async fn when_caller_is_admin_but_not_owner_should_return_forbidden() {
+        let token = create_test_token_for("support-tool", &[Scope::ProfileAdmin]);
+
+        let response = send(
+            MockProfileRepository::new(),
+            token,
+            format!("/profiles/{}/export", Id::generate()),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+}
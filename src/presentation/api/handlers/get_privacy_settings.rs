@@ -0,0 +1,123 @@
+use axum::extract::{Path, State};
+
+use crate::{
+    application::dtos::get_profile_by_id_input::GetProfileByIdInput,
+    domain::repositories::profile_repo::ProfileRepository,
+    presentation::api::{
+        handlers::responses::PrivacySettingsResponse, security::OwnerOrAdminClaims,
+        service::AppState, utils::AppErrorResponse,
+    },
+};
+
+#[utoipa::path(
+    get,
+    path = "/{id}/privacy",
+    tag = "profiles",
+    params(
+        ("id" = String, Path, description = "Profile id (UUID)")
+    ),
+    responses(
+        (status = 200, description = "Privacy settings", body = PrivacySettingsResponse),
+        (status = 401, description = "Missing or invalid token", body = AppErrorResponse),
+        (status = 403, description = "Neither the owner nor an admin", body = AppErrorResponse),
+        (status = 404, description = "Profile not found", body = AppErrorResponse),
+        (status = 429, description = "Rate limit exceeded", body = AppErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn get_privacy_settings_handler<R: ProfileRepository>(
+    _: OwnerOrAdminClaims,
+    State(state): State<AppState<R>>,
+    Path(id): Path<String>,
+) -> Result<PrivacySettingsResponse, AppErrorResponse> {
+    let command = GetProfileByIdInput::try_new(id)?;
+
+    state
+        .get_profile_by_id_use_case
+        .execute(command)
+        .await
+        .map_err(AppErrorResponse::from)
+        .map(|profile| PrivacySettingsResponse::from(profile.privacy_settings()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{Router, body::Body, extract::Request, http::StatusCode, routing::get};
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    use crate::{
+        domain::{
+            models::profile::Profile, object_values::email::Email,
+            repositories::profile_repo::MockProfileRepository,
+        },
+        presentation::api::{
+            handlers::tests::{
+                SharedMockRepository, create_test_token, create_test_token_for,
+                get_test_decoding_key,
+            },
+            security::Scope,
+        },
+    };
+
+    use super::*;
+
+    fn app(mock_repo: MockProfileRepository) -> Router {
+        let shared_repo = SharedMockRepository(Arc::new(mock_repo));
+
+        let app_state = AppState::new(Arc::new(shared_repo), Arc::new(get_test_decoding_key()));
+
+        Router::new()
+            .route("/profiles/{id}/privacy", get(get_privacy_settings_handler))
+            .with_state(app_state)
+    }
+
+    #[tokio::test]
+    async fn when_profile_exists_should_return_privacy_settings() {
+        let mut mock_repo = MockProfileRepository::new();
+
+        mock_repo
+            .expect_get_profile_by_id()
+            .times(1)
+            .returning(|id| {
+                Ok(Some(Profile::new(
+                    id.clone(),
+                    Email::try_from("john.doe@example.com").unwrap(),
+                )))
+            });
+
+        let request = Request::builder()
+            .method("GET")
+            .header("authorization", format!("Bearer {}", create_test_token()))
+            .uri("/profiles/123e4567-e89b-12d3-a456-426614174000/privacy")
+            .body(Body::empty())
+            .unwrap();
+
+        let response = app(mock_repo).oneshot(request).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn when_caller_is_not_owner_should_return_forbidden() {
+        let mock_repo = MockProfileRepository::new();
+
+        let token = create_test_token_for(
+            &Uuid::now_v7().to_string(),
+            &[Scope::ProfileRead, Scope::ProfileUpdate],
+        );
+
+        let request = Request::builder()
+            .method("GET")
+            .header("authorization", format!("Bearer {}", token))
+            .uri("/profiles/123e4567-e89b-12d3-a456-426614174000/privacy")
+            .body(Body::empty())
+            .unwrap();
+
+        let response = app(mock_repo).oneshot(request).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+}
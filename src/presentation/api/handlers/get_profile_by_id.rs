@@ -1,45 +1,136 @@
-use axum::extract::{Path, State};
+use axum::{
+    extract::{Path, State},
+    http::{
+        HeaderMap, HeaderValue, StatusCode,
+        header::{AUTHORIZATION, CACHE_CONTROL, ETAG, VARY},
+    },
+    response::{IntoResponse, Response},
+};
 
 use crate::{
     application::dtos::get_profile_by_id_input::GetProfileByIdInput,
-    domain::repositories::profile_repo::ProfileRepository,
+    domain::{models::profile::Profile, repositories::profile_repo::ProfileRepository},
     presentation::api::{
-        handlers::responses::ProfileResponse, security::ReadClaims, service::AppState,
+        conditional::{PRIVATE_CACHE, PUBLIC_CACHE, Validators},
+        handlers::responses::ProfileResponse,
+        security::{Claims, ReadClaims},
+        service::AppState,
         utils::AppErrorResponse,
     },
 };
 
+#[utoipa::path(
+    get,
+    path = "/{id}",
+    tag = "profiles",
+    params(
+        ("id" = String, Path, description = "Profile id (UUID)"),
+        ("If-None-Match" = Option<String>, Header, description = "ETag of a cached copy"),
+        ("If-Modified-Since" = Option<String>, Header, description = "Last-Modified of a cached copy")
+    ),
+    responses(
+        (status = 200, description = "Profile; fields hidden by its privacy settings are omitted unless the caller owns it or is an admin", body = ProfileResponse, headers(
+            ("ETag" = String, description = "Version of this representation"),
+            ("Last-Modified" = String, description = "Time of the last change"),
+            ("Cache-Control" = String, description = "private for the owner's view, public otherwise")
+        )),
+        (status = 304, description = "Cached copy is still current"),
+        (status = 400, description = "Invalid id", body = AppErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = AppErrorResponse),
+        (status = 403, description = "Not the owner and missing profile:read scope", body = AppErrorResponse),
+        (status = 404, description = "Profile not found", body = AppErrorResponse),
+        (status = 429, description = "Rate limit exceeded", body = AppErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
 pub async fn get_profile_by_id_handler<R: ProfileRepository>(
-    _: ReadClaims,
+    ReadClaims(claims): ReadClaims,
     State(state): State<AppState<R>>,
     Path(id): Path<String>,
-) -> Result<ProfileResponse, AppErrorResponse> {
+    headers: HeaderMap,
+) -> Result<Response, AppErrorResponse> {
     let command = GetProfileByIdInput::try_new(id)?;
 
-    state
+    let profile = state
         .get_profile_by_id_use_case
         .execute(command)
         .await
-        .map_err(AppErrorResponse::from)
-        .map(|p| ProfileResponse::from(p))
+        .map_err(AppErrorResponse::from)?;
+
+    Ok(profile_representation(&claims, profile, &headers))
+}
+
+/// Body and cache headers of a single profile as seen by `claims`, or a 304
+/// when the conditional headers of the request still match it.
+pub(crate) fn profile_representation(
+    claims: &Claims,
+    profile: Profile,
+    headers: &HeaderMap,
+) -> Response {
+    let last_modified = profile.updated_at().unwrap_or(profile.created_at());
+
+    let cache_control = if claims.can_see_private_fields(&profile.id().to_string()) {
+        PRIVATE_CACHE
+    } else {
+        PUBLIC_CACHE
+    };
+    let (body, etag) = profile_view(claims, profile);
+
+    let validators = Validators::new(etag, last_modified);
+
+    let mut response = if validators.not_modified(headers) {
+        StatusCode::NOT_MODIFIED.into_response()
+    } else {
+        body.into_response()
+    };
+
+    let headers = response.headers_mut();
+    validators.apply(headers);
+    headers.insert(CACHE_CONTROL, HeaderValue::from_static(cache_control));
+    // A representação depende de quem pergunta
+    headers.insert(VARY, HeaderValue::from(AUTHORIZATION));
+
+    response
+}
+
+/// Body and ETag of a profile returned by a write: the same view a read by
+/// `claims` would get.
+pub(crate) fn updated_profile_representation(claims: &Claims, profile: Profile) -> Response {
+    let (body, etag) = profile_view(claims, profile);
+
+    ([(ETAG, etag)], body).into_response()
+}
+
+/// The owner and admins see every field; anyone else the public view.
+fn profile_view(claims: &Claims, profile: Profile) -> (ProfileResponse, HeaderValue) {
+    if claims.can_see_private_fields(&profile.id().to_string()) {
+        let body = ProfileResponse::from(profile);
+        let etag = body.etag();
+        (body, etag)
+    } else {
+        let body = ProfileResponse::from(profile.public_view());
+        let etag = body.public_etag();
+        (body, etag)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use std::sync::Arc;
 
-    use axum::{Router, body::Body, extract::Request, http::StatusCode, routing::get};
+    use axum::{Router, body::Body, extract::Request, routing::get};
     use fake::{Fake, faker::internet::en::FreeEmail};
     use tower::ServiceExt;
 
     use crate::{
         domain::{
-            models::profile::Profile, object_values::email::Email,
+            object_values::{email::Email, privacy_settings::PrivacySettings},
             repositories::profile_repo::MockProfileRepository,
         },
         presentation::api::handlers::tests::{
-            SharedMockRepository, create_test_token, get_test_decoding_key,
+            SharedMockRepository, create_test_token, create_test_token_for, get_test_decoding_key,
         },
+        presentation::api::security::Scope,
     };
 
     use super::*;
@@ -59,6 +150,7 @@ mod tests {
                     None,
                     None,
                     None,
+                    PrivacySettings::default(),
                     chrono::Utc::now(),
                     None,
                     2,
@@ -119,5 +211,199 @@ mod tests {
         let response = app.oneshot(request).await.unwrap();
 
         assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["code"], "PROFILE_NOT_FOUND");
+    }
+
+    const PROFILE_ID: &str = "123e4567-e89b-12d3-a456-426614174000";
+
+    async fn get_profile_with_hidden_fields(token: String) -> serde_json::Value {
+        let mut mock_repo = MockProfileRepository::new();
+
+        mock_repo
+            .expect_get_profile_by_id()
+            .times(1)
+            .returning(|id| {
+                Ok(Some(Profile::from_parts(
+                    id.clone(),
+                    Email::try_from(FreeEmail().fake::<String>()).unwrap(),
+                    Some("John".try_into().unwrap()),
+                    Some("Doe".try_into().unwrap()),
+                    Some("A simple bio".try_into().unwrap()),
+                    None,
+                    PrivacySettings::new(true, false, false, true),
+                    chrono::Utc::now(),
+                    None,
+                    2,
+                )))
+            });
+
+        let shared_repo = SharedMockRepository(Arc::new(mock_repo));
+
+        let app_state = AppState::new(Arc::new(shared_repo), Arc::new(get_test_decoding_key()));
+
+        let app = Router::new()
+            .route("/profiles/{id}", get(get_profile_by_id_handler))
+            .with_state(app_state);
+
+        let request = Request::builder()
+            .method("GET")
+            .header("authorization", format!("Bearer {}", token))
+            .uri(format!("/profiles/{}", PROFILE_ID))
+            .body(Body::empty())
+            .unwrap();
+
+        let response = app.oneshot(request).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    #[tokio::test]
+    async fn when_caller_is_not_owner_should_hide_private_fields() {
+        let token = create_test_token_for(&uuid::Uuid::now_v7().to_string(), &[Scope::ProfileRead]);
+
+        let body = get_profile_with_hidden_fields(token).await;
+
+        assert_eq!(body["firstName"], "John");
+        assert!(body["lastName"].is_null());
+        assert!(body["bio"].is_null());
+    }
+
+    #[tokio::test]
+    async fn when_caller_is_owner_should_return_every_field() {
+        let token = create_test_token_for(PROFILE_ID, &[]);
+
+        let body = get_profile_with_hidden_fields(token).await;
+
+        assert_eq!(body["firstName"], "John");
+        assert_eq!(body["lastName"], "Doe");
+        assert_eq!(body["bio"], "A simple bio");
+    }
+
+    async fn get_profile_conditionally(
+        token: String,
+        condition: Option<(&'static str, String)>,
+    ) -> Response {
+        let mut mock_repo = MockProfileRepository::new();
+
+        mock_repo
+            .expect_get_profile_by_id()
+            .times(1)
+            .returning(|id| {
+                Ok(Some(Profile::from_parts(
+                    id.clone(),
+                    Email::try_from("john.doe@example.com").unwrap(),
+                    Some("John".try_into().unwrap()),
+                    None,
+                    None,
+                    None,
+                    PrivacySettings::default(),
+                    "2026-01-01T00:00:00Z".parse().unwrap(),
+                    Some("2026-07-01T12:30:15Z".parse().unwrap()),
+                    3,
+                )))
+            });
+
+        let app_state = AppState::new(
+            Arc::new(SharedMockRepository(Arc::new(mock_repo))),
+            Arc::new(get_test_decoding_key()),
+        );
+
+        let app = Router::new()
+            .route("/profiles/{id}", get(get_profile_by_id_handler))
+            .with_state(app_state);
+
+        let mut request = Request::builder()
+            .method("GET")
+            .header("authorization", format!("Bearer {}", token))
+            .uri(format!("/profiles/{}", PROFILE_ID));
+
+        if let Some((name, value)) = condition {
+            request = request.header(name, value);
+        }
+
+        app.oneshot(request.body(Body::empty()).unwrap())
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn when_caller_is_owner_should_set_private_validators() {
+        let response =
+            get_profile_conditionally(create_test_token_for(PROFILE_ID, &[]), None).await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()["etag"], "\"3\"");
+        assert_eq!(
+            response.headers()["last-modified"],
+            "Wed, 01 Jul 2026 12:30:15 GMT"
+        );
+        assert_eq!(response.headers()[CACHE_CONTROL], PRIVATE_CACHE);
+        assert_eq!(response.headers()[VARY], "authorization");
+    }
+
+    #[tokio::test]
+    async fn when_caller_is_not_owner_should_set_public_validators() {
+        let token = create_test_token_for(&uuid::Uuid::now_v7().to_string(), &[Scope::ProfileRead]);
+
+        let response = get_profile_conditionally(token, None).await;
+
+        assert_eq!(response.headers()["etag"], "\"3-public\"");
+        assert_eq!(response.headers()[CACHE_CONTROL], PUBLIC_CACHE);
+    }
+
+    #[tokio::test]
+    async fn when_if_none_match_is_current_should_return_not_modified() {
+        let response = get_profile_conditionally(
+            create_test_token_for(PROFILE_ID, &[]),
+            Some(("if-none-match", "\"3\"".into())),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(response.headers()["etag"], "\"3\"");
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert!(body.is_empty());
+    }
+
+    #[tokio::test]
+    async fn when_if_none_match_is_from_another_view_should_return_profile() {
+        let response = get_profile_conditionally(
+            create_test_token_for(PROFILE_ID, &[]),
+            Some(("if-none-match", "\"3-public\"".into())),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn when_not_modified_since_should_return_not_modified() {
+        let unchanged = get_profile_conditionally(
+            create_test_token_for(PROFILE_ID, &[]),
+            Some(("if-modified-since", "Wed, 01 Jul 2026 12:30:15 GMT".into())),
+        )
+        .await;
+        let changed = get_profile_conditionally(
+            create_test_token_for(PROFILE_ID, &[]),
+            Some(("if-modified-since", "Tue, 30 Jun 2026 00:00:00 GMT".into())),
+        )
+        .await;
+
+        assert_eq!(unchanged.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(changed.status(), StatusCode::OK);
     }
 }
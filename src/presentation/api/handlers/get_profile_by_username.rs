@@ -0,0 +1,184 @@
+use axum::{
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+    response::Response,
+};
+
+use crate::{
+    application::dtos::get_profile_by_username_input::GetProfileByUsernameInput,
+    domain::{models::error_code::ErrorCode, repositories::profile_repo::ProfileRepository},
+    presentation::api::{
+        handlers::{get_profile_by_id::profile_representation, responses::ProfileResponse},
+        security::Claims,
+        service::AppState,
+        utils::AppErrorResponse,
+    },
+};
+
+#[utoipa::path(
+    get,
+    path = "/by-username/{username}",
+    tag = "profiles",
+    params(
+        ("username" = String, Path, description = "Profile handle, case-insensitive"),
+        ("If-None-Match" = Option<String>, Header, description = "ETag of a cached copy"),
+        ("If-Modified-Since" = Option<String>, Header, description = "Last-Modified of a cached copy")
+    ),
+    responses(
+        (status = 200, description = "Profile holding the handle; fields hidden by its privacy settings are omitted unless the caller owns it or is an admin", body = ProfileResponse, headers(
+            ("ETag" = String, description = "Version of this representation"),
+            ("Last-Modified" = String, description = "Time of the last change"),
+            ("Cache-Control" = String, description = "private for the owner's view, public otherwise")
+        )),
+        (status = 304, description = "Cached copy is still current"),
+        (status = 400, description = "Invalid username", body = AppErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = AppErrorResponse),
+        (status = 403, description = "Not the owner and missing profile:read scope", body = AppErrorResponse),
+        (status = 404, description = "No profile holds the handle", body = AppErrorResponse),
+        (status = 429, description = "Rate limit exceeded", body = AppErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn get_profile_by_username_handler<R: ProfileRepository>(
+    claims: Claims,
+    State(state): State<AppState<R>>,
+    Path(username): Path<String>,
+    headers: HeaderMap,
+) -> Result<Response, AppErrorResponse> {
+    let command = GetProfileByUsernameInput::try_new(username)?;
+
+    let profile = state
+        .get_profile_by_username_use_case
+        .execute(command)
+        .await
+        .map_err(AppErrorResponse::from)?;
+
+    // O id só é conhecido depois da busca
+    if !claims.can_read(&profile.id().to_string()) {
+        return Err(AppErrorResponse::new(
+            StatusCode::FORBIDDEN,
+            ErrorCode::AccessDenied,
+            "Access denied",
+        )
+        .with_details("Requires read privileges"));
+    }
+
+    Ok(profile_representation(&claims, profile, &headers))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{Router, body::Body, extract::Request, routing::get};
+    use tower::ServiceExt;
+
+    use crate::{
+        domain::{
+            models::profile::Profile,
+            object_values::{email::Email, id::Id, privacy_settings::PrivacySettings},
+            repositories::profile_repo::MockProfileRepository,
+        },
+        presentation::api::handlers::tests::{
+            SharedMockRepository, create_test_token, create_test_token_for, get_test_decoding_key,
+        },
+        presentation::api::security::Scope,
+    };
+
+    use super::*;
+
+    const PROFILE_ID: &str = "123e4567-e89b-12d3-a456-426614174000";
+
+    fn app(mock_repo: MockProfileRepository) -> Router {
+        let shared_repo = SharedMockRepository(Arc::new(mock_repo));
+        let app_state = AppState::new(Arc::new(shared_repo), Arc::new(get_test_decoding_key()));
+
+        Router::new()
+            .route(
+                "/profiles/by-username/{username}",
+                get(get_profile_by_username_handler),
+            )
+            .with_state(app_state)
+    }
+
+    fn held_by_profile(mock_repo: &mut MockProfileRepository) {
+        mock_repo
+            .expect_get_profile_by_username()
+            .withf(|username| username.as_ref() == "john.doe")
+            .times(1)
+            .returning(|username| {
+                let mut profile = Profile::new(
+                    Id::try_from(PROFILE_ID.to_string()).unwrap(),
+                    Email::try_from("john.doe@example.com").unwrap(),
+                )
+                .with_username(Some(username.clone()), None);
+                profile.update_privacy_settings(PrivacySettings::new(true, false, true, true));
+
+                Ok(Some(profile))
+            });
+    }
+
+    async fn send(app: Router, token: String, username: &str) -> Response {
+        let request = Request::builder()
+            .method("GET")
+            .header("authorization", format!("Bearer {}", token))
+            .uri(format!("/profiles/by-username/{}", username))
+            .body(Body::empty())
+            .unwrap();
+
+        app.oneshot(request).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn when_username_is_held_should_return_public_view() {
+        let mut mock_repo = MockProfileRepository::new();
+        held_by_profile(&mut mock_repo);
+
+        let token = create_test_token_for("someone-else", &[Scope::ProfileRead]);
+        let response = send(app(mock_repo), token, "John.Doe").await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()["etag"], "\"2-public\"");
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["id"], PROFILE_ID);
+        assert_eq!(body["username"], "john.doe");
+    }
+
+    #[tokio::test]
+    async fn when_caller_cannot_read_profile_should_return_forbidden() {
+        let mut mock_repo = MockProfileRepository::new();
+        held_by_profile(&mut mock_repo);
+
+        let token = create_test_token_for("someone-else", &[]);
+        let response = send(app(mock_repo), token, "john.doe").await;
+
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn when_username_is_free_should_return_not_found() {
+        let mut mock_repo = MockProfileRepository::new();
+        mock_repo
+            .expect_get_profile_by_username()
+            .times(1)
+            .returning(|_| Ok(None));
+
+        let response = send(app(mock_repo), create_test_token(), "nobody").await;
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn when_username_is_invalid_should_return_bad_request() {
+        let mut mock_repo = MockProfileRepository::new();
+        mock_repo.expect_get_profile_by_username().never();
+
+        let response = send(app(mock_repo), create_test_token(), "jd").await;
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+}
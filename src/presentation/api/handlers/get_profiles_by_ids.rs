@@ -0,0 +1,248 @@
+use axum::extract::State;
+
+use crate::{
+    application::dtos::get_profiles_by_ids_input::GetProfilesByIdsInput,
+    domain::repositories::profile_repo::ProfileRepository,
+    presentation::api::{
+        handlers::{
+            requests::GetProfilesByIdsRequest,
+            responses::{ProfileResponse, ProfilesByIdsResponse},
+        },
+        security::Claims,
+        service::AppState,
+        utils::{AppErrorResponse, ValidatedJson},
+    },
+};
+
+#[utoipa::path(
+    post,
+    path = "/batch-get",
+    tag = "profiles",
+    request_body = GetProfilesByIdsRequest,
+    responses(
+        (status = 200, description = "Profiles split into found, missing and forbidden ids; each profile follows the same privacy rules as a single read", body = ProfilesByIdsResponse),
+        (status = 400, description = "Invalid request body or id", body = AppErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = AppErrorResponse),
+        (status = 429, description = "Rate limit exceeded", body = AppErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn get_profiles_by_ids_handler<R: ProfileRepository>(
+    claims: Claims,
+    State(state): State<AppState<R>>,
+    ValidatedJson(input): ValidatedJson<GetProfilesByIdsRequest>,
+) -> Result<ProfilesByIdsResponse, AppErrorResponse> {
+    let mut command = GetProfilesByIdsInput::try_new(input.ids)?;
+
+    // Mesma regra de ReadClaims, aplicada a cada id
+    let (allowed, forbidden) = command
+        .ids
+        .into_iter()
+        .partition(|id| claims.can_read(&id.to_string()));
+    command.ids = allowed;
+
+    let result = state
+        .get_profiles_by_ids_use_case
+        .execute(command)
+        .await
+        .map_err(AppErrorResponse::from)?;
+
+    Ok(ProfilesByIdsResponse {
+        found: result
+            .found
+            .into_iter()
+            .map(|profile| {
+                if claims.can_see_private_fields(&profile.id().to_string()) {
+                    ProfileResponse::from(profile)
+                } else {
+                    ProfileResponse::from(profile.public_view())
+                }
+            })
+            .collect(),
+        missing: result.missing.iter().map(ToString::to_string).collect(),
+        forbidden: forbidden.iter().map(ToString::to_string).collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{
+        Router,
+        body::Body,
+        extract::Request,
+        http::{StatusCode, header::AUTHORIZATION},
+        routing::post,
+    };
+    use fake::{Fake, faker::internet::en::FreeEmail};
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::{
+        domain::{
+            models::profile::Profile,
+            object_values::{email::Email, id::Id, privacy_settings::PrivacySettings},
+            repositories::profile_repo::MockProfileRepository,
+        },
+        presentation::api::{
+            handlers::tests::{SharedMockRepository, create_test_token_for, get_test_decoding_key},
+            security::Scope,
+        },
+    };
+
+    fn profile(id: &Id) -> Profile {
+        Profile::from_parts(
+            id.clone(),
+            Email::try_from(FreeEmail().fake::<String>()).unwrap(),
+            None,
+            None,
+            None,
+            None,
+            PrivacySettings::default(),
+            chrono::Utc::now(),
+            None,
+            1,
+        )
+    }
+
+    async fn send(
+        mock_repo: MockProfileRepository,
+        token: String,
+        body: Value,
+    ) -> (StatusCode, Value) {
+        let app_state = AppState::new(
+            Arc::new(SharedMockRepository(Arc::new(mock_repo))),
+            Arc::new(get_test_decoding_key()),
+        );
+
+        let app = Router::new()
+            .route("/profiles/batch-get", post(get_profiles_by_ids_handler))
+            .with_state(app_state);
+
+        let request = Request::builder()
+            .method("POST")
+            .uri("/profiles/batch-get")
+            .header(AUTHORIZATION, format!("Bearer {}", token))
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap();
+
+        let response = app.oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn when_caller_has_read_scope_should_split_found_and_missing() {
+        let (existing, absent) = (Id::generate(), Id::generate());
+        let mut mock_repo = MockProfileRepository::new();
+
+        let stored = profile(&existing);
+        mock_repo
+            .expect_get_profiles_by_ids()
+            .times(1)
+            .returning(move |_| Ok(vec![stored.clone()]));
+
+        let token = create_test_token_for("feed-service", &[Scope::ProfileRead]);
+
+        let (status, body) = send(
+            mock_repo,
+            token,
+            json!({"ids": [existing.to_string(), absent.to_string()]}),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["found"][0]["id"], existing.to_string());
+        assert_eq!(body["missing"], json!([absent.to_string()]));
+        assert_eq!(body["forbidden"], json!([]));
+    }
+
+    #[tokio::test]
+    async fn when_caller_only_owns_one_profile_should_forbid_the_others_without_querying_them() {
+        let (own, other) = (Id::generate(), Id::generate());
+        let mut mock_repo = MockProfileRepository::new();
+
+        let stored = profile(&own);
+        let expected = vec![own.clone()];
+        mock_repo
+            .expect_get_profiles_by_ids()
+            .withf(move |ids| ids == expected.as_slice())
+            .times(1)
+            .returning(move |_| Ok(vec![stored.clone()]));
+
+        let token = create_test_token_for(&own.to_string(), &[]);
+
+        let (status, body) = send(
+            mock_repo,
+            token,
+            json!({"ids": [own.to_string(), other.to_string()]}),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["found"][0]["id"], own.to_string());
+        assert_eq!(body["missing"], json!([]));
+        assert_eq!(body["forbidden"], json!([other.to_string()]));
+    }
+
+    #[tokio::test]
+    async fn when_caller_is_not_owner_should_apply_privacy_settings() {
+        let id = Id::generate();
+        let mut mock_repo = MockProfileRepository::new();
+
+        let hidden = Profile::from_parts(
+            id.clone(),
+            Email::try_from(FreeEmail().fake::<String>()).unwrap(),
+            None,
+            None,
+            Some("A bio that is long enough".to_string().try_into().unwrap()),
+            None,
+            PrivacySettings::new(true, true, false, true),
+            chrono::Utc::now(),
+            None,
+            1,
+        );
+        mock_repo
+            .expect_get_profiles_by_ids()
+            .returning(move |_| Ok(vec![hidden.clone()]));
+
+        let token = create_test_token_for("feed-service", &[Scope::ProfileRead]);
+
+        let (_, body) = send(mock_repo, token, json!({"ids": [id.to_string()]})).await;
+
+        assert_eq!(body["found"][0]["bio"], Value::Null);
+    }
+
+    #[tokio::test]
+    async fn when_too_many_ids_are_requested_should_return_bad_request() {
+        let ids: Vec<String> = (0..101).map(|_| Id::generate().to_string()).collect();
+        let token = create_test_token_for("feed-service", &[Scope::ProfileRead]);
+
+        let (status, body) = send(MockProfileRepository::new(), token, json!({"ids": ids})).await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "VALIDATION_FAILED");
+    }
+
+    #[tokio::test]
+    async fn when_an_id_is_invalid_should_return_bad_request() {
+        let token = create_test_token_for("feed-service", &[Scope::ProfileRead]);
+
+        let (status, body) = send(
+            MockProfileRepository::new(),
+            token,
+            json!({"ids": [Id::generate().to_string(), "nope"]}),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "ID_INVALID");
+    }
+}
@@ -1,7 +1,15 @@
+pub mod bulk_profiles;
+pub mod change_username;
 pub mod create_profile;
+pub mod export_profile;
+pub mod get_privacy_settings;
 pub mod get_profile_by_id;
+pub mod get_profile_by_username;
+pub mod get_profiles_by_ids;
 pub mod requests;
 pub mod responses;
+pub mod update_display_name;
+pub mod update_privacy_settings;
 pub mod update_profile_by_id;
 
 #[cfg(test)]
@@ -18,11 +26,13 @@ pub mod tests {
 
     use crate::domain::{
         models::profile::Profile,
-        object_values::id::Id,
+        object_values::{id::Id, username::Username},
         repositories::profile_repo::{
-            MockProfileRepository, ProfileRepository, ProfileRepositoryError,
+            BatchWriteResult, MockProfileRepository, ProfileRepository, ProfileRepositoryError,
+            UsernameReservation,
         },
     };
+    use crate::presentation::api::security::Scope;
 
     // Este é o "truque" para o Axum:
     // Um wrapper que é Clone, mas aponta para o mesmo Mock
@@ -38,9 +48,62 @@ pub mod tests {
             self.0.get_profile_by_id(id).await
         }
 
+        async fn get_complete_profile_by_id(
+            &self,
+            id: &Id,
+        ) -> Result<Option<Profile>, ProfileRepositoryError> {
+            self.0.get_complete_profile_by_id(id).await
+        }
+
+        async fn get_profiles_by_ids(
+            &self,
+            ids: &[Id],
+        ) -> Result<Vec<Profile>, ProfileRepositoryError> {
+            self.0.get_profiles_by_ids(ids).await
+        }
+
+        async fn insert_profiles(
+            &self,
+            profiles: &[Profile],
+        ) -> Result<BatchWriteResult, ProfileRepositoryError> {
+            self.0.insert_profiles(profiles).await
+        }
+
+        async fn update_profiles(
+            &self,
+            profiles: &[Profile],
+        ) -> Result<BatchWriteResult, ProfileRepositoryError> {
+            self.0.update_profiles(profiles).await
+        }
+
+        async fn delete_profile(&self, id: &Id) -> Result<bool, ProfileRepositoryError> {
+            self.0.delete_profile(id).await
+        }
+
         async fn save(&self, profile: &Profile) -> Result<(), ProfileRepositoryError> {
             self.0.save(profile).await
         }
+
+        async fn get_profile_by_username(
+            &self,
+            username: &Username,
+        ) -> Result<Option<Profile>, ProfileRepositoryError> {
+            self.0.get_profile_by_username(username).await
+        }
+
+        async fn get_username_reservation(
+            &self,
+            username: &Username,
+        ) -> Result<Option<UsernameReservation>, ProfileRepositoryError> {
+            self.0.get_username_reservation(username).await
+        }
+
+        async fn reserve_username(
+            &self,
+            reservation: &UsernameReservation,
+        ) -> Result<(), ProfileRepositoryError> {
+            self.0.reserve_username(reservation).await
+        }
     }
 
     lazy_static! {
@@ -61,15 +124,30 @@ pub mod tests {
     pub fn create_test_token() -> String {
         let profile_id = Uuid::now_v7().to_string();
 
-        let claims = json!({
-            "sub": profile_id,
+        create_test_token_for(
+            &profile_id,
+            &[
+                Scope::ProfileAdmin,
+                Scope::ProfileCreate,
+                Scope::ProfileRead,
+                Scope::ProfileUpdate,
+                Scope::ProfileDelete,
+            ],
+        )
+    }
+
+    pub fn create_test_token_for(sub: &str, scopes: &[Scope]) -> String {
+        encode_test_claims(json!({
+            "sub": sub,
             "iat": 1700000000u64,
             "exp": 9999999999u64,
             "aud": ["profile-service"],
-            "scopes": ["profile:admin", "profile:create", "profile:read", "profile:update", "profile:delete"],
+            "scopes": scopes,
             "email": "test@example.com"
-        });
+        }))
+    }
 
+    pub fn encode_test_claims(claims: serde_json::Value) -> String {
         let encoding_key = EncodingKey::from_ed_der(&TEST_KEYS.0);
         encode(&Header::new(Algorithm::EdDSA), &claims, &encoding_key).unwrap()
     }
@@ -1,7 +1,8 @@
 use serde::Deserialize;
+use utoipa::{IntoParams, ToSchema};
 use validify::Validify;
 
-#[derive(Debug, Clone, Deserialize, Validify)]
+#[derive(Debug, Clone, Deserialize, Validify, ToSchema)]
 pub struct CreateProfileRequest {
     #[modify(lowercase, trim)]
     #[validate(length(
@@ -9,6 +10,7 @@ pub struct CreateProfileRequest {
         max = 100,
         message = "ID must be between 3 and 100 characters"
     ))]
+    #[schema(min_length = 3, max_length = 100)]
     pub id: String,
 
     #[modify(lowercase, trim)]
@@ -20,10 +22,22 @@ pub struct CreateProfileRequest {
             message = "Email must be a valid email address between 3 and 255 characters"
         )
     )]
+    #[schema(min_length = 3, max_length = 255, format = Email)]
     pub email: String,
 }
 
-#[derive(Debug, Clone, Deserialize, Validify)]
+#[derive(Debug, Clone, Deserialize, Validify, ToSchema)]
+pub struct GetProfilesByIdsRequest {
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "Between 1 and 100 ids must be requested"
+    ))]
+    #[schema(min_items = 1, max_items = 100)]
+    pub ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Validify, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateProfileRequest {
     #[modify(trim)]
@@ -32,6 +46,7 @@ pub struct UpdateProfileRequest {
         max = 15,
         message = "First name must be between 2 and 15 characters"
     ))]
+    #[schema(min_length = 2, max_length = 15)]
     pub first_name: Option<String>,
 
     #[modify(trim)]
@@ -40,6 +55,7 @@ pub struct UpdateProfileRequest {
         max = 25,
         message = "Last name must be between 2 and 25 characters"
     ))]
+    #[schema(min_length = 2, max_length = 25)]
     pub last_name: Option<String>,
 
     #[modify(trim)]
@@ -48,6 +64,7 @@ pub struct UpdateProfileRequest {
         max = 160,
         message = "Bio must be between 10 and 160 characters"
     ))]
+    #[schema(min_length = 10, max_length = 160)]
     pub bio: Option<String>,
 
     #[modify(trim)]
@@ -56,5 +73,49 @@ pub struct UpdateProfileRequest {
         max = 2048,
         message = "Profile image URL must be between 5 and 2048 characters"
     ))]
+    #[schema(min_length = 5, max_length = 2048)]
     pub profile_image_url: Option<String>,
 }
+
+/// One line of a bulk update: the profile id and the fields to change.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct BulkUpdateProfileRequest {
+    pub id: String,
+    #[serde(flatten)]
+    pub fields: UpdateProfileRequest,
+}
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdatePrivacySettingsRequest {
+    pub first_name_visible: Option<bool>,
+    pub last_name_visible: Option<bool>,
+    pub bio_visible: Option<bool>,
+    pub profile_image_url_visible: Option<bool>,
+}
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeUsernameRequest {
+    #[schema(example = "john.doe", min_length = 3, max_length = 20)]
+    pub username: String,
+}
+
+/// Both preferences are replaced; an absent `displayName` goes back to the
+/// name composed from the first and last names.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateDisplayNameRequest {
+    #[schema(example = "Johnny D", max_length = 50)]
+    pub display_name: Option<String>,
+    /// `given_family` (default), `family_given` or `initials`
+    #[schema(example = "given_family")]
+    pub display_name_format: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ExportProfileQuery {
+    /// `json` (default) or `csv`
+    pub format: Option<String>,
+}
@@ -1,18 +1,29 @@
-use axum::response::IntoResponse;
+use axum::{http::HeaderValue, response::IntoResponse};
 use serde::Serialize;
+use utoipa::ToSchema;
 
-use crate::domain::models::profile::Profile;
+use crate::{
+    application::use_cases::export_profile::ProfileExport,
+    domain::{models::profile::Profile, object_values::privacy_settings::PrivacySettings},
+    presentation::api::utils::AppErrorResponse,
+};
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ProfileResponse {
     pub id: String,
     pub email: String,
+    pub username: Option<String>,
+    /// Chosen by the owner, or composed from the visible names in their
+    /// preferred format, or the part of the email before the `@`
+    pub display_name: String,
     pub first_name: Option<String>,
     pub last_name: Option<String>,
     pub bio: Option<String>,
     pub profile_image_url: Option<String>,
+    #[schema(format = DateTime)]
     pub created_at: String,
+    #[schema(format = DateTime)]
     pub updated_at: Option<String>,
     pub version: u64,
 }
@@ -22,6 +33,8 @@ impl From<Profile> for ProfileResponse {
         Self {
             id: profile.id().to_string(),
             email: profile.email().to_string(),
+            username: profile.username().map(|u| u.to_string()),
+            display_name: profile.display_name().into_inner(),
             first_name: profile.first_name().map(|f| f.to_string()),
             last_name: profile.last_name().map(|l| l.to_string()),
             bio: profile.bio().map(|b| b.to_string()),
@@ -33,8 +46,250 @@ impl From<Profile> for ProfileResponse {
     }
 }
 
+impl ProfileResponse {
+    /// Strong validator of this representation. The version is bumped on
+    /// every write, so it changes whenever the profile does.
+    pub fn etag(&self) -> HeaderValue {
+        HeaderValue::from_str(&format!("\"{}\"", self.version))
+            .expect("a version is a valid header value")
+    }
+
+    /// Validator of the public view. It differs from the owner's because the
+    /// two representations of the same version carry different fields.
+    pub fn public_etag(&self) -> HeaderValue {
+        HeaderValue::from_str(&format!("\"{}-public\"", self.version))
+            .expect("a version is a valid header value")
+    }
+}
+
 impl IntoResponse for ProfileResponse {
     fn into_response(self) -> axum::response::Response {
         axum::Json(self).into_response()
     }
 }
+
+/// Outcome of a batch lookup. Every requested id appears in exactly one of
+/// the three lists; forbidden ids are not looked up, so their existence is
+/// not revealed.
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfilesByIdsResponse {
+    pub found: Vec<ProfileResponse>,
+    pub missing: Vec<String>,
+    pub forbidden: Vec<String>,
+}
+
+impl IntoResponse for ProfilesByIdsResponse {
+    fn into_response(self) -> axum::response::Response {
+        axum::Json(self).into_response()
+    }
+}
+
+/// Data portability bundle: every stored field of the profile, private ones
+/// included, plus the record's metadata.
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileExportResponse {
+    #[schema(format = DateTime)]
+    pub exported_at: String,
+    pub profile: ExportedProfileResponse,
+    pub metadata: ExportMetadataResponse,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportedProfileResponse {
+    pub id: String,
+    pub email: String,
+    pub username: Option<String>,
+    pub display_name: Option<String>,
+    pub display_name_format: String,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub bio: Option<String>,
+    pub profile_image_url: Option<String>,
+    pub privacy_settings: PrivacySettingsResponse,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportMetadataResponse {
+    #[schema(format = DateTime)]
+    pub created_at: String,
+    #[schema(format = DateTime)]
+    pub updated_at: Option<String>,
+    pub version: u64,
+}
+
+impl From<ProfileExport> for ProfileExportResponse {
+    fn from(export: ProfileExport) -> Self {
+        let profile = export.profile;
+
+        Self {
+            exported_at: export.exported_at.to_rfc3339(),
+            profile: ExportedProfileResponse {
+                id: profile.id().to_string(),
+                email: profile.email().to_string(),
+                username: profile.username().map(|u| u.to_string()),
+                display_name: profile.display_name_override().map(|d| d.to_string()),
+                display_name_format: profile.display_name_format().to_string(),
+                first_name: profile.first_name().map(|f| f.to_string()),
+                last_name: profile.last_name().map(|l| l.to_string()),
+                bio: profile.bio().map(|b| b.to_string()),
+                profile_image_url: profile.profile_image_url().map(|url| url.to_string()),
+                privacy_settings: PrivacySettingsResponse::from(profile.privacy_settings()),
+            },
+            metadata: ExportMetadataResponse {
+                created_at: profile.created_at().to_rfc3339(),
+                updated_at: profile.updated_at().map(|u| u.to_rfc3339()),
+                version: profile.version(),
+            },
+        }
+    }
+}
+
+impl ProfileExportResponse {
+    /// The same bundle as a header row and a single data row, for users who
+    /// open it in a spreadsheet. Absent values are empty cells.
+    pub fn to_csv(&self) -> String {
+        let (profile, privacy, metadata) = (
+            &self.profile,
+            &self.profile.privacy_settings,
+            &self.metadata,
+        );
+
+        let row = [
+            profile.id.clone(),
+            profile.email.clone(),
+            profile.first_name.clone().unwrap_or_default(),
+            profile.last_name.clone().unwrap_or_default(),
+            profile.bio.clone().unwrap_or_default(),
+            profile.profile_image_url.clone().unwrap_or_default(),
+            profile.username.clone().unwrap_or_default(),
+            profile.display_name.clone().unwrap_or_default(),
+            profile.display_name_format.clone(),
+            privacy.first_name_visible.to_string(),
+            privacy.last_name_visible.to_string(),
+            privacy.bio_visible.to_string(),
+            privacy.profile_image_url_visible.to_string(),
+            metadata.created_at.clone(),
+            metadata.updated_at.clone().unwrap_or_default(),
+            metadata.version.to_string(),
+            self.exported_at.clone(),
+        ];
+
+        format!(
+            "{}\r\n{}\r\n",
+            CSV_HEADER.join(","),
+            row.iter()
+                .map(|cell| csv_cell(cell))
+                .collect::<Vec<_>>()
+                .join(",")
+        )
+    }
+}
+
+const CSV_HEADER: [&str; 17] = [
+    "id",
+    "email",
+    "first_name",
+    "last_name",
+    "bio",
+    "profile_image_url",
+    "username",
+    "display_name",
+    "display_name_format",
+    "first_name_visible",
+    "last_name_visible",
+    "bio_visible",
+    "profile_image_url_visible",
+    "created_at",
+    "updated_at",
+    "version",
+    "exported_at",
+];
+
+/// Quotes a cell as RFC 4180 asks. A leading `=`, `+`, `-` or `@` is
+/// escaped with `'` so spreadsheets don't evaluate user text as a formula.
+fn csv_cell(value: &str) -> String {
+    let value = match value.chars().next() {
+        Some('=' | '+' | '-' | '@') => format!("'{}", value),
+        _ => value.to_string(),
+    };
+
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum BulkLineStatus {
+    Created,
+    Updated,
+    Failed,
+}
+
+/// Outcome of one line of a bulk request, streamed back as NDJSON.
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkLineResponse {
+    pub line: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub status: BulkLineStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<AppErrorResponse>,
+}
+
+impl BulkLineResponse {
+    pub fn succeeded(line: usize, status: BulkLineStatus, profile: &Profile) -> Self {
+        Self {
+            line,
+            id: Some(profile.id().to_string()),
+            status,
+            version: Some(profile.version()),
+            error: None,
+        }
+    }
+
+    pub fn failed(line: usize, id: Option<String>, error: AppErrorResponse) -> Self {
+        Self {
+            line,
+            id,
+            status: BulkLineStatus::Failed,
+            version: None,
+            error: Some(error),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PrivacySettingsResponse {
+    pub first_name_visible: bool,
+    pub last_name_visible: bool,
+    pub bio_visible: bool,
+    pub profile_image_url_visible: bool,
+}
+
+impl From<&PrivacySettings> for PrivacySettingsResponse {
+    fn from(settings: &PrivacySettings) -> Self {
+        Self {
+            first_name_visible: settings.first_name_visible(),
+            last_name_visible: settings.last_name_visible(),
+            bio_visible: settings.bio_visible(),
+            profile_image_url_visible: settings.profile_image_url_visible(),
+        }
+    }
+}
+
+impl IntoResponse for PrivacySettingsResponse {
+    fn into_response(self) -> axum::response::Response {
+        axum::Json(self).into_response()
+    }
+}
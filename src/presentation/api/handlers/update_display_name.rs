@@ -0,0 +1,171 @@
+use axum::{
+    extract::{Path, State},
+    response::Response,
+};
+
+use crate::{
+    application::dtos::update_display_name_input::UpdateDisplayNameInput,
+    domain::repositories::profile_repo::ProfileRepository,
+    presentation::api::{
+        handlers::{
+            get_profile_by_id::updated_profile_representation, requests::UpdateDisplayNameRequest,
+            responses::ProfileResponse,
+        },
+        security::UpdateClaims,
+        service::AppState,
+        utils::{AppErrorResponse, AppJson},
+    },
+};
+
+#[utoipa::path(
+    put,
+    path = "/{id}/display-name",
+    tag = "profiles",
+    params(
+        ("id" = String, Path, description = "Profile id (UUID)")
+    ),
+    request_body = UpdateDisplayNameRequest,
+    responses(
+        (status = 200, description = "Display name preferences replaced; fields hidden by its privacy settings are omitted unless the caller owns it or is an admin", body = ProfileResponse, headers(
+            ("ETag" = String, description = "Version of this representation")
+        )),
+        (status = 400, description = "Invalid display name or format", body = AppErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = AppErrorResponse),
+        (status = 403, description = "Not the owner and missing profile:update scope", body = AppErrorResponse),
+        (status = 404, description = "Profile not found", body = AppErrorResponse),
+        (status = 409, description = "Version conflict", body = AppErrorResponse),
+        (status = 429, description = "Rate limit exceeded", body = AppErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn update_display_name_handler<R: ProfileRepository>(
+    UpdateClaims(claims): UpdateClaims,
+    State(state): State<AppState<R>>,
+    Path(id): Path<String>,
+    AppJson(input): AppJson<UpdateDisplayNameRequest>,
+) -> Result<Response, AppErrorResponse> {
+    let command =
+        UpdateDisplayNameInput::try_new(id, input.display_name, input.display_name_format)?;
+
+    state
+        .update_display_name_use_case
+        .execute(command)
+        .await
+        .map_err(AppErrorResponse::from)
+        .map(|profile| updated_profile_representation(&claims, profile))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{Router, body::Body, http::Request, http::StatusCode, routing::put};
+    use serde_json::json;
+    use tower::ServiceExt;
+
+    use crate::{
+        domain::{
+            models::profile::Profile,
+            object_values::{email::Email, first_name::FirstName, last_name::LastName},
+            repositories::profile_repo::MockProfileRepository,
+        },
+        presentation::api::handlers::tests::{
+            SharedMockRepository, create_test_token, get_test_decoding_key,
+        },
+    };
+
+    use super::*;
+
+    fn app(mock_repo: MockProfileRepository) -> Router {
+        let shared_repo = SharedMockRepository(Arc::new(mock_repo));
+
+        let app_state = AppState::new(Arc::new(shared_repo), Arc::new(get_test_decoding_key()));
+
+        Router::new()
+            .route(
+                "/profiles/{id}/display-name",
+                put(update_display_name_handler),
+            )
+            .with_state(app_state)
+    }
+
+    fn request(body: serde_json::Value) -> Request<Body> {
+        Request::builder()
+            .method("PUT")
+            .uri("/profiles/123e4567-e89b-12d3-a456-426614174000/display-name")
+            .header("content-type", "application/json")
+            .header("authorization", format!("Bearer {}", create_test_token()))
+            .body(Body::from(body.to_string()))
+            .unwrap()
+    }
+
+    async fn body(response: axum::response::Response) -> serde_json::Value {
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    fn stored(mock_repo: &mut MockProfileRepository) {
+        mock_repo
+            .expect_get_profile_by_id()
+            .times(1)
+            .returning(|id| {
+                let mut profile =
+                    Profile::new(id.clone(), Email::try_from("jdoe@example.com").unwrap());
+                profile.update_profile(
+                    Some(FirstName::try_from("John").unwrap()),
+                    Some(LastName::try_from("Doe").unwrap()),
+                    None,
+                    None,
+                );
+                Ok(Some(profile))
+            });
+        mock_repo.expect_save().returning(|_| Ok(()));
+    }
+
+    #[tokio::test]
+    async fn when_format_is_given_should_compose_display_name_in_it() {
+        let mut mock_repo = MockProfileRepository::new();
+        stored(&mut mock_repo);
+
+        let response = app(mock_repo)
+            .oneshot(request(json!({"displayNameFormat": "family_given"})))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(body(response).await["displayName"], "Doe John");
+    }
+
+    #[tokio::test]
+    async fn when_display_name_is_chosen_should_return_it() {
+        let mut mock_repo = MockProfileRepository::new();
+        stored(&mut mock_repo);
+
+        let response = app(mock_repo)
+            .oneshot(request(json!({"displayName": "  Johnny   D "})))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(body(response).await["displayName"], "Johnny D");
+    }
+
+    #[tokio::test]
+    async fn when_format_is_unknown_should_return_bad_request() {
+        let mut mock_repo = MockProfileRepository::new();
+        mock_repo.expect_get_profile_by_id().never();
+
+        let response = app(mock_repo)
+            .oneshot(request(json!({"displayNameFormat": "surname_first"})))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let body = body(response).await;
+        assert_eq!(body["errors"][0]["field"], "displayNameFormat");
+        assert_eq!(body["errors"][0]["code"], "DISPLAY_NAME_INVALID_FORMAT");
+    }
+}
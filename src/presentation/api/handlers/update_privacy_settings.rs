@@ -0,0 +1,170 @@
+use axum::extract::{Path, State};
+
+use crate::{
+    application::dtos::update_privacy_settings_input::UpdatePrivacySettingsInput,
+    domain::repositories::profile_repo::ProfileRepository,
+    presentation::api::{
+        handlers::{requests::UpdatePrivacySettingsRequest, responses::PrivacySettingsResponse},
+        security::OwnerOrAdminClaims,
+        service::AppState,
+        utils::{AppErrorResponse, AppJson},
+    },
+};
+
+#[utoipa::path(
+    put,
+    path = "/{id}/privacy",
+    tag = "profiles",
+    params(
+        ("id" = String, Path, description = "Profile id (UUID)")
+    ),
+    request_body = UpdatePrivacySettingsRequest,
+    responses(
+        (status = 200, description = "Privacy settings updated", body = PrivacySettingsResponse),
+        (status = 400, description = "Invalid request body", body = AppErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = AppErrorResponse),
+        (status = 403, description = "Neither the owner nor an admin", body = AppErrorResponse),
+        (status = 404, description = "Profile not found", body = AppErrorResponse),
+        (status = 409, description = "Version conflict", body = AppErrorResponse),
+        (status = 429, description = "Rate limit exceeded", body = AppErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn update_privacy_settings_handler<R: ProfileRepository>(
+    _: OwnerOrAdminClaims,
+    State(state): State<AppState<R>>,
+    Path(id): Path<String>,
+    AppJson(input): AppJson<UpdatePrivacySettingsRequest>,
+) -> Result<PrivacySettingsResponse, AppErrorResponse> {
+    let command = UpdatePrivacySettingsInput::try_new(
+        id,
+        input.first_name_visible,
+        input.last_name_visible,
+        input.bio_visible,
+        input.profile_image_url_visible,
+    )?;
+
+    state
+        .update_privacy_settings_use_case
+        .execute(command)
+        .await
+        .map_err(AppErrorResponse::from)
+        .map(|profile| PrivacySettingsResponse::from(profile.privacy_settings()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{Router, body::Body, http::Request, http::StatusCode, routing::put};
+    use serde_json::json;
+    use tower::ServiceExt;
+
+    use crate::{
+        domain::{
+            models::profile::Profile, object_values::email::Email,
+            repositories::profile_repo::MockProfileRepository,
+        },
+        presentation::api::{
+            handlers::tests::{
+                SharedMockRepository, create_test_token, create_test_token_for,
+                get_test_decoding_key,
+            },
+            security::Scope,
+        },
+    };
+
+    use super::*;
+
+    fn app(mock_repo: MockProfileRepository) -> Router {
+        let shared_repo = SharedMockRepository(Arc::new(mock_repo));
+
+        let app_state = AppState::new(Arc::new(shared_repo), Arc::new(get_test_decoding_key()));
+
+        Router::new()
+            .route(
+                "/profiles/{id}/privacy",
+                put(update_privacy_settings_handler),
+            )
+            .with_state(app_state)
+    }
+
+    fn request(body: serde_json::Value) -> Request<Body> {
+        Request::builder()
+            .method("PUT")
+            .uri("/profiles/123e4567-e89b-12d3-a456-426614174000/privacy")
+            .header("content-type", "application/json")
+            .header("authorization", format!("Bearer {}", create_test_token()))
+            .body(Body::from(body.to_string()))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn when_profile_exists_should_return_updated_settings() {
+        let mut mock_repo = MockProfileRepository::new();
+
+        mock_repo
+            .expect_get_profile_by_id()
+            .times(1)
+            .returning(|id| {
+                Ok(Some(Profile::new(
+                    id.clone(),
+                    Email::try_from("john.doe@example.com").unwrap(),
+                )))
+            });
+
+        mock_repo.expect_save().times(1).returning(|_| Ok(()));
+
+        let response = app(mock_repo)
+            .oneshot(request(json!({ "bioVisible": false })))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body["bioVisible"], false);
+        assert_eq!(body["firstNameVisible"], true);
+    }
+
+    #[tokio::test]
+    async fn when_profile_does_not_exist_should_return_not_found() {
+        let mut mock_repo = MockProfileRepository::new();
+
+        mock_repo
+            .expect_get_profile_by_id()
+            .times(1)
+            .return_const(Ok(None));
+
+        let response = app(mock_repo)
+            .oneshot(request(json!({ "bioVisible": false })))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn when_caller_only_has_update_scope_should_return_forbidden() {
+        let mut mock_repo = MockProfileRepository::new();
+        mock_repo.expect_get_profile_by_id().never();
+
+        let token = create_test_token_for("someone-else", &[Scope::ProfileUpdate]);
+
+        let request = Request::builder()
+            .method("PUT")
+            .uri("/profiles/123e4567-e89b-12d3-a456-426614174000/privacy")
+            .header("content-type", "application/json")
+            .header("authorization", format!("Bearer {}", token))
+            .body(Body::from(json!({ "bioVisible": false }).to_string()))
+            .unwrap();
+
+        let response = app(mock_repo).oneshot(request).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+}
@@ -1,24 +1,51 @@
 use axum::{
-    Json,
     extract::{Path, State},
-    http::StatusCode,
+    response::Response,
 };
 
 use crate::{
     application::dtos::update_profile_input::UpdateProfileInput,
     domain::repositories::profile_repo::ProfileRepository,
     presentation::api::{
-        handlers::requests::UpdateProfileRequest, security::UpdateClaims, service::AppState,
-        utils::AppErrorResponse,
+        handlers::{
+            get_profile_by_id::updated_profile_representation, requests::UpdateProfileRequest,
+            responses::ProfileResponse,
+        },
+        security::UpdateClaims,
+        service::AppState,
+        utils::{AppErrorResponse, AppJson},
     },
 };
 
+#[utoipa::path(
+    put,
+    path = "/{id}",
+    tag = "profiles",
+    params(
+        ("id" = String, Path, description = "Profile id (UUID)"),
+        ("Idempotency-Key" = Option<String>, Header, description = "Replays the first successful response to retries with the same key")
+    ),
+    request_body = UpdateProfileRequest,
+    responses(
+        (status = 200, description = "Updated profile; fields hidden by its privacy settings are omitted unless the caller owns it or is an admin", body = ProfileResponse, headers(
+            ("ETag" = String, description = "Version of this representation")
+        )),
+        (status = 400, description = "Invalid request body", body = AppErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = AppErrorResponse),
+        (status = 403, description = "Not the owner and missing profile:update scope", body = AppErrorResponse),
+        (status = 404, description = "Profile not found", body = AppErrorResponse),
+        (status = 409, description = "Version conflict, or a request with the same idempotency key is in progress", body = AppErrorResponse),
+        (status = 422, description = "Idempotency key reused with a different request", body = AppErrorResponse),
+        (status = 429, description = "Rate limit exceeded", body = AppErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
 pub async fn update_profile_by_id_handler<R: ProfileRepository>(
-    _: UpdateClaims,
+    UpdateClaims(claims): UpdateClaims,
     State(state): State<AppState<R>>,
     Path(id): Path<String>,
-    Json(input): Json<UpdateProfileRequest>,
-) -> Result<StatusCode, AppErrorResponse> {
+    AppJson(input): AppJson<UpdateProfileRequest>,
+) -> Result<Response, AppErrorResponse> {
     let command = UpdateProfileInput::try_new(
         id,
         input.first_name,
@@ -27,12 +54,13 @@ pub async fn update_profile_by_id_handler<R: ProfileRepository>(
         input.profile_image_url,
     )?;
 
-    state
+    let profile = state
         .update_profile_use_case
         .execute(command)
         .await
-        .map_err(AppErrorResponse::from)
-        .map(|_| StatusCode::OK)
+        .map_err(AppErrorResponse::from)?;
+
+    Ok(updated_profile_representation(&claims, profile))
 }
 
 #[cfg(test)]
@@ -40,7 +68,12 @@ mod tests {
 
     use std::sync::Arc;
 
-    use axum::{Router, body::Body, http::Request, routing::put};
+    use axum::{
+        Router,
+        body::Body,
+        http::{Request, StatusCode, header::ETAG},
+        routing::put,
+    };
     use fake::Fake;
     use fake::faker::name::en::{FirstName, LastName};
     use serde_json::json;
@@ -48,7 +81,11 @@ mod tests {
 
     use crate::domain::models::profile::Profile;
     use crate::domain::object_values::email::Email;
-    use crate::presentation::api::handlers::tests::{create_test_token, get_test_decoding_key};
+    use crate::domain::object_values::privacy_settings::PrivacySettings;
+    use crate::presentation::api::handlers::tests::{
+        create_test_token, create_test_token_for, get_test_decoding_key,
+    };
+    use crate::presentation::api::security::Scope;
     use crate::{
         domain::repositories::profile_repo::MockProfileRepository,
         presentation::api::{handlers::tests::SharedMockRepository, service::AppState},
@@ -96,7 +133,7 @@ mod tests {
     }
 
     #[tokio::test]
-    pub async fn when_profile_exists_should_return_ok() {
+    pub async fn when_profile_exists_should_return_updated_profile() {
         let mut mock_repo = MockProfileRepository::new();
 
         mock_repo
@@ -110,6 +147,7 @@ mod tests {
                     None,
                     None,
                     None,
+                    PrivacySettings::default(),
                     chrono::Utc::now(),
                     None,
                     2,
@@ -146,6 +184,15 @@ mod tests {
         let response = app.oneshot(request).await.unwrap();
 
         assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[ETAG], "\"3\"");
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body["id"], "123e4567-e89b-12d3-a456-426614174000");
+        assert_eq!(body["version"], 3);
     }
 
     #[tokio::test]
@@ -163,6 +210,7 @@ mod tests {
                     None,
                     None,
                     None,
+                    PrivacySettings::default(),
                     chrono::Utc::now(),
                     Some(chrono::Utc::now() + chrono::Duration::hours(1)),
                     2,
@@ -198,4 +246,98 @@ mod tests {
 
         assert_eq!(response.status(), StatusCode::CONFLICT);
     }
+
+    #[tokio::test]
+    pub async fn when_caller_is_not_owner_should_return_public_view() {
+        let mut mock_repo = MockProfileRepository::new();
+
+        mock_repo
+            .expect_get_profile_by_id()
+            .times(1)
+            .returning(|id| {
+                Ok(Some(Profile::from_parts(
+                    id.clone(),
+                    Email::try_from("john.doe@example.com").unwrap(),
+                    None,
+                    None,
+                    None,
+                    None,
+                    PrivacySettings::new(false, true, true, true),
+                    chrono::Utc::now(),
+                    None,
+                    2,
+                )))
+            });
+
+        mock_repo.expect_save().times(1).returning(|_| Ok(()));
+
+        let shared_repo = SharedMockRepository(Arc::new(mock_repo));
+
+        let app_state = AppState::new(Arc::new(shared_repo), Arc::new(get_test_decoding_key()));
+        let token = create_test_token_for("someone-else", &[Scope::ProfileUpdate]);
+
+        let app = Router::new()
+            .route("/profiles/{id}", put(update_profile_by_id_handler))
+            .with_state(app_state);
+
+        let request = Request::builder()
+            .method("PUT")
+            .uri("/profiles/123e4567-e89b-12d3-a456-426614174000")
+            .header("content-type", "application/json")
+            .header("authorization", format!("Bearer {}", token))
+            .body(Body::from(
+                json!({
+                    "firstName": "John",
+                    "lastName": "Doe",
+                })
+                .to_string(),
+            ))
+            .unwrap();
+
+        let response = app.oneshot(request).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[ETAG], "\"3-public\"");
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body["firstName"], serde_json::Value::Null);
+        assert_eq!(body["lastName"], "Doe");
+    }
+
+    #[tokio::test]
+    pub async fn when_body_is_malformed_should_return_invalid_json_error() {
+        let mut mock_repo = MockProfileRepository::new();
+        mock_repo.expect_get_profile_by_id().never();
+
+        let shared_repo = SharedMockRepository(Arc::new(mock_repo));
+
+        let app_state = AppState::new(Arc::new(shared_repo), Arc::new(get_test_decoding_key()));
+
+        let app = Router::new()
+            .route("/profiles/{id}", put(update_profile_by_id_handler))
+            .with_state(app_state);
+
+        let request = Request::builder()
+            .method("PUT")
+            .uri("/profiles/123e4567-e89b-12d3-a456-426614174000")
+            .header("content-type", "application/json")
+            .header("authorization", format!("Bearer {}", create_test_token()))
+            .body(Body::from("{\"firstName\": "))
+            .unwrap();
+
+        let response = app.oneshot(request).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body["code"], "INVALID_JSON");
+    }
 }
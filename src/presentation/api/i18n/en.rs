@@ -0,0 +1,100 @@
+use crate::domain::models::error_code::ErrorCode;
+
+pub fn message(code: ErrorCode) -> &'static str {
+    match code {
+        ErrorCode::ProfileAlreadyExists => "Profile already exists",
+        ErrorCode::ProfileNotFound => "Profile not found",
+        ErrorCode::ProfileVersionConflict => "Version conflict",
+        ErrorCode::ProfileDataCorrupted => "Stored profile data is invalid",
+        ErrorCode::InternalError => "Internal server error",
+
+        ErrorCode::IdInvalid => "Invalid id",
+
+        ErrorCode::EmailEmpty => "Email cannot be empty",
+        ErrorCode::EmailTooLong => "Email is too long (maximum {max} characters)",
+        ErrorCode::EmailInvalid => "Invalid email format (e.g., user@example.com)",
+
+        ErrorCode::FirstNameEmpty => "First name cannot be empty",
+        ErrorCode::FirstNameTooShort => "First name is too short (minimum {min} characters)",
+        ErrorCode::FirstNameTooLong => "First name is too long (maximum {max} characters)",
+        ErrorCode::FirstNameInvalidCharacters => "First name contains invalid characters",
+        ErrorCode::FirstNameInvalidEdgeCharacters => {
+            "First name cannot start or end with a special character"
+        }
+
+        ErrorCode::LastNameEmpty => "Last name cannot be empty",
+        ErrorCode::LastNameTooShort => "Last name is too short (minimum {min} characters)",
+        ErrorCode::LastNameTooLong => "Last name is too long (maximum {max} characters)",
+        ErrorCode::LastNameInvalidCharacters => "Last name contains invalid characters",
+        ErrorCode::LastNameInvalidEdgeCharacters => {
+            "Last name cannot start or end with a special character"
+        }
+
+        ErrorCode::BioEmpty => "Bio cannot be empty",
+        ErrorCode::BioTooShort => "Bio is too short (minimum {min} characters)",
+        ErrorCode::BioTooLong => "Bio is too long (maximum {max} characters)",
+        ErrorCode::BioInvalidCharacters => "Bio contains invalid characters",
+
+        ErrorCode::UsernameEmpty => "Username cannot be empty",
+        ErrorCode::UsernameTooShort => "Username is too short (minimum {min} characters)",
+        ErrorCode::UsernameTooLong => "Username is too long (maximum {max} characters)",
+        ErrorCode::UsernameInvalidCharacters => {
+            "Username may only contain letters, numbers, underscores, and dots"
+        }
+        ErrorCode::UsernameInvalidEdgeCharacters => {
+            "Username must start and end with a letter or number"
+        }
+        ErrorCode::UsernameConfusable => {
+            "Username contains characters that imitate letters or numbers"
+        }
+        ErrorCode::UsernameReserved => "Username is reserved",
+        ErrorCode::UsernameTaken => "Username is already taken",
+        ErrorCode::UsernameChangeTooSoon => "Username was changed too recently",
+
+        ErrorCode::DisplayNameEmpty => "Display name cannot be empty",
+        ErrorCode::DisplayNameTooLong => "Display name is too long (maximum {max} characters)",
+        ErrorCode::DisplayNameInvalidCharacters => {
+            "Display name may only contain letters, numbers, spaces, dots, apostrophes, and hyphens"
+        }
+        ErrorCode::DisplayNameInvalidFormat => {
+            "Display name format must be given_family, family_given, or initials"
+        }
+
+        ErrorCode::ImageUrlEmpty => "URL cannot be empty",
+        ErrorCode::ImageUrlTooLong => "URL is too long (maximum {max} characters)",
+        ErrorCode::ImageUrlInvalid => "Invalid URL format",
+        ErrorCode::ImageUrlInvalidScheme => "URL must start with http:// or https://",
+        ErrorCode::ImageUrlInvalidExtension => {
+            "URL must end with a valid image extension (.jpg, .jpeg, .png, .gif)"
+        }
+
+        ErrorCode::InvalidJson => "Invalid JSON",
+        ErrorCode::ValidationFailed => "Validation error",
+        ErrorCode::FieldTooShort => "Must be at least {min} characters",
+        ErrorCode::FieldTooLong => "Must be at most {max} characters",
+        ErrorCode::FieldInvalidFormat => "Invalid format",
+        ErrorCode::FieldInvalid => "Invalid value",
+        ErrorCode::MissingPathParameter => "Missing path parameter",
+        ErrorCode::ClientAddressUnavailable => "Client address unavailable",
+        ErrorCode::RateLimited => "Too many requests",
+        ErrorCode::PayloadTooLarge => "Request body is too large",
+        ErrorCode::ExportFormatUnsupported => "Unsupported export format",
+
+        ErrorCode::IdempotencyKeyInvalid => "Invalid idempotency key",
+        ErrorCode::IdempotencyKeyReused => {
+            "Idempotency key was already used with a different request"
+        }
+        ErrorCode::IdempotencyRequestInProgress => {
+            "A request with this idempotency key is still being processed"
+        }
+
+        ErrorCode::TokenMissing => "Missing token",
+        ErrorCode::TokenMalformed => "Invalid token format",
+        ErrorCode::TokenExpired => "Token has expired",
+        ErrorCode::TokenNotYetValid => "Token is not valid yet",
+        ErrorCode::TokenInvalidSignature => "Invalid token signature",
+        ErrorCode::TokenInvalidAudience => "Invalid token audience",
+        ErrorCode::TokenInvalid => "Invalid token",
+        ErrorCode::AccessDenied => "Access denied",
+    }
+}
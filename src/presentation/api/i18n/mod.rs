@@ -0,0 +1,302 @@
+mod en;
+mod pt_br;
+
+use std::collections::BTreeMap;
+
+use axum::{
+    body::Body,
+    extract::Request,
+    http::{
+        HeaderMap, HeaderValue,
+        header::{ACCEPT_LANGUAGE, CONTENT_LANGUAGE, CONTENT_LENGTH},
+    },
+    middleware::Next,
+    response::Response,
+};
+use serde_json::Value;
+
+use crate::{domain::models::error_code::ErrorCode, presentation::api::utils::AppErrorResponse};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Locale {
+    #[default]
+    En,
+    PtBr,
+}
+
+impl Locale {
+    pub fn tag(&self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::PtBr => "pt-BR",
+        }
+    }
+
+    /// Any `pt` variant is served pt-BR, the only Portuguese catalogue.
+    fn from_tag(tag: &str) -> Option<Self> {
+        let language = tag.split('-').next().unwrap_or_default();
+
+        if language.eq_ignore_ascii_case("pt") {
+            Some(Locale::PtBr)
+        } else if language.eq_ignore_ascii_case("en") {
+            Some(Locale::En)
+        } else {
+            None
+        }
+    }
+
+    /// Picks the supported locale with the highest `q` in `Accept-Language`,
+    /// falling back to English.
+    pub fn negotiate(headers: &HeaderMap) -> Self {
+        let mut ranges: Vec<(f32, &str)> = headers
+            .get_all(ACCEPT_LANGUAGE)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .map(|range| {
+                let mut params = range.split(';').map(str::trim);
+                let tag = params.next().unwrap_or_default();
+                let quality = params
+                    .filter_map(|param| param.split_once('='))
+                    .find(|(name, _)| name.trim().eq_ignore_ascii_case("q"))
+                    .and_then(|(_, value)| value.trim().parse::<f32>().ok())
+                    .unwrap_or(1.0);
+
+                (quality, tag)
+            })
+            .filter(|(quality, _)| *quality > 0.0)
+            .collect();
+
+        ranges.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+        ranges
+            .into_iter()
+            .find_map(|(_, tag)| Self::from_tag(tag))
+            .unwrap_or_default()
+    }
+
+    fn template(&self, code: ErrorCode) -> &'static str {
+        match self {
+            Locale::En => en::message(code),
+            Locale::PtBr => pt_br::message(code),
+        }
+    }
+
+    /// Message for `code`, with `{name}` placeholders filled from `params`.
+    /// Returns `None` when a placeholder has no matching param.
+    pub fn message(&self, code: ErrorCode, params: &BTreeMap<String, Value>) -> Option<String> {
+        let mut message = self.template(code).to_string();
+
+        for (name, value) in params {
+            let value = match value {
+                Value::String(value) => value.clone(),
+                other => other.to_string(),
+            };
+            message = message.replace(&format!("{{{}}}", name), &value);
+        }
+
+        (!message.contains('{')).then_some(message)
+    }
+
+    pub fn localize(&self, error: &mut AppErrorResponse) {
+        for violation in &mut error.violations {
+            if let Some(message) = self.message(violation.code, &violation.params) {
+                violation.message = message;
+            }
+        }
+
+        // Um único campo inválido vira o código principal; seus limites
+        // preenchem a mensagem principal
+        let params = error
+            .violations
+            .iter()
+            .find(|violation| violation.code == error.code)
+            .map(|violation| violation.params.clone())
+            .unwrap_or_default();
+
+        if let Some(message) = self.message(error.code, &params) {
+            error.message = message;
+        }
+    }
+}
+
+/// Rewrites error messages in the locale negotiated through
+/// `Accept-Language`. Must run inside the problem+json layer so both
+/// representations see the translated error.
+pub async fn localize_errors_middleware(request: Request, next: Next) -> Response {
+    let locale = Locale::negotiate(request.headers());
+
+    let response = next.run(request).await;
+
+    let Some(mut error) = response.extensions().get::<AppErrorResponse>().cloned() else {
+        return response;
+    };
+
+    locale.localize(&mut error);
+
+    let Ok(body) = serde_json::to_vec(&error) else {
+        return response;
+    };
+
+    let (mut parts, _) = response.into_parts();
+    parts.headers.remove(CONTENT_LENGTH);
+    parts
+        .headers
+        .insert(CONTENT_LANGUAGE, HeaderValue::from_static(locale.tag()));
+    parts.extensions.insert(error);
+
+    Response::from_parts(parts, Body::from(body))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{Router, http::StatusCode, middleware::from_fn, routing::get};
+    use strum::IntoEnumIterator;
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::{
+        application::dtos::update_profile_input::UpdateProfileInput,
+        presentation::api::utils::AppErrorResponse,
+    };
+
+    fn accept_language(value: &'static str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(ACCEPT_LANGUAGE, HeaderValue::from_static(value));
+        headers
+    }
+
+    fn placeholders(template: &str) -> Vec<&str> {
+        let mut names: Vec<&str> = template
+            .split('{')
+            .skip(1)
+            .filter_map(|rest| rest.split_once('}').map(|(name, _)| name))
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn when_accept_language_is_negotiated_should_pick_best_supported_locale() {
+        assert_eq!(Locale::negotiate(&HeaderMap::new()), Locale::En);
+        assert_eq!(Locale::negotiate(&accept_language("pt-BR")), Locale::PtBr);
+        assert_eq!(Locale::negotiate(&accept_language("pt")), Locale::PtBr);
+        assert_eq!(
+            Locale::negotiate(&accept_language("fr-FR, pt-BR;q=0.8, en;q=0.5")),
+            Locale::PtBr
+        );
+        assert_eq!(
+            Locale::negotiate(&accept_language("pt-BR;q=0.4, en-US;q=0.9")),
+            Locale::En
+        );
+        assert_eq!(Locale::negotiate(&accept_language("de, fr")), Locale::En);
+        assert_eq!(Locale::negotiate(&accept_language("pt;q=0")), Locale::En);
+    }
+
+    #[test]
+    fn when_catalogues_are_compared_should_translate_every_code_with_same_params() {
+        for code in ErrorCode::iter() {
+            let en = Locale::En.template(code);
+            let pt_br = Locale::PtBr.template(code);
+
+            assert!(!en.is_empty(), "{} has no en message", code);
+            assert!(!pt_br.is_empty(), "{} has no pt-BR message", code);
+            assert_eq!(placeholders(en), placeholders(pt_br), "{}", code);
+        }
+    }
+
+    #[test]
+    fn when_placeholder_has_no_param_should_not_build_message() {
+        assert_eq!(
+            Locale::PtBr.message(ErrorCode::BioTooShort, &BTreeMap::new()),
+            None
+        );
+        assert_eq!(
+            Locale::PtBr.message(
+                ErrorCode::BioTooShort,
+                &BTreeMap::from([("min".to_string(), Value::from(10))])
+            ),
+            Some("A bio é muito curta (mínimo de 10 caracteres)".to_string())
+        );
+    }
+
+    #[test]
+    fn when_error_has_field_violations_should_localize_each_of_them() {
+        let error = UpdateProfileInput::try_new(
+            "123e4567-e89b-12d3-a456-426614174000".into(),
+            Some("J".into()),
+            None,
+            Some("curta".into()),
+            None,
+        )
+        .unwrap_err();
+        let mut error = AppErrorResponse::from(error);
+
+        Locale::PtBr.localize(&mut error);
+
+        assert_eq!(error.message, "Erro de validação");
+        assert_eq!(
+            error.violations[0].message,
+            "O nome é muito curto (mínimo de 2 caracteres)"
+        );
+        assert_eq!(
+            error.violations[1].message,
+            "A bio é muito curta (mínimo de 10 caracteres)"
+        );
+    }
+
+    #[test]
+    fn when_error_has_single_invalid_field_should_fill_main_message_params() {
+        let error = UpdateProfileInput::try_new(
+            "123e4567-e89b-12d3-a456-426614174000".into(),
+            None,
+            Some("D".into()),
+            None,
+            None,
+        )
+        .unwrap_err();
+        let mut error = AppErrorResponse::from(error);
+
+        Locale::En.localize(&mut error);
+
+        assert_eq!(
+            error.message,
+            "Last name is too short (minimum 2 characters)"
+        );
+    }
+
+    #[tokio::test]
+    async fn when_request_accepts_portuguese_should_return_translated_error() {
+        let app = Router::new()
+            .route(
+                "/profiles/{id}",
+                get(|| async {
+                    Err::<(), _>(AppErrorResponse::new(
+                        StatusCode::NOT_FOUND,
+                        ErrorCode::ProfileNotFound,
+                        "Profile not found",
+                    ))
+                }),
+            )
+            .layer(from_fn(localize_errors_middleware));
+
+        let request = Request::builder()
+            .uri("/profiles/42")
+            .header(ACCEPT_LANGUAGE, "pt-BR,pt;q=0.9,en;q=0.8")
+            .body(Body::empty())
+            .unwrap();
+
+        let response = app.oneshot(request).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert_eq!(response.headers()[CONTENT_LANGUAGE], "pt-BR");
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: Value = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body["message"], "Perfil não encontrado");
+        assert_eq!(body["code"], "PROFILE_NOT_FOUND");
+    }
+}
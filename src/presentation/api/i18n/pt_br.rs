@@ -0,0 +1,106 @@
+use crate::domain::models::error_code::ErrorCode;
+
+pub fn message(code: ErrorCode) -> &'static str {
+    match code {
+        ErrorCode::ProfileAlreadyExists => "Perfil já existe",
+        ErrorCode::ProfileNotFound => "Perfil não encontrado",
+        ErrorCode::ProfileVersionConflict => "Conflito de versão",
+        ErrorCode::ProfileDataCorrupted => "Os dados armazenados do perfil são inválidos",
+        ErrorCode::InternalError => "Erro interno do servidor",
+
+        ErrorCode::IdInvalid => "ID inválido",
+
+        ErrorCode::EmailEmpty => "O email não pode ser vazio",
+        ErrorCode::EmailTooLong => "O email é muito longo (máximo de {max} caracteres)",
+        ErrorCode::EmailInvalid => "Formato de email inválido (ex.: usuario@exemplo.com)",
+
+        ErrorCode::FirstNameEmpty => "O nome não pode ser vazio",
+        ErrorCode::FirstNameTooShort => "O nome é muito curto (mínimo de {min} caracteres)",
+        ErrorCode::FirstNameTooLong => "O nome é muito longo (máximo de {max} caracteres)",
+        ErrorCode::FirstNameInvalidCharacters => "O nome contém caracteres inválidos",
+        ErrorCode::FirstNameInvalidEdgeCharacters => {
+            "O nome não pode começar ou terminar com caractere especial"
+        }
+
+        ErrorCode::LastNameEmpty => "O sobrenome não pode ser vazio",
+        ErrorCode::LastNameTooShort => "O sobrenome é muito curto (mínimo de {min} caracteres)",
+        ErrorCode::LastNameTooLong => "O sobrenome é muito longo (máximo de {max} caracteres)",
+        ErrorCode::LastNameInvalidCharacters => "O sobrenome contém caracteres inválidos",
+        ErrorCode::LastNameInvalidEdgeCharacters => {
+            "O sobrenome não pode começar ou terminar com caractere especial"
+        }
+
+        ErrorCode::BioEmpty => "A bio não pode ser vazia",
+        ErrorCode::BioTooShort => "A bio é muito curta (mínimo de {min} caracteres)",
+        ErrorCode::BioTooLong => "A bio é muito longa (máximo de {max} caracteres)",
+        ErrorCode::BioInvalidCharacters => "A bio contém caracteres inválidos",
+
+        ErrorCode::UsernameEmpty => "O nome de usuário não pode ser vazio",
+        ErrorCode::UsernameTooShort => {
+            "O nome de usuário é muito curto (mínimo de {min} caracteres)"
+        }
+        ErrorCode::UsernameTooLong => {
+            "O nome de usuário é muito longo (máximo de {max} caracteres)"
+        }
+        ErrorCode::UsernameInvalidCharacters => {
+            "O nome de usuário só pode conter letras, números, sublinhados e pontos"
+        }
+        ErrorCode::UsernameInvalidEdgeCharacters => {
+            "O nome de usuário deve começar e terminar com letra ou número"
+        }
+        ErrorCode::UsernameConfusable => {
+            "O nome de usuário contém caracteres que imitam letras ou números"
+        }
+        ErrorCode::UsernameReserved => "O nome de usuário é reservado",
+        ErrorCode::UsernameTaken => "O nome de usuário já está em uso",
+        ErrorCode::UsernameChangeTooSoon => "O nome de usuário foi alterado há pouco tempo",
+
+        ErrorCode::DisplayNameEmpty => "O nome de exibição não pode ser vazio",
+        ErrorCode::DisplayNameTooLong => {
+            "O nome de exibição é muito longo (máximo de {max} caracteres)"
+        }
+        ErrorCode::DisplayNameInvalidCharacters => {
+            "O nome de exibição só pode conter letras, números, espaços, pontos, apóstrofos e hífens"
+        }
+        ErrorCode::DisplayNameInvalidFormat => {
+            "O formato do nome de exibição deve ser given_family, family_given ou initials"
+        }
+
+        ErrorCode::ImageUrlEmpty => "A URL não pode ser vazia",
+        ErrorCode::ImageUrlTooLong => "A URL é muito longa (máximo de {max} caracteres)",
+        ErrorCode::ImageUrlInvalid => "Formato de URL inválido",
+        ErrorCode::ImageUrlInvalidScheme => "A URL deve começar com http:// ou https://",
+        ErrorCode::ImageUrlInvalidExtension => {
+            "A URL deve terminar com uma extensão de imagem válida (.jpg, .jpeg, .png, .gif)"
+        }
+
+        ErrorCode::InvalidJson => "JSON inválido",
+        ErrorCode::ValidationFailed => "Erro de validação",
+        ErrorCode::FieldTooShort => "Deve ter pelo menos {min} caracteres",
+        ErrorCode::FieldTooLong => "Deve ter no máximo {max} caracteres",
+        ErrorCode::FieldInvalidFormat => "Formato inválido",
+        ErrorCode::FieldInvalid => "Valor inválido",
+        ErrorCode::MissingPathParameter => "Parâmetro de rota ausente",
+        ErrorCode::ClientAddressUnavailable => "Endereço do cliente indisponível",
+        ErrorCode::RateLimited => "Muitas requisições",
+        ErrorCode::PayloadTooLarge => "Corpo da requisição muito grande",
+        ErrorCode::ExportFormatUnsupported => "Formato de exportação não suportado",
+
+        ErrorCode::IdempotencyKeyInvalid => "Chave de idempotência inválida",
+        ErrorCode::IdempotencyKeyReused => {
+            "A chave de idempotência já foi usada com outra requisição"
+        }
+        ErrorCode::IdempotencyRequestInProgress => {
+            "Uma requisição com esta chave de idempotência ainda está em processamento"
+        }
+
+        ErrorCode::TokenMissing => "Token ausente",
+        ErrorCode::TokenMalformed => "Formato de token inválido",
+        ErrorCode::TokenExpired => "O token expirou",
+        ErrorCode::TokenNotYetValid => "O token ainda não é válido",
+        ErrorCode::TokenInvalidSignature => "Assinatura do token inválida",
+        ErrorCode::TokenInvalidAudience => "Audiência do token inválida",
+        ErrorCode::TokenInvalid => "Token inválido",
+        ErrorCode::AccessDenied => "Acesso negado",
+    }
+}
@@ -0,0 +1,402 @@
+use std::sync::Arc;
+
+use axum::{
+    body::{Body, Bytes},
+    extract::{FromRequestParts, MatchedPath, Request, State},
+    http::{HeaderName, HeaderValue, Method, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use ring::digest::{SHA256, digest};
+
+use crate::{
+    domain::{
+        models::error_code::ErrorCode,
+        repositories::{
+            idempotency_repo::{
+                IdempotencyClaim, IdempotencyRecord, IdempotencyRepository, IdempotentResponse,
+            },
+            profile_repo::ProfileRepository,
+        },
+    },
+    presentation::api::{
+        security::Claims, service::AppState, settings::IdempotencySettings,
+        utils::AppErrorResponse, versioning::unversioned_path,
+    },
+};
+
+pub const IDEMPOTENCY_KEY: &str = "idempotency-key";
+pub const IDEMPOTENT_REPLAYED: &str = "idempotent-replayed";
+
+const MAX_KEY_LENGTH: usize = 255;
+/// Same limit axum applies to JSON bodies.
+const MAX_BODY_BYTES: usize = 2 * 1024 * 1024;
+/// Routes that ignore the key. The bulk endpoints stream NDJSON of any size
+/// both ways, which the stored copy would have to buffer, and the batch read
+/// changes nothing.
+const EXCLUDED_ROUTES: [&str; 3] = [
+    "/profiles/bulk-create",
+    "/profiles/bulk-update",
+    "/profiles/batch-get",
+];
+
+pub struct IdempotencyState<R: ProfileRepository> {
+    pub app_state: AppState<R>,
+    pub repository: Arc<dyn IdempotencyRepository>,
+    pub settings: IdempotencySettings,
+}
+
+impl<R: ProfileRepository> Clone for IdempotencyState<R> {
+    fn clone(&self) -> Self {
+        Self {
+            app_state: self.app_state.clone(),
+            repository: Arc::clone(&self.repository),
+            settings: self.settings.clone(),
+        }
+    }
+}
+
+/// Identifies the request a key was first used with. The version prefix is
+/// ignored so a retry through `/v1` matches a first attempt on the alias.
+pub fn fingerprint(method: &Method, path: &str, body: &[u8]) -> String {
+    let mut input = format!("{} {}\n", method, unversioned_path(path)).into_bytes();
+    input.extend_from_slice(body);
+
+    digest(&SHA256, &input)
+        .as_ref()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+fn is_excluded(request: &Request) -> bool {
+    let path = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map_or(request.uri().path(), MatchedPath::as_str);
+
+    EXCLUDED_ROUTES.contains(&unversioned_path(path))
+}
+
+fn replay(response: IdempotentResponse) -> Response {
+    let mut builder = Response::builder().status(response.status);
+
+    for (name, value) in &response.headers {
+        builder = builder.header(name, value);
+    }
+
+    builder
+        .header(IDEMPOTENT_REPLAYED, "true")
+        .body(Body::from(response.body))
+        .unwrap_or_else(|_| StatusCode::INTERNAL_SERVER_ERROR.into_response())
+}
+
+fn error(status: StatusCode, code: ErrorCode, message: &str) -> Response {
+    AppErrorResponse::new(status, code, message).into_response()
+}
+
+/// Honours `Idempotency-Key` on POST, PUT and PATCH. The first request with a
+/// key claims it; a successful response is stored and replayed to retries
+/// with the same body, while failures free the key so the client can try
+/// again. Keys are scoped to the token subject, so clients cannot collide.
+pub async fn idempotency_middleware<R: ProfileRepository>(
+    State(state): State<IdempotencyState<R>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if !matches!(
+        *request.method(),
+        Method::POST | Method::PUT | Method::PATCH
+    ) || is_excluded(&request)
+    {
+        return next.run(request).await;
+    }
+
+    let Some(key) = request.headers().get(IDEMPOTENCY_KEY).cloned() else {
+        return next.run(request).await;
+    };
+
+    let key = match key.to_str() {
+        Ok(key) if !key.trim().is_empty() && key.len() <= MAX_KEY_LENGTH => key.trim().to_string(),
+        _ => {
+            return error(
+                StatusCode::BAD_REQUEST,
+                ErrorCode::IdempotencyKeyInvalid,
+                "Invalid idempotency key",
+            );
+        }
+    };
+
+    let (mut parts, body) = request.into_parts();
+
+    // Sem token válido o handler responde 401; não há o que deduplicar
+    let Ok(claims) = Claims::from_request_parts(&mut parts, &state.app_state).await else {
+        return next.run(Request::from_parts(parts, body)).await;
+    };
+
+    let Ok(body) = axum::body::to_bytes(body, MAX_BODY_BYTES).await else {
+        return error(
+            StatusCode::PAYLOAD_TOO_LARGE,
+            ErrorCode::PayloadTooLarge,
+            "Request body is too large",
+        );
+    };
+
+    let key = format!("{}:{}", claims.sub, key);
+    let fingerprint = fingerprint(&parts.method, parts.uri.path(), &body);
+    let request = Request::from_parts(parts, Body::from(body));
+
+    let claim = match state
+        .repository
+        .claim(&key, &fingerprint, state.settings.lock)
+        .await
+    {
+        Ok(claim) => claim,
+        Err(e) => {
+            // Sem armazenamento disponível, atende sem deduplicar
+            tracing::warn!("Idempotency claim failed: {}", e);
+            return next.run(request).await;
+        }
+    };
+
+    match claim {
+        IdempotencyClaim::Existing(record) if record.fingerprint() != fingerprint => error(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            ErrorCode::IdempotencyKeyReused,
+            "Idempotency key was already used with a different request",
+        ),
+        IdempotencyClaim::Existing(IdempotencyRecord::InProgress { .. }) => error(
+            StatusCode::CONFLICT,
+            ErrorCode::IdempotencyRequestInProgress,
+            "A request with this idempotency key is still being processed",
+        ),
+        IdempotencyClaim::Existing(IdempotencyRecord::Completed { response, .. }) => {
+            replay(response)
+        }
+        IdempotencyClaim::Acquired => {
+            let response = next.run(request).await;
+            record(&state, &key, &fingerprint, response).await
+        }
+    }
+}
+
+async fn record<R: ProfileRepository>(
+    state: &IdempotencyState<R>,
+    key: &str,
+    fingerprint: &str,
+    response: Response,
+) -> Response {
+    if !response.status().is_success() {
+        if let Err(e) = state.repository.release(key).await {
+            tracing::warn!("Idempotency release failed: {}", e);
+        }
+        return response;
+    }
+
+    let (parts, body) = response.into_parts();
+
+    let body: Bytes = match axum::body::to_bytes(body, usize::MAX).await {
+        Ok(body) => body,
+        Err(e) => {
+            tracing::warn!("Idempotency response could not be read: {}", e);
+            let _ = state.repository.release(key).await;
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+
+    let stored = IdempotentResponse {
+        status: parts.status.as_u16(),
+        headers: parts
+            .headers
+            .iter()
+            .filter_map(|(name, value): (&HeaderName, &HeaderValue)| {
+                Some((name.to_string(), value.to_str().ok()?.to_string()))
+            })
+            .collect(),
+        body: body.to_vec(),
+    };
+
+    if let Err(e) = state
+        .repository
+        .complete(key, fingerprint, stored, state.settings.ttl)
+        .await
+    {
+        tracing::warn!("Idempotency response could not be stored: {}", e);
+    }
+
+    Response::from_parts(parts, Body::from(body))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    use axum::{
+        Router, http::header::AUTHORIZATION, middleware::from_fn_with_state, routing::post,
+    };
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::{
+        domain::repositories::profile_repo::MockProfileRepository,
+        infrastructure::repositories::memory_idempotency_repo::MemoryIdempotencyRepository,
+        presentation::api::handlers::tests::{
+            SharedMockRepository, create_test_token_for, get_test_decoding_key,
+        },
+        presentation::api::security::Scope,
+    };
+
+    fn app(calls: Arc<AtomicU64>, status: StatusCode) -> Router {
+        let app_state = AppState::new(
+            Arc::new(SharedMockRepository(Arc::new(MockProfileRepository::new()))),
+            Arc::new(get_test_decoding_key()),
+        );
+
+        let state = IdempotencyState {
+            app_state,
+            repository: Arc::new(MemoryIdempotencyRepository::new()),
+            settings: IdempotencySettings::default(),
+        };
+
+        Router::new()
+            .route(
+                "/profiles",
+                post(move |body: String| {
+                    let version = calls.fetch_add(1, Ordering::SeqCst) + 1;
+                    async move {
+                        (
+                            status,
+                            format!("{{\"version\":{},\"body\":{}}}", version, body),
+                        )
+                    }
+                }),
+            )
+            .route(
+                "/profiles/bulk-create",
+                post(move |body: String| async move { (status, body) }),
+            )
+            .layer(from_fn_with_state(state, idempotency_middleware))
+    }
+
+    fn request(key: &str, body: Value) -> Request {
+        request_as("iam-service", key, body)
+    }
+
+    fn request_as(sub: &str, key: &str, body: Value) -> Request {
+        let token = create_test_token_for(sub, &[Scope::ProfileCreate]);
+
+        Request::builder()
+            .method(Method::POST)
+            .uri("/profiles")
+            .header(AUTHORIZATION, format!("Bearer {}", token))
+            .header(IDEMPOTENCY_KEY, key)
+            .body(Body::from(body.to_string()))
+            .unwrap()
+    }
+
+    async fn send(app: &Router, request: Request) -> (StatusCode, Option<HeaderValue>, Value) {
+        let response = app.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let replayed = response.headers().get(IDEMPOTENT_REPLAYED).cloned();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+
+        (status, replayed, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn when_request_is_retried_with_same_key_should_replay_first_response() {
+        let calls = Arc::new(AtomicU64::new(0));
+        let app = app(Arc::clone(&calls), StatusCode::CREATED);
+
+        let first = send(&app, request("abc", json!({"id": 1}))).await;
+        let retry = send(&app, request("abc", json!({"id": 1}))).await;
+
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(first.0, StatusCode::CREATED);
+        assert_eq!(first.1, None);
+        assert_eq!(retry.0, StatusCode::CREATED);
+        assert_eq!(retry.1.unwrap(), "true");
+        assert_eq!(retry.2, first.2);
+    }
+
+    #[tokio::test]
+    async fn when_key_is_reused_with_different_body_should_reject_it() {
+        let calls = Arc::new(AtomicU64::new(0));
+        let app = app(Arc::clone(&calls), StatusCode::CREATED);
+
+        send(&app, request("abc", json!({"id": 1}))).await;
+        let (status, _, body) = send(&app, request("abc", json!({"id": 2}))).await;
+
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body["code"], "IDEMPOTENCY_KEY_REUSED");
+    }
+
+    #[tokio::test]
+    async fn when_another_subject_uses_same_key_should_not_share_it() {
+        let calls = Arc::new(AtomicU64::new(0));
+        let app = app(Arc::clone(&calls), StatusCode::CREATED);
+
+        send(&app, request_as("alice", "abc", json!({"id": 1}))).await;
+        let (status, replayed, _) = send(&app, request_as("bob", "abc", json!({"id": 2}))).await;
+
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(replayed, None);
+    }
+
+    #[tokio::test]
+    async fn when_first_request_fails_should_let_retry_run_again() {
+        let calls = Arc::new(AtomicU64::new(0));
+        let app = app(Arc::clone(&calls), StatusCode::CONFLICT);
+
+        send(&app, request("abc", json!({"id": 1}))).await;
+        let (status, replayed, _) = send(&app, request("abc", json!({"id": 1}))).await;
+
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(replayed, None);
+    }
+
+    #[tokio::test]
+    async fn when_key_is_empty_should_return_bad_request() {
+        let app = app(Arc::new(AtomicU64::new(0)), StatusCode::CREATED);
+
+        let (status, _, body) = send(&app, request(" ", json!({}))).await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "IDEMPOTENCY_KEY_INVALID");
+    }
+
+    #[test]
+    fn when_paths_differ_only_by_version_should_share_fingerprint() {
+        assert_eq!(
+            fingerprint(&Method::POST, "/v1/profiles", b"{}"),
+            fingerprint(&Method::POST, "/profiles", b"{}")
+        );
+        assert_ne!(
+            fingerprint(&Method::POST, "/profiles", b"{}"),
+            fingerprint(&Method::PUT, "/profiles", b"{}")
+        );
+    }
+
+    #[tokio::test]
+    async fn when_route_is_excluded_should_ignore_the_key() {
+        let app = app(Arc::new(AtomicU64::new(0)), StatusCode::OK);
+
+        let request = || {
+            let mut request = request("abc", json!({"id": 1}));
+            *request.uri_mut() = "/profiles/bulk-create".parse().unwrap();
+            request
+        };
+
+        let first = send(&app, request()).await;
+        let retry = send(&app, request()).await;
+
+        assert_eq!(first.1, None);
+        assert_eq!(retry.1, None);
+    }
+}
@@ -1,5 +1,17 @@
+mod client_ip;
+mod conditional;
+mod cors;
 mod handlers;
+mod i18n;
+mod idempotency;
+mod ndjson;
+mod openapi;
+mod problem;
+mod rate_limit;
 mod security;
+mod tls;
 mod utils;
+mod versioning;
 
 pub mod service;
+pub mod settings;
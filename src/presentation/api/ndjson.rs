@@ -0,0 +1,205 @@
+use std::convert::Infallible;
+
+use axum::{
+    body::{Body, BodyDataStream, Bytes},
+    http::{HeaderValue, header::CONTENT_TYPE},
+    response::{IntoResponse, Response},
+};
+use futures_util::{StreamExt, stream};
+use serde::Serialize;
+use tokio::sync::mpsc;
+
+pub const NDJSON: &str = "application/x-ndjson";
+
+/// Longest line accepted; a single profile operation is far smaller.
+pub const MAX_LINE_BYTES: usize = 64 * 1024;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Line {
+    Data(usize, Vec<u8>),
+    /// The line went over `MAX_LINE_BYTES` and was skipped.
+    TooLong(usize),
+}
+
+/// Splits a request body into lines as it arrives, so a stream of millions
+/// of operations is never held in memory. Lines are numbered from 1, blank
+/// lines included, so numbers match the client's file.
+pub struct NdjsonLines {
+    stream: BodyDataStream,
+    buffer: Vec<u8>,
+    number: usize,
+    skipping: bool,
+    done: bool,
+}
+
+impl NdjsonLines {
+    pub fn new(body: Body) -> Self {
+        Self {
+            stream: body.into_data_stream(),
+            buffer: Vec::new(),
+            number: 0,
+            skipping: false,
+            done: false,
+        }
+    }
+
+    /// Next non-blank line, or `None` once the body is exhausted.
+    pub async fn next(&mut self) -> Option<Result<Line, axum::Error>> {
+        loop {
+            if let Some(end) = self.buffer.iter().position(|byte| *byte == b'\n') {
+                let line: Vec<u8> = self.buffer.drain(..=end).collect();
+
+                // Resto de uma linha longa demais, já reportada
+                if std::mem::take(&mut self.skipping) {
+                    continue;
+                }
+
+                self.number += 1;
+
+                if let Some(line) = self.line(line) {
+                    return Some(Ok(line));
+                }
+                continue;
+            }
+
+            if self.done {
+                if self.skipping || self.buffer.is_empty() {
+                    return None;
+                }
+
+                self.number += 1;
+                let line = std::mem::take(&mut self.buffer);
+
+                return self.line(line).map(Ok);
+            }
+
+            if !self.skipping && self.buffer.len() > MAX_LINE_BYTES {
+                self.buffer.clear();
+                self.skipping = true;
+                self.number += 1;
+
+                return Some(Ok(Line::TooLong(self.number)));
+            }
+
+            match self.stream.next().await {
+                Some(Ok(chunk)) if self.skipping => match chunk.iter().position(|b| *b == b'\n') {
+                    Some(end) => self.buffer.extend_from_slice(&chunk[end..]),
+                    None => continue,
+                },
+                Some(Ok(chunk)) => self.buffer.extend_from_slice(&chunk),
+                Some(Err(e)) => return Some(Err(e)),
+                None => self.done = true,
+            }
+        }
+    }
+
+    fn line(&self, mut line: Vec<u8>) -> Option<Line> {
+        while line.last().is_some_and(u8::is_ascii_whitespace) {
+            line.pop();
+        }
+
+        if line.len() > MAX_LINE_BYTES {
+            return Some(Line::TooLong(self.number));
+        }
+
+        (!line.iter().all(u8::is_ascii_whitespace)).then_some(Line::Data(self.number, line))
+    }
+}
+
+/// One JSON document followed by a newline.
+pub fn to_line<T: Serialize>(value: &T) -> Bytes {
+    let mut line = serde_json::to_vec(value).unwrap_or_default();
+    line.push(b'\n');
+
+    Bytes::from(line)
+}
+
+/// Streams every line sent through `lines` to the client as it is produced.
+pub fn ndjson_response(lines: mpsc::Receiver<Bytes>) -> Response {
+    let body = stream::unfold(lines, |mut lines| async move {
+        lines
+            .recv()
+            .await
+            .map(|line| (Ok::<_, Infallible>(line), lines))
+    });
+
+    let mut response = Body::from_stream(body).into_response();
+    response
+        .headers_mut()
+        .insert(CONTENT_TYPE, HeaderValue::from_static(NDJSON));
+
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn body(chunks: Vec<&'static [u8]>) -> Body {
+        Body::from_stream(stream::iter(chunks.into_iter().map(Ok::<_, Infallible>)))
+    }
+
+    async fn collect(body: Body) -> Vec<Line> {
+        let mut lines = NdjsonLines::new(body);
+        let mut collected = Vec::new();
+
+        while let Some(line) = lines.next().await {
+            collected.push(line.unwrap());
+        }
+
+        collected
+    }
+
+    #[tokio::test]
+    async fn when_lines_span_chunks_should_reassemble_them() {
+        let lines = collect(body(vec![b"{\"a\":", b"1}\r\n\n  \n{\"b\"", b":2}"])).await;
+
+        assert_eq!(
+            lines,
+            vec![
+                Line::Data(1, b"{\"a\":1}".to_vec()),
+                Line::Data(4, b"{\"b\":2}".to_vec()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn when_line_is_too_long_should_skip_it_and_keep_going() {
+        let long: &'static [u8] = Box::leak(vec![b'x'; MAX_LINE_BYTES + 10].into_boxed_slice());
+
+        let lines = collect(body(vec![b"{}\n", long, long, b"\n{}\n"])).await;
+
+        assert_eq!(
+            lines,
+            vec![
+                Line::Data(1, b"{}".to_vec()),
+                Line::TooLong(2),
+                Line::Data(3, b"{}".to_vec()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn when_response_is_streamed_should_send_each_line() {
+        let (tx, rx) = mpsc::channel(2);
+
+        tokio::spawn(async move {
+            tx.send(to_line(&serde_json::json!({"line": 1})))
+                .await
+                .unwrap();
+            tx.send(to_line(&serde_json::json!({"line": 2})))
+                .await
+                .unwrap();
+        });
+
+        let response = ndjson_response(rx);
+
+        assert_eq!(response.headers()[CONTENT_TYPE], NDJSON);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+
+        assert_eq!(&body[..], b"{\"line\":1}\n{\"line\":2}\n");
+    }
+}
@@ -0,0 +1,261 @@
+use std::sync::Arc;
+
+use axum::{Json, Router, response::IntoResponse, routing::get};
+use utoipa::{
+    Modify, OpenApi,
+    openapi::{
+        OpenApi as OpenApiDocument,
+        security::{HttpAuthScheme, HttpBuilder, SecurityScheme},
+    },
+};
+use utoipa_swagger_ui::{Config, SwaggerUi};
+
+use crate::{
+    domain::models::error_code::ErrorCode,
+    presentation::api::utils::{AppErrorResponse, FieldViolation},
+};
+
+pub const OPENAPI_PATH: &str = "/openapi.json";
+pub const SWAGGER_UI_PATH: &str = "/docs";
+
+/// Root of the generated document. Operations are collected from the
+/// `#[utoipa::path]` annotations as the routes are registered in
+/// `service::api_router`, so the router and the document share one source.
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "Profile Service",
+        description = "Gerenciamento de perfis de usuário"
+    ),
+    components(schemas(AppErrorResponse, FieldViolation, ErrorCode)),
+    modifiers(&BearerSecurity),
+    tags((name = "profiles", description = "Perfis de usuário"))
+)]
+pub struct ApiDoc;
+
+struct BearerSecurity;
+
+impl Modify for BearerSecurity {
+    fn modify(&self, openapi: &mut OpenApiDocument) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+
+        components.add_security_scheme(
+            "bearer_auth",
+            SecurityScheme::Http(
+                HttpBuilder::new()
+                    .scheme(HttpAuthScheme::Bearer)
+                    .bearer_format("JWT")
+                    .build(),
+            ),
+        );
+    }
+}
+
+/// Serves the document at `/openapi.json` and, when enabled, a Swagger UI
+/// at `/docs/`. The UI assets are compiled into the binary, so the page
+/// loads nothing from third-party hosts.
+pub fn docs_router<S>(openapi: OpenApiDocument, swagger_ui: bool) -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    let openapi = Arc::new(openapi);
+
+    let router = Router::new().route(
+        OPENAPI_PATH,
+        get(move || {
+            let openapi = Arc::clone(&openapi);
+            async move { Json(openapi.as_ref().clone()).into_response() }
+        }),
+    );
+
+    if swagger_ui {
+        router.merge(SwaggerUi::new(SWAGGER_UI_PATH).config(Config::from(OPENAPI_PATH)))
+    } else {
+        router
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{
+        body::Body,
+        http::{Method, Request, StatusCode},
+    };
+    use tower::ServiceExt;
+    use utoipa::openapi::path::PathItem;
+
+    use super::*;
+    use crate::{
+        domain::repositories::profile_repo::MockProfileRepository,
+        presentation::api::{
+            handlers::tests::{SharedMockRepository, get_test_decoding_key},
+            service::{AppState, api_router},
+            versioning::Deprecation,
+        },
+    };
+
+    const ALL_METHODS: [Method; 5] = [
+        Method::GET,
+        Method::POST,
+        Method::PUT,
+        Method::PATCH,
+        Method::DELETE,
+    ];
+
+    fn legacy_routes() -> Deprecation {
+        Deprecation {
+            since: chrono::Utc::now(),
+            sunset: None,
+            successor: Some("/v1".into()),
+        }
+    }
+
+    fn documented_methods(item: &PathItem) -> Vec<Method> {
+        [
+            (Method::GET, &item.get),
+            (Method::POST, &item.post),
+            (Method::PUT, &item.put),
+            (Method::PATCH, &item.patch),
+            (Method::DELETE, &item.delete),
+        ]
+        .into_iter()
+        .filter(|(_, operation)| operation.is_some())
+        .map(|(method, _)| method)
+        .collect()
+    }
+
+    fn app() -> (Router, OpenApiDocument) {
+        let (router, openapi) = api_router::<SharedMockRepository>(legacy_routes());
+        let state = AppState::new(
+            Arc::new(SharedMockRepository(Arc::new(MockProfileRepository::new()))),
+            Arc::new(get_test_decoding_key()),
+        );
+
+        (router.with_state(state), openapi)
+    }
+
+    async fn status(app: &Router, method: Method, path: &str) -> StatusCode {
+        let uri = path.replace("{id}", "123e4567-e89b-12d3-a456-426614174000");
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .body(Body::empty())
+            .unwrap();
+
+        app.clone().oneshot(request).await.unwrap().status()
+    }
+
+    #[tokio::test]
+    async fn when_spec_is_compared_with_router_should_document_exactly_the_served_methods() {
+        let (app, openapi) = app();
+
+        assert!(!openapi.paths.paths.is_empty());
+
+        for (path, item) in &openapi.paths.paths {
+            let documented = documented_methods(item);
+
+            for method in ALL_METHODS {
+                let status = status(&app, method.clone(), path).await;
+
+                if documented.contains(&method) {
+                    assert_ne!(
+                        status,
+                        StatusCode::NOT_FOUND,
+                        "{} {} not routed",
+                        method,
+                        path
+                    );
+                    assert_ne!(
+                        status,
+                        StatusCode::METHOD_NOT_ALLOWED,
+                        "{} {} not routed",
+                        method,
+                        path
+                    );
+                } else {
+                    assert_eq!(
+                        status,
+                        StatusCode::METHOD_NOT_ALLOWED,
+                        "{} {} is routed but not documented",
+                        method,
+                        path
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn when_spec_is_generated_should_describe_contract_types() {
+        let (_, openapi) = api_router::<SharedMockRepository>(legacy_routes());
+        let json = serde_json::to_value(&openapi).unwrap();
+
+        assert_eq!(json["openapi"], "3.1.0");
+
+        for schema in [
+            "CreateProfileRequest",
+            "UpdateProfileRequest",
+            "ProfileResponse",
+            "AppErrorResponse",
+            "ErrorCode",
+        ] {
+            assert!(
+                json["components"]["schemas"].get(schema).is_some(),
+                "missing schema {}",
+                schema
+            );
+        }
+
+        for path in ["/profiles", "/profiles/{id}", "/profiles/{id}/privacy"] {
+            let versioned = format!("/v1{}", path);
+
+            assert!(json["paths"].get(path).is_some(), "missing path {}", path);
+            assert!(
+                json["paths"].get(&versioned).is_some(),
+                "missing path {}",
+                versioned
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn when_swagger_ui_is_disabled_should_only_serve_document() {
+        let (_, openapi) = api_router::<SharedMockRepository>(legacy_routes());
+        let router: Router = docs_router(openapi, false);
+
+        assert_eq!(
+            status(&router, Method::GET, OPENAPI_PATH).await,
+            StatusCode::OK
+        );
+        assert_eq!(
+            status(&router, Method::GET, SWAGGER_UI_PATH).await,
+            StatusCode::NOT_FOUND
+        );
+    }
+
+    #[tokio::test]
+    async fn when_swagger_ui_is_enabled_should_serve_page_and_its_assets() {
+        let (_, openapi) = api_router::<SharedMockRepository>(legacy_routes());
+        let router: Router = docs_router(openapi, true);
+
+        assert_eq!(
+            status(&router, Method::GET, SWAGGER_UI_PATH).await,
+            StatusCode::SEE_OTHER
+        );
+
+        for path in [
+            "/docs/",
+            "/docs/swagger-ui.css",
+            "/docs/swagger-initializer.js",
+        ] {
+            assert_eq!(
+                status(&router, Method::GET, path).await,
+                StatusCode::OK,
+                "{}",
+                path
+            );
+        }
+    }
+}
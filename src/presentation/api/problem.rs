@@ -0,0 +1,263 @@
+use axum::{
+    body::Body,
+    extract::Request,
+    http::{
+        HeaderMap, HeaderValue,
+        header::{ACCEPT, CONTENT_LENGTH, CONTENT_TYPE},
+    },
+    middleware::Next,
+    response::Response,
+};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::{
+    domain::models::error_code::ErrorCode,
+    presentation::api::utils::{AppErrorResponse, FieldViolation},
+};
+
+pub const PROBLEM_JSON: &str = "application/problem+json";
+const APPLICATION_JSON: &str = "application/json";
+const PROBLEM_TYPE_PREFIX: &str = "urn:profile-service:problem:";
+
+/// RFC 9457 representation of an [`AppErrorResponse`]. `code` and `timestamp`
+/// are kept as extension members so clients can switch formats without
+/// losing information.
+#[derive(Debug, Serialize)]
+pub struct ProblemDetails {
+    #[serde(rename = "type")]
+    pub problem_type: String,
+    pub title: String,
+    pub status: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    pub instance: String,
+    pub code: ErrorCode,
+    pub timestamp: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<FieldViolation>,
+}
+
+impl ProblemDetails {
+    pub fn from_error(error: AppErrorResponse, instance: &str) -> Self {
+        Self {
+            problem_type: format!("{}{}", PROBLEM_TYPE_PREFIX, error.code),
+            title: error.message,
+            status: error.status_code,
+            detail: error.details,
+            instance: instance.to_string(),
+            code: error.code,
+            timestamp: error.timestamp,
+            errors: error.violations,
+        }
+    }
+}
+
+/// Whether the client asked for problem+json at least as strongly as for
+/// plain JSON. Wildcards keep the default shape, so existing clients sending
+/// `*/*` see no change.
+pub fn prefers_problem_json(headers: &HeaderMap) -> bool {
+    let mut problem_quality = 0.0;
+    let mut json_quality = 0.0;
+
+    for range in headers
+        .get_all(ACCEPT)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+    {
+        let mut params = range.split(';').map(str::trim);
+        let media_type = params.next().unwrap_or_default().to_ascii_lowercase();
+
+        let quality = params
+            .filter_map(|param| param.split_once('='))
+            .find(|(name, _)| name.trim().eq_ignore_ascii_case("q"))
+            .and_then(|(_, value)| value.trim().parse::<f32>().ok())
+            .unwrap_or(1.0);
+
+        match media_type.as_str() {
+            PROBLEM_JSON => problem_quality = quality,
+            APPLICATION_JSON => json_quality = quality,
+            _ => {}
+        }
+    }
+
+    problem_quality > 0.0 && problem_quality >= json_quality
+}
+
+/// Re-renders error responses as problem+json when the client negotiates it.
+/// Errors are recognised by the [`AppErrorResponse`] every error response
+/// carries in its extensions; other responses pass through untouched.
+pub async fn problem_json_middleware(request: Request, next: Next) -> Response {
+    let wants_problem = prefers_problem_json(request.headers());
+    let instance = request.uri().path().to_string();
+
+    let response = next.run(request).await;
+
+    if !wants_problem {
+        return response;
+    }
+
+    let Some(error) = response.extensions().get::<AppErrorResponse>().cloned() else {
+        return response;
+    };
+
+    let problem = ProblemDetails::from_error(error, &instance);
+
+    let Ok(body) = serde_json::to_vec(&problem) else {
+        return response;
+    };
+
+    let (mut parts, _) = response.into_parts();
+    parts
+        .headers
+        .insert(CONTENT_TYPE, HeaderValue::from_static(PROBLEM_JSON));
+    parts.headers.remove(CONTENT_LENGTH);
+
+    Response::from_parts(parts, Body::from(body))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        Router,
+        http::StatusCode,
+        middleware::from_fn,
+        routing::{get, post},
+    };
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::presentation::api::{
+        handlers::requests::CreateProfileRequest, utils::ValidatedJson,
+    };
+
+    fn accept(value: &'static str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(ACCEPT, HeaderValue::from_static(value));
+        headers
+    }
+
+    #[test]
+    fn when_accept_lists_problem_json_should_prefer_it() {
+        assert!(prefers_problem_json(&accept(PROBLEM_JSON)));
+        assert!(prefers_problem_json(&accept(
+            "application/problem+json, application/json"
+        )));
+        assert!(prefers_problem_json(&accept(
+            "application/json;q=0.5, application/problem+json"
+        )));
+    }
+
+    #[test]
+    fn when_accept_prefers_plain_json_or_wildcard_should_keep_default() {
+        assert!(!prefers_problem_json(&HeaderMap::new()));
+        assert!(!prefers_problem_json(&accept("*/*")));
+        assert!(!prefers_problem_json(&accept("application/json")));
+        assert!(!prefers_problem_json(&accept(
+            "application/problem+json;q=0.2, application/json"
+        )));
+        assert!(!prefers_problem_json(&accept(
+            "application/problem+json;q=0"
+        )));
+    }
+
+    fn app() -> Router {
+        Router::new()
+            .route(
+                "/profiles/{id}",
+                get(|| async {
+                    Err::<(), _>(
+                        AppErrorResponse::new(
+                            StatusCode::NOT_FOUND,
+                            ErrorCode::ProfileNotFound,
+                            "Profile not found",
+                        )
+                        .with_details("Profile not found with id: 42"),
+                    )
+                }),
+            )
+            .route(
+                "/profiles",
+                post(|_: ValidatedJson<CreateProfileRequest>| async { StatusCode::CREATED }),
+            )
+            .layer(from_fn(problem_json_middleware))
+    }
+
+    async fn send(request: Request) -> (StatusCode, Option<HeaderValue>, Value) {
+        let response = app().oneshot(request).await.unwrap();
+        let status = response.status();
+        let content_type = response.headers().get(CONTENT_TYPE).cloned();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+
+        (status, content_type, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn when_problem_json_is_negotiated_should_render_rfc_9457_body() {
+        let request = Request::builder()
+            .uri("/profiles/42")
+            .header(ACCEPT, PROBLEM_JSON)
+            .body(Body::empty())
+            .unwrap();
+
+        let (status, content_type, body) = send(request).await;
+
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(content_type.unwrap(), PROBLEM_JSON);
+        assert_eq!(
+            body["type"],
+            "urn:profile-service:problem:PROFILE_NOT_FOUND"
+        );
+        assert_eq!(body["title"], "Profile not found");
+        assert_eq!(body["status"], 404);
+        assert_eq!(body["detail"], "Profile not found with id: 42");
+        assert_eq!(body["instance"], "/profiles/42");
+        assert_eq!(body["code"], "PROFILE_NOT_FOUND");
+    }
+
+    #[tokio::test]
+    async fn when_problem_json_is_not_negotiated_should_keep_default_shape() {
+        let request = Request::builder()
+            .uri("/profiles/42")
+            .body(Body::empty())
+            .unwrap();
+
+        let (_, content_type, body) = send(request).await;
+
+        assert_eq!(content_type.unwrap(), APPLICATION_JSON);
+        assert_eq!(body["message"], "Profile not found");
+        assert_eq!(body["statusCode"], 404);
+        assert!(body.get("type").is_none());
+    }
+
+    #[tokio::test]
+    async fn when_validation_fails_should_list_field_violations() {
+        let request = Request::builder()
+            .method("POST")
+            .uri("/profiles")
+            .header(ACCEPT, PROBLEM_JSON)
+            .header(CONTENT_TYPE, APPLICATION_JSON)
+            .body(Body::from(
+                json!({ "id": "1", "email": "not-an-email" }).to_string(),
+            ))
+            .unwrap();
+
+        let (status, _, body) = send(request).await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "VALIDATION_FAILED");
+
+        let fields: Vec<&str> = body["errors"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|violation| violation["field"].as_str().unwrap())
+            .collect();
+        assert!(fields.contains(&"id"));
+        assert!(fields.contains(&"email"));
+    }
+}
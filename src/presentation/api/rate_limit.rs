@@ -0,0 +1,362 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{FromRequestParts, MatchedPath, Request, State},
+    http::{HeaderMap, HeaderValue, StatusCode, header::RETRY_AFTER},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Utc};
+
+use crate::{
+    domain::{
+        models::error_code::ErrorCode,
+        repositories::{
+            profile_repo::ProfileRepository,
+            rate_limit_repo::{RateLimitRepository, RateLimitRepositoryError},
+        },
+    },
+    presentation::api::{
+        client_ip::ClientIp,
+        security::{Claims, Scope},
+        service::AppState,
+        settings::RateLimitSettings,
+        utils::AppErrorResponse,
+        versioning::unversioned_path,
+    },
+};
+
+pub const RATE_LIMIT_LIMIT: &str = "ratelimit-limit";
+pub const RATE_LIMIT_REMAINING: &str = "ratelimit-remaining";
+pub const RATE_LIMIT_RESET: &str = "ratelimit-reset";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitTier {
+    Anonymous,
+    Authenticated,
+    Admin,
+}
+
+/// Who a request is counted against: the token subject (or mTLS client) when
+/// authenticated, the client address otherwise.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Principal {
+    key: String,
+    tier: RateLimitTier,
+}
+
+impl Principal {
+    pub fn from_claims(claims: &Claims) -> Self {
+        let tier = if claims.has_scope(vec![Scope::ProfileAdmin]) {
+            RateLimitTier::Admin
+        } else {
+            RateLimitTier::Authenticated
+        };
+
+        Self {
+            key: format!("sub:{}", claims.sub),
+            tier,
+        }
+    }
+
+    pub fn anonymous(client: &str) -> Self {
+        Self {
+            key: format!("ip:{}", client),
+            tier: RateLimitTier::Anonymous,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RateLimitDecision {
+    pub limit: u64,
+    pub remaining: u64,
+    pub resets_at: DateTime<Utc>,
+    pub allowed: bool,
+}
+
+impl RateLimitDecision {
+    fn reset_after_secs(&self) -> u64 {
+        let millis = (self.resets_at - Utc::now()).num_milliseconds().max(0) as u64;
+        millis.div_ceil(1000)
+    }
+
+    pub fn apply_headers(&self, headers: &mut HeaderMap) {
+        headers.insert(RATE_LIMIT_LIMIT, HeaderValue::from(self.limit));
+        headers.insert(RATE_LIMIT_REMAINING, HeaderValue::from(self.remaining));
+        headers.insert(RATE_LIMIT_RESET, HeaderValue::from(self.reset_after_secs()));
+
+        if !self.allowed {
+            headers.insert(RETRY_AFTER, HeaderValue::from(self.reset_after_secs()));
+        }
+    }
+}
+
+pub struct RateLimiter {
+    repository: Arc<dyn RateLimitRepository>,
+    settings: RateLimitSettings,
+}
+
+impl RateLimiter {
+    pub fn new(repository: Arc<dyn RateLimitRepository>, settings: RateLimitSettings) -> Self {
+        Self {
+            repository,
+            settings,
+        }
+    }
+
+    fn tier_limit(&self, tier: RateLimitTier) -> u64 {
+        match tier {
+            RateLimitTier::Anonymous => self.settings.anonymous_limit,
+            RateLimitTier::Authenticated => self.settings.authenticated_limit,
+            RateLimitTier::Admin => self.settings.admin_limit,
+        }
+    }
+
+    /// Counts the request against the principal's tier quota and, when the
+    /// route has its own limit, against the principal's quota for that route.
+    /// The most restrictive of the two is reported.
+    pub async fn check(
+        &self,
+        principal: &Principal,
+        route: &str,
+    ) -> Result<RateLimitDecision, RateLimitRepositoryError> {
+        let mut buckets = vec![(principal.key.clone(), self.tier_limit(principal.tier))];
+
+        if let Some(limit) = self.settings.route_limits.get(route) {
+            buckets.push((format!("{}:{}", principal.key, route), *limit));
+        }
+
+        let mut decisions = Vec::with_capacity(buckets.len());
+
+        for (key, limit) in buckets {
+            let window = self.repository.hit(&key, self.settings.window).await?;
+
+            decisions.push(RateLimitDecision {
+                limit,
+                remaining: limit.saturating_sub(window.hits),
+                resets_at: window.resets_at,
+                allowed: window.hits <= limit,
+            });
+        }
+
+        Ok(decisions
+            .into_iter()
+            .min_by_key(|decision| (decision.allowed, decision.remaining))
+            .expect("at least the tier bucket is checked"))
+    }
+}
+
+pub struct RateLimitState<R: ProfileRepository> {
+    pub app_state: AppState<R>,
+    pub rate_limiter: Arc<RateLimiter>,
+}
+
+impl<R: ProfileRepository> Clone for RateLimitState<R> {
+    fn clone(&self) -> Self {
+        Self {
+            app_state: self.app_state.clone(),
+            rate_limiter: Arc::clone(&self.rate_limiter),
+        }
+    }
+}
+
+pub async fn rate_limit_middleware<R: ProfileRepository>(
+    State(state): State<RateLimitState<R>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let (mut parts, body) = request.into_parts();
+
+    let path = parts
+        .extensions
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_string())
+        .unwrap_or_else(|| parts.uri.path().to_string());
+    let route = format!("{} {}", parts.method, unversioned_path(&path));
+
+    let principal = match Claims::from_request_parts(&mut parts, &state.app_state).await {
+        Ok(claims) => Principal::from_claims(&claims),
+        Err(_) => {
+            let client = ClientIp::resolve(&parts.headers, &parts.extensions)
+                .map(|ClientIp(ip)| ip.to_string())
+                .unwrap_or_else(|| "unknown".to_string());
+            Principal::anonymous(&client)
+        }
+    };
+
+    let request = Request::from_parts(parts, body);
+
+    let decision = match state.rate_limiter.check(&principal, &route).await {
+        Ok(decision) => decision,
+        Err(e) => {
+            // Sem contador disponível, prefere atender a bloquear todo o tráfego
+            tracing::warn!("Rate limit check failed: {}", e);
+            return next.run(request).await;
+        }
+    };
+
+    let mut response = if decision.allowed {
+        next.run(request).await
+    } else {
+        AppErrorResponse::new(
+            StatusCode::TOO_MANY_REQUESTS,
+            ErrorCode::RateLimited,
+            "Too many requests",
+        )
+        .into_response()
+    };
+
+    decision.apply_headers(response.headers_mut());
+
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, time::Duration};
+
+    use axum::{Router, body::Body, middleware::from_fn_with_state, routing::get};
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::{
+        domain::repositories::{
+            profile_repo::MockProfileRepository, rate_limit_repo::MockRateLimitRepository,
+        },
+        infrastructure::repositories::memory_rate_limit_repo::MemoryRateLimitRepository,
+        presentation::api::handlers::tests::{
+            SharedMockRepository, create_test_token, create_test_token_for, get_test_decoding_key,
+        },
+    };
+
+    fn settings() -> RateLimitSettings {
+        RateLimitSettings {
+            window: Duration::from_secs(60),
+            anonymous_limit: 1,
+            authenticated_limit: 2,
+            admin_limit: 4,
+            route_limits: HashMap::from([("GET /limited".to_string(), 1)]),
+            ..RateLimitSettings::default()
+        }
+    }
+
+    fn app(repository: Arc<dyn RateLimitRepository>) -> Router {
+        let app_state = AppState::new(
+            Arc::new(SharedMockRepository(Arc::new(MockProfileRepository::new()))),
+            Arc::new(get_test_decoding_key()),
+        );
+
+        let state = RateLimitState {
+            app_state: app_state.clone(),
+            rate_limiter: Arc::new(RateLimiter::new(repository, settings())),
+        };
+
+        Router::new()
+            .route("/open", get(|| async { StatusCode::OK }))
+            .route("/limited", get(|| async { StatusCode::OK }))
+            .layer(from_fn_with_state(state, rate_limit_middleware))
+            .with_state(app_state)
+    }
+
+    async fn send(app: &Router, uri: &str, token: Option<&str>) -> Response {
+        let mut request = Request::builder().method("GET").uri(uri);
+        if let Some(token) = token {
+            request = request.header("authorization", format!("Bearer {}", token));
+        }
+
+        app.clone()
+            .oneshot(request.body(Body::empty()).unwrap())
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn when_under_limit_should_set_rate_limit_headers() {
+        let app = app(Arc::new(MemoryRateLimitRepository::new()));
+        let token = create_test_token_for(&Uuid::now_v7().to_string(), &[Scope::ProfileRead]);
+
+        let response = send(&app, "/open", Some(&token)).await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[RATE_LIMIT_LIMIT], "2");
+        assert_eq!(response.headers()[RATE_LIMIT_REMAINING], "1");
+        assert!(response.headers().contains_key(RATE_LIMIT_RESET));
+    }
+
+    #[tokio::test]
+    async fn when_over_limit_should_return_too_many_requests_with_retry_after() {
+        let app = app(Arc::new(MemoryRateLimitRepository::new()));
+        let token = create_test_token_for(&Uuid::now_v7().to_string(), &[Scope::ProfileRead]);
+
+        send(&app, "/open", Some(&token)).await;
+        send(&app, "/open", Some(&token)).await;
+        let response = send(&app, "/open", Some(&token)).await;
+
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(response.headers()[RATE_LIMIT_REMAINING], "0");
+        assert!(response.headers().contains_key(RETRY_AFTER));
+    }
+
+    #[tokio::test]
+    async fn when_principals_differ_should_have_separate_quotas() {
+        let app = app(Arc::new(MemoryRateLimitRepository::new()));
+        let first = create_test_token_for(&Uuid::now_v7().to_string(), &[Scope::ProfileRead]);
+        let second = create_test_token_for(&Uuid::now_v7().to_string(), &[Scope::ProfileRead]);
+
+        send(&app, "/open", Some(&first)).await;
+        send(&app, "/open", Some(&first)).await;
+        let response = send(&app, "/open", Some(&second)).await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn when_caller_is_admin_should_use_admin_tier() {
+        let app = app(Arc::new(MemoryRateLimitRepository::new()));
+
+        let response = send(&app, "/open", Some(&create_test_token())).await;
+
+        assert_eq!(response.headers()[RATE_LIMIT_LIMIT], "4");
+    }
+
+    #[tokio::test]
+    async fn when_caller_is_anonymous_should_use_anonymous_tier() {
+        let app = app(Arc::new(MemoryRateLimitRepository::new()));
+
+        send(&app, "/open", None).await;
+        let response = send(&app, "/open", None).await;
+
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    }
+
+    #[tokio::test]
+    async fn when_route_has_own_limit_should_apply_most_restrictive_limit() {
+        let app = app(Arc::new(MemoryRateLimitRepository::new()));
+        let token = create_test_token();
+
+        send(&app, "/limited", Some(&token)).await;
+        let limited = send(&app, "/limited", Some(&token)).await;
+        let open = send(&app, "/open", Some(&token)).await;
+
+        assert_eq!(limited.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(limited.headers()[RATE_LIMIT_LIMIT], "1");
+        assert_eq!(open.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn when_counter_is_unavailable_should_let_request_through() {
+        let mut repository = MockRateLimitRepository::new();
+        repository
+            .expect_hit()
+            .returning(|_, _| Err(RateLimitRepositoryError::Unknown("down".into())));
+
+        let app = app(Arc::new(repository));
+
+        let response = send(&app, "/open", None).await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(!response.headers().contains_key(RATE_LIMIT_LIMIT));
+    }
+}
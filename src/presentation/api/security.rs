@@ -5,12 +5,12 @@ use axum::{
     http::{StatusCode, header::AUTHORIZATION, request::Parts},
 };
 use chrono::Utc;
-use jsonwebtoken::{Algorithm, Validation, decode};
+use jsonwebtoken::{Algorithm, Validation, decode, errors::ErrorKind};
 use serde::{Deserialize, Serialize};
 use strum_macros::{AsRefStr, Display, EnumString};
 
 use crate::{
-    domain::repositories::profile_repo::ProfileRepository,
+    domain::{models::error_code::ErrorCode, repositories::profile_repo::ProfileRepository},
     presentation::api::{service::AppState, utils::AppErrorResponse},
 };
 
@@ -43,7 +43,7 @@ pub enum Scope {
     Unknown,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Claims {
     pub sub: String,
     pub exp: usize,
@@ -59,8 +59,25 @@ impl Claims {
             .iter()
             .any(|scope| required_scopes.contains(scope))
     }
+
+    /// Owners and holders of a read scope may read a profile.
+    pub fn can_read(&self, profile_id: &str) -> bool {
+        self.sub == profile_id || self.has_scope(vec![Scope::ProfileAdmin, Scope::ProfileRead])
+    }
+
+    /// Owners and admins see every field of a profile, regardless of its
+    /// privacy settings.
+    pub fn can_see_private_fields(&self, profile_id: &str) -> bool {
+        self.sub == profile_id || self.has_scope(vec![Scope::ProfileAdmin])
+    }
 }
 
+/// Outcome of reading the caller from a request, kept in its extensions so
+/// the rate limiter, the idempotency layer and the handler verify the token
+/// only once.
+#[derive(Clone)]
+struct Authentication(Result<Claims, AppErrorResponse>);
+
 impl<R> FromRequestParts<AppState<R>> for Claims
 where
     R: ProfileRepository,
@@ -71,70 +88,122 @@ where
         parts: &mut Parts,
         state: &AppState<R>,
     ) -> Result<Self, Self::Rejection> {
-        // 1. Extrai o header Authorization
-        let auth_header = parts
-            .headers
-            .get(AUTHORIZATION)
-            .and_then(|v| v.to_str().ok())
-            .ok_or(AppErrorResponse {
-                message: "Missing token".into(),
-                status_code: StatusCode::UNAUTHORIZED.as_u16(),
-                timestamp: Utc::now(),
-                code: None,
-                details: None,
-            })?;
+        if let Some(Authentication(result)) = parts.extensions.get::<Authentication>() {
+            return result.clone();
+        }
 
-        let token = auth_header
-            .strip_prefix("Bearer ")
-            .ok_or(AppErrorResponse {
-                message: "Invalid token format".into(),
-                status_code: StatusCode::UNAUTHORIZED.as_u16(),
-                timestamp: Utc::now(),
-                code: None,
-                details: None,
-            })?;
+        let result = authenticate(parts, state);
+        parts.extensions.insert(Authentication(result.clone()));
 
-        // 2. Configura a validação
-        let mut validation = Validation::new(Algorithm::EdDSA);
-
-        // Defina qual "ID" este microserviço aceita
-        // O token vindo do seu IAM-Service DEVE ter "profile-service" no campo 'aud'
-        validation.set_audience(&["profile-service"]);
-
-        // Garante que a validação de expiração e assinatura ocorra
-        validation.validate_exp = true;
-
-        let token_data =
-            decode::<Claims>(token, &state.decoding_key, &validation).map_err(|e| {
-                AppErrorResponse {
-                    message: format!("Token validation error: {}", e),
-                    status_code: StatusCode::UNAUTHORIZED.as_u16(),
-                    timestamp: Utc::now(),
-                    code: None,
-                    details: None,
-                }
-            })?;
+        result
+    }
+}
 
-        // Verifica se o token foi emitido para o Profile Service
-        if !token_data
-            .claims
-            .aud
-            .contains(&"profile-service".to_string())
-        {
-            return Err(AppErrorResponse {
-                message: "Invalid token audience".into(),
-                status_code: StatusCode::UNAUTHORIZED.as_u16(),
-                timestamp: Utc::now(),
-                code: None,
-                details: None,
-            });
-        }
+fn authenticate<R: ProfileRepository>(
+    parts: &Parts,
+    state: &AppState<R>,
+) -> Result<Claims, AppErrorResponse> {
+    // 1. Extrai o header Authorization
+    let auth_header = parts
+        .headers
+        .get(AUTHORIZATION)
+        .and_then(|v| v.to_str().ok());
+
+    // Sem token, aceita a identidade de um cliente mTLS confiável
+    if auth_header.is_none()
+        && let Some(identity) = parts.extensions.get::<ClientIdentity>()
+    {
+        return Ok(Claims::from(identity.clone()));
+    }
 
-        Ok(token_data.claims)
+    let auth_header = auth_header.ok_or(AppErrorResponse::new(
+        StatusCode::UNAUTHORIZED,
+        ErrorCode::TokenMissing,
+        "Missing token",
+    ))?;
+
+    let token = auth_header
+        .strip_prefix("Bearer ")
+        .ok_or(AppErrorResponse::new(
+            StatusCode::UNAUTHORIZED,
+            ErrorCode::TokenMalformed,
+            "Invalid token format",
+        ))?;
+
+    // 2. Configura a validação
+    let mut validation = Validation::new(Algorithm::EdDSA);
+
+    // Defina qual "ID" este microserviço aceita
+    // O token vindo do seu IAM-Service DEVE ter "profile-service" no campo 'aud'
+    validation.set_audience(&["profile-service"]);
+
+    // Garante que a validação de expiração e assinatura ocorra
+    validation.validate_exp = true;
+
+    let token_data = decode::<Claims>(token, &state.decoding_key, &validation).map_err(|e| {
+        AppErrorResponse::new(
+            StatusCode::UNAUTHORIZED,
+            token_error_code(e.kind()),
+            "Invalid token",
+        )
+        .with_details(e.to_string())
+    })?;
+
+    // Verifica se o token foi emitido para o Profile Service
+    if !token_data
+        .claims
+        .aud
+        .contains(&"profile-service".to_string())
+    {
+        return Err(AppErrorResponse::new(
+            StatusCode::UNAUTHORIZED,
+            ErrorCode::TokenInvalidAudience,
+            "Invalid token audience",
+        ));
     }
+
+    Ok(token_data.claims)
 }
 
-pub struct CreateClaims(Claims);
+fn token_error_code(kind: &ErrorKind) -> ErrorCode {
+    match kind {
+        ErrorKind::ExpiredSignature => ErrorCode::TokenExpired,
+        ErrorKind::ImmatureSignature => ErrorCode::TokenNotYetValid,
+        ErrorKind::InvalidSignature => ErrorCode::TokenInvalidSignature,
+        ErrorKind::InvalidAudience => ErrorCode::TokenInvalidAudience,
+        ErrorKind::InvalidToken
+        | ErrorKind::Base64(_)
+        | ErrorKind::Json(_)
+        | ErrorKind::Utf8(_) => ErrorCode::TokenMalformed,
+        _ => ErrorCode::TokenInvalid,
+    }
+}
+
+/// Identity of a client authenticated by a trusted TLS client certificate,
+/// inserted into the request extensions by the TLS acceptor.
+#[derive(Debug, Clone)]
+pub struct ClientIdentity {
+    pub subject: String,
+    pub scopes: Vec<Scope>,
+}
+
+impl From<ClientIdentity> for Claims {
+    fn from(identity: ClientIdentity) -> Self {
+        let now = Utc::now().timestamp() as usize;
+
+        Claims {
+            sub: identity.subject,
+            exp: now,
+            iat: now,
+            aud: vec!["profile-service".to_string()],
+            scopes: identity.scopes,
+            email: String::new(),
+        }
+    }
+}
+
+#[allow(dead_code)]
+pub struct CreateClaims(pub Claims);
 
 impl<R> FromRequestParts<AppState<R>> for CreateClaims
 where
@@ -149,20 +218,20 @@ where
         let claims = Claims::from_request_parts(parts, state).await?;
 
         if !claims.has_scope(vec![Scope::ProfileAdmin, Scope::ProfileCreate]) {
-            return Err(AppErrorResponse {
-                message: "Access denied: Requires admin privileges".into(),
-                status_code: StatusCode::FORBIDDEN.as_u16(),
-                timestamp: Utc::now(),
-                code: None,
-                details: None,
-            });
+            return Err(AppErrorResponse::new(
+                StatusCode::FORBIDDEN,
+                ErrorCode::AccessDenied,
+                "Access denied",
+            )
+            .with_details("Requires admin privileges"));
         }
 
         Ok(CreateClaims(claims))
     }
 }
 
-pub struct UpdateClaims(Claims);
+#[allow(dead_code)]
+pub struct UpdateClaims(pub Claims);
 
 impl<R> FromRequestParts<AppState<R>> for UpdateClaims
 where
@@ -177,42 +246,37 @@ where
         let claims = Claims::from_request_parts(parts, state).await?;
 
         let Path(path_params): Path<HashMap<String, String>> =
-            Path::from_request_parts(parts, state)
-                .await
-                .map_err(|_| AppErrorResponse {
-                    message: "Missing path parameters".into(),
-                    status_code: StatusCode::BAD_REQUEST.as_u16(),
-                    timestamp: Utc::now(),
-                    code: None,
-                    details: None,
-                })?;
-
-        let target_id = path_params.get("id").ok_or(AppErrorResponse {
-            message: "Missing 'id' parameter".into(),
-            status_code: StatusCode::BAD_REQUEST.as_u16(),
-            timestamp: Utc::now(),
-            code: None,
-            details: None,
-        })?;
+            Path::from_request_parts(parts, state).await.map_err(|_| {
+                AppErrorResponse::new(
+                    StatusCode::BAD_REQUEST,
+                    ErrorCode::MissingPathParameter,
+                    "Missing path parameters",
+                )
+            })?;
+
+        let target_id = path_params.get("id").ok_or(AppErrorResponse::new(
+            StatusCode::BAD_REQUEST,
+            ErrorCode::MissingPathParameter,
+            "Missing 'id' parameter",
+        ))?;
 
         let is_owner = &claims.sub == target_id;
         let has_scope = claims.has_scope(vec![Scope::ProfileAdmin, Scope::ProfileUpdate]);
 
         if !(is_owner || has_scope) {
-            return Err(AppErrorResponse {
-                message: "Access denied: Requires update privileges".into(),
-                status_code: StatusCode::FORBIDDEN.as_u16(),
-                timestamp: Utc::now(),
-                code: None,
-                details: None,
-            });
+            return Err(AppErrorResponse::new(
+                StatusCode::FORBIDDEN,
+                ErrorCode::AccessDenied,
+                "Access denied",
+            )
+            .with_details("Requires update privileges"));
         }
 
         Ok(UpdateClaims(claims))
     }
 }
 
-pub struct ReadClaims(Claims);
+pub struct ReadClaims(pub Claims);
 
 impl<R> FromRequestParts<AppState<R>> for ReadClaims
 where
@@ -227,42 +291,154 @@ where
         let claims = Claims::from_request_parts(parts, state).await?;
 
         let Path(path_params): Path<HashMap<String, String>> =
-            Path::from_request_parts(parts, state)
-                .await
-                .map_err(|_| AppErrorResponse {
-                    message: "Missing path parameters".into(),
-                    status_code: StatusCode::BAD_REQUEST.as_u16(),
-                    timestamp: Utc::now(),
-                    code: None,
-                    details: None,
-                })?;
-
-        let target_id = path_params.get("id").ok_or(AppErrorResponse {
-            message: "Missing 'id' parameter".into(),
-            status_code: StatusCode::BAD_REQUEST.as_u16(),
-            timestamp: Utc::now(),
-            code: None,
-            details: None,
-        })?;
-
-        let is_owner = &claims.sub == target_id;
-        let has_scope = claims.has_scope(vec![Scope::ProfileAdmin, Scope::ProfileRead]);
+            Path::from_request_parts(parts, state).await.map_err(|_| {
+                AppErrorResponse::new(
+                    StatusCode::BAD_REQUEST,
+                    ErrorCode::MissingPathParameter,
+                    "Missing path parameters",
+                )
+            })?;
 
-        if !(is_owner || has_scope) {
-            return Err(AppErrorResponse {
-                message: "Access denied: Requires read privileges".into(),
-                status_code: StatusCode::FORBIDDEN.as_u16(),
-                timestamp: Utc::now(),
-                code: None,
-                details: None,
-            });
+        let target_id = path_params.get("id").ok_or(AppErrorResponse::new(
+            StatusCode::BAD_REQUEST,
+            ErrorCode::MissingPathParameter,
+            "Missing 'id' parameter",
+        ))?;
+
+        if !claims.can_read(target_id) {
+            return Err(AppErrorResponse::new(
+                StatusCode::FORBIDDEN,
+                ErrorCode::AccessDenied,
+                "Access denied",
+            )
+            .with_details("Requires read privileges"));
         }
 
         Ok(ReadClaims(claims))
     }
 }
 
-pub struct DeleteClaims(Claims);
+/// Operations reserved to administrators, such as bulk migrations.
+#[allow(dead_code)]
+pub struct AdminClaims(pub Claims);
+
+impl<R> FromRequestParts<AppState<R>> for AdminClaims
+where
+    R: ProfileRepository,
+{
+    type Rejection = AppErrorResponse;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState<R>,
+    ) -> Result<Self, Self::Rejection> {
+        let claims = Claims::from_request_parts(parts, state).await?;
+
+        if !claims.has_scope(vec![Scope::ProfileAdmin]) {
+            return Err(AppErrorResponse::new(
+                StatusCode::FORBIDDEN,
+                ErrorCode::AccessDenied,
+                "Access denied",
+            )
+            .with_details("Requires admin privileges"));
+        }
+
+        Ok(AdminClaims(claims))
+    }
+}
+
+/// Operations only the profile's own user may perform, such as exporting
+/// their data; no scope grants them on someone else's behalf.
+#[allow(dead_code)]
+pub struct OwnerClaims(pub Claims);
+
+impl<R> FromRequestParts<AppState<R>> for OwnerClaims
+where
+    R: ProfileRepository,
+{
+    type Rejection = AppErrorResponse;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState<R>,
+    ) -> Result<Self, Self::Rejection> {
+        let claims = Claims::from_request_parts(parts, state).await?;
+
+        let Path(path_params): Path<HashMap<String, String>> =
+            Path::from_request_parts(parts, state).await.map_err(|_| {
+                AppErrorResponse::new(
+                    StatusCode::BAD_REQUEST,
+                    ErrorCode::MissingPathParameter,
+                    "Missing path parameters",
+                )
+            })?;
+
+        let target_id = path_params.get("id").ok_or(AppErrorResponse::new(
+            StatusCode::BAD_REQUEST,
+            ErrorCode::MissingPathParameter,
+            "Missing 'id' parameter",
+        ))?;
+
+        if &claims.sub != target_id {
+            return Err(AppErrorResponse::new(
+                StatusCode::FORBIDDEN,
+                ErrorCode::AccessDenied,
+                "Access denied",
+            )
+            .with_details("Only the profile owner is allowed"));
+        }
+
+        Ok(OwnerClaims(claims))
+    }
+}
+
+/// Operations on settings that are the owner's business, such as privacy.
+/// Admins may act on any profile; `profile:update` is not enough.
+#[allow(dead_code)]
+pub struct OwnerOrAdminClaims(pub Claims);
+
+impl<R> FromRequestParts<AppState<R>> for OwnerOrAdminClaims
+where
+    R: ProfileRepository,
+{
+    type Rejection = AppErrorResponse;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState<R>,
+    ) -> Result<Self, Self::Rejection> {
+        let claims = Claims::from_request_parts(parts, state).await?;
+
+        let Path(path_params): Path<HashMap<String, String>> =
+            Path::from_request_parts(parts, state).await.map_err(|_| {
+                AppErrorResponse::new(
+                    StatusCode::BAD_REQUEST,
+                    ErrorCode::MissingPathParameter,
+                    "Missing path parameters",
+                )
+            })?;
+
+        let target_id = path_params.get("id").ok_or(AppErrorResponse::new(
+            StatusCode::BAD_REQUEST,
+            ErrorCode::MissingPathParameter,
+            "Missing 'id' parameter",
+        ))?;
+
+        if !claims.can_see_private_fields(target_id) {
+            return Err(AppErrorResponse::new(
+                StatusCode::FORBIDDEN,
+                ErrorCode::AccessDenied,
+                "Access denied",
+            )
+            .with_details("Only the profile owner or an admin is allowed"));
+        }
+
+        Ok(OwnerOrAdminClaims(claims))
+    }
+}
+
+#[allow(dead_code)]
+pub struct DeleteClaims(pub Claims);
 
 impl<R> FromRequestParts<AppState<R>> for DeleteClaims
 where
@@ -277,15 +453,151 @@ where
         let claims = Claims::from_request_parts(parts, state).await?;
 
         if !claims.has_scope(vec![Scope::ProfileAdmin, Scope::ProfileDelete]) {
-            return Err(AppErrorResponse {
-                message: "Access denied: Requires delete privileges".into(),
-                status_code: StatusCode::FORBIDDEN.as_u16(),
-                timestamp: Utc::now(),
-                code: None,
-                details: None,
-            });
+            return Err(AppErrorResponse::new(
+                StatusCode::FORBIDDEN,
+                ErrorCode::AccessDenied,
+                "Access denied",
+            )
+            .with_details("Requires delete privileges"));
         }
 
         Ok(DeleteClaims(claims))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{Router, body::Body, http::Request, routing::get};
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::{
+        domain::repositories::profile_repo::MockProfileRepository,
+        presentation::api::handlers::tests::{
+            SharedMockRepository, create_test_token_for, encode_test_claims, get_test_decoding_key,
+        },
+    };
+
+    const PROFILE_ID: &str = "123e4567-e89b-12d3-a456-426614174000";
+
+    async fn send(authorization: Option<String>) -> (StatusCode, Value) {
+        let app_state = AppState::new(
+            Arc::new(SharedMockRepository(Arc::new(MockProfileRepository::new()))),
+            Arc::new(get_test_decoding_key()),
+        );
+
+        let app = Router::new()
+            .route(
+                "/profiles/{id}",
+                get(|_: ReadClaims| async { StatusCode::OK }),
+            )
+            .with_state(app_state);
+
+        let mut request = Request::builder().uri(format!("/profiles/{}", PROFILE_ID));
+        if let Some(authorization) = authorization {
+            request = request.header(AUTHORIZATION, authorization);
+        }
+
+        let response = app
+            .oneshot(request.body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+
+        (status, serde_json::from_slice(&body).unwrap_or(Value::Null))
+    }
+
+    #[tokio::test]
+    async fn when_token_is_missing_should_return_token_missing_code() {
+        let (status, body) = send(None).await;
+
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert_eq!(body["code"], "TOKEN_MISSING");
+    }
+
+    #[tokio::test]
+    async fn when_authorization_is_not_bearer_should_return_token_malformed_code() {
+        let (status, body) = send(Some("Basic dXNlcjpwYXNz".into())).await;
+
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert_eq!(body["code"], "TOKEN_MALFORMED");
+    }
+
+    #[tokio::test]
+    async fn when_token_is_expired_should_return_token_expired_code() {
+        let token = encode_test_claims(json!({
+            "sub": PROFILE_ID,
+            "iat": 1600000000u64,
+            "exp": 1600000060u64,
+            "aud": ["profile-service"],
+            "scopes": [],
+            "email": "test@example.com"
+        }));
+
+        let (status, body) = send(Some(format!("Bearer {}", token))).await;
+
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert_eq!(body["code"], "TOKEN_EXPIRED");
+    }
+
+    #[tokio::test]
+    async fn when_token_has_other_audience_should_return_token_invalid_audience_code() {
+        let token = encode_test_claims(json!({
+            "sub": PROFILE_ID,
+            "iat": 1700000000u64,
+            "exp": 9999999999u64,
+            "aud": ["billing-service"],
+            "scopes": [],
+            "email": "test@example.com"
+        }));
+
+        let (_, body) = send(Some(format!("Bearer {}", token))).await;
+
+        assert_eq!(body["code"], "TOKEN_INVALID_AUDIENCE");
+    }
+
+    #[tokio::test]
+    async fn when_claims_were_already_read_should_not_decode_token_again() {
+        let app_state = AppState::new(
+            Arc::new(SharedMockRepository(Arc::new(MockProfileRepository::new()))),
+            Arc::new(get_test_decoding_key()),
+        );
+        let token = create_test_token_for(PROFILE_ID, &[Scope::ProfileRead]);
+        let (mut parts, _) = Request::builder()
+            .header(AUTHORIZATION, format!("Bearer {}", token))
+            .body(())
+            .unwrap()
+            .into_parts();
+
+        let first = Claims::from_request_parts(&mut parts, &app_state)
+            .await
+            .unwrap();
+
+        // Um token trocado depois da primeira leitura não é verificado de novo
+        parts
+            .headers
+            .insert(AUTHORIZATION, "Bearer invalid".parse().unwrap());
+        let second = Claims::from_request_parts(&mut parts, &app_state)
+            .await
+            .unwrap();
+
+        assert_eq!(first.sub, PROFILE_ID);
+        assert_eq!(second.sub, PROFILE_ID);
+    }
+
+    #[tokio::test]
+    async fn when_caller_lacks_scope_should_return_access_denied_code() {
+        let token = create_test_token_for("someone-else", &[Scope::ProfileUpdate]);
+
+        let (status, body) = send(Some(format!("Bearer {}", token))).await;
+
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert_eq!(body["code"], "ACCESS_DENIED");
+    }
+}
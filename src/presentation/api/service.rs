@@ -1,100 +1,263 @@
 use std::{net::SocketAddr, sync::Arc};
 
 use axum::{
-    Router,
-    http::{
-        HeaderValue, Method,
-        header::{AUTHORIZATION, CONTENT_TYPE},
-    },
-    routing::{get, post},
+    Extension, Router,
+    body::Body,
+    http::Request,
+    middleware::{from_fn, from_fn_with_state},
 };
 use jsonwebtoken::DecodingKey;
-use tower_governor::{GovernorLayer, governor::GovernorConfigBuilder};
-use tower_http::{cors::CorsLayer, trace::TraceLayer};
+use tower_http::trace::TraceLayer;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
+use utoipa::{OpenApi, openapi::OpenApi as OpenApiDocument};
+use utoipa_axum::{router::OpenApiRouter, routes};
 
 use crate::{
     application::use_cases::{
-        create_profile::CreateProfileUseCase, get_profile_by_id::GetProfileByIdUseCase,
+        bulk_create_profiles::BulkCreateProfilesUseCase,
+        bulk_update_profiles::BulkUpdateProfilesUseCase,
+        change_username::{ChangeUsernameUseCase, UsernamePolicy},
+        create_profile::CreateProfileUseCase,
+        export_profile::ExportProfileUseCase,
+        get_profile_by_id::GetProfileByIdUseCase,
+        get_profile_by_username::GetProfileByUsernameUseCase,
+        get_profiles_by_ids::GetProfilesByIdsUseCase,
+        update_display_name::UpdateDisplayNameUseCase,
+        update_privacy_settings::UpdatePrivacySettingsUseCase,
         update_profile::UpdateProfileUseCase,
     },
-    domain::repositories::profile_repo::ProfileRepository,
-    infrastructure::repositories::mongo_profile_repo::MongoProfileRepository,
+    domain::repositories::{
+        idempotency_repo::IdempotencyRepository, profile_repo::ProfileRepository,
+        rate_limit_repo::RateLimitRepository,
+    },
 };
 
 use super::handlers::{
-    create_profile::create_profile_handler, get_profile_by_id::get_profile_by_id_handler,
-    update_profile_by_id::update_profile_by_id_handler,
+    bulk_profiles, change_username, create_profile, export_profile, get_privacy_settings,
+    get_profile_by_id, get_profile_by_username, get_profiles_by_ids, update_display_name,
+    update_privacy_settings, update_profile_by_id,
+};
+use super::{
+    client_ip::ClientIp,
+    cors::cors_layer,
+    i18n::localize_errors_middleware,
+    idempotency::{IdempotencyState, idempotency_middleware},
+    openapi::{ApiDoc, docs_router},
+    problem::problem_json_middleware,
+    rate_limit::{RateLimitState, RateLimiter, rate_limit_middleware},
+    settings::ServiceSettings,
+    tls::{self, load_server_config},
+    versioning::{Deprecation, V1_PREFIX, mount_version},
 };
 
-#[derive(Clone)]
 pub struct AppState<R: ProfileRepository> {
+    pub bulk_create_profiles_use_case: Arc<BulkCreateProfilesUseCase<R>>,
+    pub bulk_update_profiles_use_case: Arc<BulkUpdateProfilesUseCase<R>>,
+    pub change_username_use_case: Arc<ChangeUsernameUseCase<R>>,
     pub create_profile_use_case: Arc<CreateProfileUseCase<R>>,
+    pub export_profile_use_case: Arc<ExportProfileUseCase<R>>,
     pub get_profile_by_id_use_case: Arc<GetProfileByIdUseCase<R>>,
+    pub get_profile_by_username_use_case: Arc<GetProfileByUsernameUseCase<R>>,
+    pub get_profiles_by_ids_use_case: Arc<GetProfilesByIdsUseCase<R>>,
+    pub update_display_name_use_case: Arc<UpdateDisplayNameUseCase<R>>,
     pub update_profile_use_case: Arc<UpdateProfileUseCase<R>>,
+    pub update_privacy_settings_use_case: Arc<UpdatePrivacySettingsUseCase<R>>,
     pub decoding_key: Arc<DecodingKey>,
 }
 
+// Manual impl: every field is an `Arc`, so `R` itself need not be `Clone`
+impl<R: ProfileRepository> Clone for AppState<R> {
+    fn clone(&self) -> Self {
+        Self {
+            bulk_create_profiles_use_case: Arc::clone(&self.bulk_create_profiles_use_case),
+            bulk_update_profiles_use_case: Arc::clone(&self.bulk_update_profiles_use_case),
+            change_username_use_case: Arc::clone(&self.change_username_use_case),
+            create_profile_use_case: Arc::clone(&self.create_profile_use_case),
+            export_profile_use_case: Arc::clone(&self.export_profile_use_case),
+            get_profile_by_id_use_case: Arc::clone(&self.get_profile_by_id_use_case),
+            get_profile_by_username_use_case: Arc::clone(&self.get_profile_by_username_use_case),
+            get_profiles_by_ids_use_case: Arc::clone(&self.get_profiles_by_ids_use_case),
+            update_display_name_use_case: Arc::clone(&self.update_display_name_use_case),
+            update_profile_use_case: Arc::clone(&self.update_profile_use_case),
+            update_privacy_settings_use_case: Arc::clone(&self.update_privacy_settings_use_case),
+            decoding_key: Arc::clone(&self.decoding_key),
+        }
+    }
+}
+
 impl<R: ProfileRepository> AppState<R> {
     pub fn new(repository: Arc<R>, decoding_key: Arc<DecodingKey>) -> Self {
         Self {
+            bulk_create_profiles_use_case: Arc::new(BulkCreateProfilesUseCase::new(Arc::clone(
+                &repository,
+            ))),
+            bulk_update_profiles_use_case: Arc::new(BulkUpdateProfilesUseCase::new(Arc::clone(
+                &repository,
+            ))),
+            change_username_use_case: Arc::new(ChangeUsernameUseCase::new(
+                Arc::clone(&repository),
+                UsernamePolicy::default(),
+            )),
             create_profile_use_case: Arc::new(CreateProfileUseCase::new(Arc::clone(&repository))),
+            export_profile_use_case: Arc::new(ExportProfileUseCase::new(Arc::clone(&repository))),
             get_profile_by_id_use_case: Arc::new(GetProfileByIdUseCase::new(Arc::clone(
                 &repository,
             ))),
+            get_profile_by_username_use_case: Arc::new(GetProfileByUsernameUseCase::new(
+                Arc::clone(&repository),
+            )),
+            get_profiles_by_ids_use_case: Arc::new(GetProfilesByIdsUseCase::new(Arc::clone(
+                &repository,
+            ))),
+            update_display_name_use_case: Arc::new(UpdateDisplayNameUseCase::new(Arc::clone(
+                &repository,
+            ))),
             update_profile_use_case: Arc::new(UpdateProfileUseCase::new(Arc::clone(&repository))),
+            update_privacy_settings_use_case: Arc::new(UpdatePrivacySettingsUseCase::new(
+                Arc::clone(&repository),
+            )),
             decoding_key,
         }
     }
+
+    /// Replaces the default limits on renames.
+    pub fn with_username_policy(mut self, policy: UsernamePolicy) -> Self {
+        self.change_username_use_case = Arc::new(self.change_username_use_case.with_policy(policy));
+        self
+    }
+}
+
+fn v1_routes<R: ProfileRepository>() -> OpenApiRouter<AppState<R>> {
+    let profiles = OpenApiRouter::new()
+        .routes(routes!(create_profile::create_profile_handler))
+        .routes(routes!(get_profiles_by_ids::get_profiles_by_ids_handler))
+        .routes(routes!(bulk_profiles::bulk_create_profiles_handler))
+        .routes(routes!(bulk_profiles::bulk_update_profiles_handler))
+        .routes(routes!(
+            get_profile_by_id::get_profile_by_id_handler,
+            update_profile_by_id::update_profile_by_id_handler
+        ))
+        .routes(routes!(export_profile::export_profile_handler))
+        .routes(routes!(
+            get_profile_by_username::get_profile_by_username_handler
+        ))
+        .routes(routes!(change_username::change_username_handler))
+        .routes(routes!(update_display_name::update_display_name_handler))
+        .routes(routes!(
+            get_privacy_settings::get_privacy_settings_handler,
+            update_privacy_settings::update_privacy_settings_handler
+        ));
+
+    OpenApiRouter::new().nest("/profiles", profiles)
+}
+
+/// Routes of the API together with the OpenAPI document describing them.
+/// Handlers are registered through their `#[utoipa::path]` annotations, which
+/// is what keeps the document in step with the router.
+///
+/// `/v1` is served alongside the unversioned routes, kept as a deprecated
+/// alias while clients migrate. A new version gets its own `vN_routes` and
+/// `mount_version` call here.
+pub fn api_router<R: ProfileRepository>(
+    legacy_routes: Deprecation,
+) -> (Router<AppState<R>>, OpenApiDocument) {
+    let mut openapi = ApiDoc::openapi();
+
+    let (v1, v1_openapi) = mount_version(v1_routes(), V1_PREFIX, None);
+    let (legacy, legacy_openapi) = mount_version(v1_routes(), "", Some(legacy_routes));
+
+    openapi.merge(v1_openapi);
+    openapi.merge(legacy_openapi);
+
+    (v1.merge(legacy), openapi)
 }
 
 pub struct Service {}
 
 impl Service {
-    pub async fn run(respository: MongoProfileRepository, request_host: String, addr: String) {
+    pub async fn run<R: ProfileRepository>(
+        respository: R,
+        rate_limit_repository: Arc<dyn RateLimitRepository>,
+        idempotency_repository: Arc<dyn IdempotencyRepository>,
+        settings: ServiceSettings,
+    ) {
         tracing_subscriber::registry()
             .with(tracing_subscriber::fmt::layer())
             .init();
 
-        let cors_layer = CorsLayer::new()
-            .allow_methods([Method::GET, Method::POST, Method::PUT])
-            .allow_origin(HeaderValue::from_str(&request_host).unwrap())
-            .allow_headers([AUTHORIZATION, CONTENT_TYPE])
-            .allow_credentials(true);
-
-        let governor_conf = GovernorConfigBuilder::default()
-            .per_second(2)
-            .burst_size(8)
-            .finish()
-            .unwrap();
-
-        let routers = Router::new()
-            .route("/", post(create_profile_handler))
-            .route(
-                "/{id}",
-                get(get_profile_by_id_handler).put(update_profile_by_id_handler),
-            );
+        let cors_layer = cors_layer(&settings.cors);
+
+        let (routers, openapi) = api_router(settings.legacy_routes.clone());
 
         let pem_content =
             std::fs::read("./keys/public_key.pem").expect("Failed to view EdDSA public key");
 
         let decoding_key = DecodingKey::from_ed_pem(&pem_content).expect("Invalid EdDSA key");
 
-        let state = AppState::new(Arc::new(respository), Arc::new(decoding_key));
+        let state = AppState::new(Arc::new(respository), Arc::new(decoding_key))
+            .with_username_policy(settings.username);
+
+        let rate_limit_state = RateLimitState {
+            app_state: state.clone(),
+            rate_limiter: Arc::new(RateLimiter::new(
+                rate_limit_repository,
+                settings.rate_limit.clone(),
+            )),
+        };
+
+        let idempotency_state = IdempotencyState {
+            app_state: state.clone(),
+            repository: idempotency_repository,
+            settings: settings.idempotency.clone(),
+        };
 
-        let app = Router::new()
-            .nest("/profiles", routers)
+        let trace_layer = TraceLayer::new_for_http().make_span_with(|request: &Request<Body>| {
+            let client_ip = ClientIp::resolve(request.headers(), request.extensions())
+                .map(|ClientIp(ip)| ip.to_string())
+                .unwrap_or_default();
+
+            tracing::info_span!(
+                "request",
+                method = %request.method(),
+                uri = %request.uri(),
+                client_ip = %client_ip,
+            )
+        });
+
+        let app = routers
+            .merge(docs_router(openapi, settings.swagger_ui))
+            .layer(from_fn_with_state(
+                idempotency_state,
+                idempotency_middleware,
+            ))
+            .layer(from_fn_with_state(rate_limit_state, rate_limit_middleware))
             .with_state(state)
-            .layer(TraceLayer::new_for_http())
-            .layer(GovernorLayer::new(governor_conf))
+            .layer(from_fn(localize_errors_middleware))
+            .layer(from_fn(problem_json_middleware))
+            .layer(trace_layer)
+            .layer(Extension(Arc::new(settings.trusted_proxies)))
             .layer(cors_layer);
 
-        let listener = tokio::net::TcpListener::bind(&addr).await.unwrap();
-        axum::serve(
-            listener,
-            app.into_make_service_with_connect_info::<SocketAddr>(),
-        )
-        .await
-        .unwrap();
+        let listener = tokio::net::TcpListener::bind(&settings.addr).await.unwrap();
+
+        match settings.tls {
+            Some(tls_settings) => {
+                let config = load_server_config(&tls_settings).expect("Invalid TLS configuration");
+                tls::serve(
+                    listener,
+                    app,
+                    config,
+                    tls_settings.trusted_clients,
+                    tls_settings.handshake_timeout,
+                )
+                .await;
+            }
+            None => axum::serve(
+                listener,
+                app.into_make_service_with_connect_info::<SocketAddr>(),
+            )
+            .await
+            .unwrap(),
+        }
     }
 }
@@ -0,0 +1,661 @@
+use std::{collections::HashMap, str::FromStr, time::Duration};
+
+use anyhow::{Result, anyhow};
+use axum::http::{
+    HeaderName, Method,
+    header::{ETAG, LAST_MODIFIED, LINK, LOCATION, RETRY_AFTER},
+};
+use chrono::{DateTime, NaiveDate, Utc};
+
+use crate::{
+    application::use_cases::change_username::UsernamePolicy,
+    infrastructure::repositories::mongo_profile_repo::ProfileReadPolicy,
+    presentation::api::{
+        client_ip::TrustedProxies,
+        cors::OriginPattern,
+        idempotency::IDEMPOTENT_REPLAYED,
+        rate_limit::{RATE_LIMIT_LIMIT, RATE_LIMIT_REMAINING, RATE_LIMIT_RESET},
+        security::Scope,
+        versioning::{DEPRECATION, Deprecation, SUNSET, V1_PREFIX},
+    },
+};
+
+/// Date the unversioned `/profiles` routes were deprecated in favour of
+/// `/v1/profiles`, used when `API_LEGACY_DEPRECATED_AT` is not set.
+const LEGACY_DEPRECATED_AT: &str = "2026-10-18";
+
+pub struct ServiceSettings {
+    pub addr: String,
+    pub tls: Option<TlsSettings>,
+    pub rate_limit: RateLimitSettings,
+    pub idempotency: IdempotencySettings,
+    pub profile_cache: ProfileCacheSettings,
+    pub profile_read: ProfileReadSettings,
+    pub profile_schema: ProfileSchemaSettings,
+    pub username: UsernamePolicy,
+    pub trusted_proxies: TrustedProxies,
+    pub cors: CorsSettings,
+    pub swagger_ui: bool,
+    /// Deprecation announced on the unversioned `/profiles` alias.
+    pub legacy_routes: Deprecation,
+}
+
+pub struct TlsSettings {
+    pub cert_path: String,
+    pub key_path: String,
+    pub client_ca_path: Option<String>,
+    /// Scopes granted to each trusted client certificate, keyed by the
+    /// certificate subject's common name.
+    pub trusted_clients: HashMap<String, Vec<Scope>>,
+    /// Connections that have not finished the handshake by then are closed.
+    pub handshake_timeout: Duration,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitBackend {
+    Memory,
+    Mongo,
+}
+
+#[derive(Debug, Clone)]
+pub struct RateLimitSettings {
+    pub backend: RateLimitBackend,
+    pub window: Duration,
+    pub anonymous_limit: u64,
+    pub authenticated_limit: u64,
+    pub admin_limit: u64,
+    /// Additional per-principal limits for single routes, keyed by
+    /// `METHOD /path` as declared in the router without the version prefix,
+    /// e.g. `POST /profiles`. Every version of the route shares the limit.
+    pub route_limits: HashMap<String, u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdempotencyBackend {
+    Memory,
+    Mongo,
+}
+
+#[derive(Debug, Clone)]
+pub struct IdempotencySettings {
+    pub backend: IdempotencyBackend,
+    /// How long a completed response is kept for replay.
+    pub ttl: Duration,
+    /// How long a key stays claimed while its first request runs.
+    pub lock: Duration,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProfileCacheBackend {
+    Disabled,
+    Memory,
+    Redis { url: String },
+}
+
+#[derive(Debug, Clone)]
+pub struct ProfileCacheSettings {
+    pub backend: ProfileCacheBackend,
+    /// Maximum number of profiles kept by the in-memory backend.
+    pub capacity: usize,
+    /// How long a cached profile is served before it is read again.
+    pub ttl: Duration,
+    /// How often hit and miss counters are logged.
+    pub metrics_interval: Duration,
+}
+
+#[derive(Debug, Clone)]
+pub struct ProfileReadSettings {
+    pub policy: ProfileReadPolicy,
+    /// How often the counters of fields left out by lenient reads are logged.
+    pub metrics_interval: Duration,
+}
+
+#[derive(Debug, Clone)]
+pub struct ProfileSchemaSettings {
+    /// Rewrites the documents stored in older shapes once, at startup.
+    pub upgrade_in_background: bool,
+    pub upgrade_batch_size: u64,
+    /// Pause between batches of the upgrade.
+    pub upgrade_pause: Duration,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Environment {
+    Development,
+    Staging,
+    Production,
+}
+
+#[derive(Debug, Clone)]
+pub struct CorsSettings {
+    pub allowed_origins: Vec<OriginPattern>,
+    pub allowed_methods: Vec<Method>,
+    pub exposed_headers: Vec<HeaderName>,
+    pub max_age: Duration,
+}
+
+impl ServiceSettings {
+    pub fn from_env() -> Result<Self> {
+        let request_host =
+            std::env::var("REQUEST_HOST").unwrap_or_else(|_| "http://localhost:3000".into());
+        let addr = std::env::var("SERVICE_ADDR").unwrap_or_else(|_| "0.0.0.0:3000".into());
+        let trusted_proxies = std::env::var("TRUSTED_PROXIES")
+            .unwrap_or_default()
+            .parse()
+            .map_err(|e| anyhow!("Invalid TRUSTED_PROXIES: {}", e))?;
+
+        Ok(Self {
+            addr,
+            tls: TlsSettings::from_env()?,
+            rate_limit: RateLimitSettings::from_env()?,
+            idempotency: IdempotencySettings::from_env()?,
+            profile_cache: ProfileCacheSettings::from_env()?,
+            profile_read: ProfileReadSettings::from_env()?,
+            profile_schema: ProfileSchemaSettings::from_env()?,
+            username: username_policy_from_env()?,
+            trusted_proxies,
+            cors: CorsSettings::from_env(&request_host)?,
+            swagger_ui: matches!(
+                std::env::var("SWAGGER_UI_ENABLED").as_deref(),
+                Ok("true") | Ok("1")
+            ),
+            legacy_routes: legacy_routes_from_env()?,
+        })
+    }
+}
+
+impl TlsSettings {
+    /// TLS is only enabled when both `TLS_CERT_PATH` and `TLS_KEY_PATH` are set.
+    pub fn from_env() -> Result<Option<Self>> {
+        let (Ok(cert_path), Ok(key_path)) = (
+            std::env::var("TLS_CERT_PATH"),
+            std::env::var("TLS_KEY_PATH"),
+        ) else {
+            return Ok(None);
+        };
+
+        let client_ca_path = std::env::var("MTLS_CLIENT_CA_PATH").ok();
+        let trusted_clients = std::env::var("MTLS_TRUSTED_CLIENTS")
+            .map(|value| parse_trusted_clients(&value))
+            .unwrap_or_else(|_| Ok(HashMap::new()))?;
+
+        let handshake_timeout = Duration::from_secs(env_number("TLS_HANDSHAKE_TIMEOUT_SECS", 10)?);
+
+        Ok(Some(Self {
+            cert_path,
+            key_path,
+            client_ca_path,
+            trusted_clients,
+            handshake_timeout,
+        }))
+    }
+}
+
+impl Default for RateLimitSettings {
+    fn default() -> Self {
+        Self {
+            backend: RateLimitBackend::Memory,
+            window: Duration::from_secs(60),
+            anonymous_limit: 60,
+            authenticated_limit: 300,
+            admin_limit: 1200,
+            route_limits: HashMap::new(),
+        }
+    }
+}
+
+impl RateLimitSettings {
+    pub fn from_env() -> Result<Self> {
+        let defaults = Self::default();
+
+        let backend = match std::env::var("RATE_LIMIT_BACKEND").as_deref() {
+            Ok("mongo") => RateLimitBackend::Mongo,
+            Ok("memory") | Err(_) => RateLimitBackend::Memory,
+            Ok(other) => return Err(anyhow!("Unknown rate limit backend: {}", other)),
+        };
+
+        let route_limits = std::env::var("RATE_LIMIT_ROUTES")
+            .map(|value| parse_route_limits(&value))
+            .unwrap_or_else(|_| Ok(HashMap::new()))?;
+
+        Ok(Self {
+            backend,
+            window: Duration::from_secs(env_number("RATE_LIMIT_WINDOW_SECS", 60)?),
+            anonymous_limit: env_number("RATE_LIMIT_ANONYMOUS", defaults.anonymous_limit)?,
+            authenticated_limit: env_number(
+                "RATE_LIMIT_AUTHENTICATED",
+                defaults.authenticated_limit,
+            )?,
+            admin_limit: env_number("RATE_LIMIT_ADMIN", defaults.admin_limit)?,
+            route_limits,
+        })
+    }
+}
+
+impl Default for IdempotencySettings {
+    fn default() -> Self {
+        Self {
+            backend: IdempotencyBackend::Memory,
+            ttl: Duration::from_secs(24 * 60 * 60),
+            lock: Duration::from_secs(60),
+        }
+    }
+}
+
+impl IdempotencySettings {
+    pub fn from_env() -> Result<Self> {
+        let defaults = Self::default();
+
+        let backend = match std::env::var("IDEMPOTENCY_BACKEND").as_deref() {
+            Ok("mongo") => IdempotencyBackend::Mongo,
+            Ok("memory") | Err(_) => IdempotencyBackend::Memory,
+            Ok(other) => return Err(anyhow!("Unknown idempotency backend: {}", other)),
+        };
+
+        Ok(Self {
+            backend,
+            ttl: Duration::from_secs(env_number("IDEMPOTENCY_TTL_SECS", defaults.ttl.as_secs())?),
+            lock: Duration::from_secs(env_number(
+                "IDEMPOTENCY_LOCK_SECS",
+                defaults.lock.as_secs(),
+            )?),
+        })
+    }
+}
+
+impl Default for ProfileCacheSettings {
+    fn default() -> Self {
+        Self {
+            backend: ProfileCacheBackend::Disabled,
+            capacity: 10_000,
+            ttl: Duration::from_secs(60),
+            metrics_interval: Duration::from_secs(300),
+        }
+    }
+}
+
+impl ProfileCacheSettings {
+    pub fn from_env() -> Result<Self> {
+        let defaults = Self::default();
+
+        let backend = match std::env::var("PROFILE_CACHE_BACKEND").as_deref() {
+            Ok("memory") => ProfileCacheBackend::Memory,
+            Ok("redis") => ProfileCacheBackend::Redis {
+                url: std::env::var("PROFILE_CACHE_REDIS_URL")
+                    .unwrap_or_else(|_| "redis://127.0.0.1:6379".to_string()),
+            },
+            Ok("none") | Err(_) => ProfileCacheBackend::Disabled,
+            Ok(other) => return Err(anyhow!("Unknown profile cache backend: {}", other)),
+        };
+
+        Ok(Self {
+            backend,
+            capacity: env_number("PROFILE_CACHE_CAPACITY", defaults.capacity as u64)? as usize,
+            ttl: Duration::from_secs(env_number(
+                "PROFILE_CACHE_TTL_SECS",
+                defaults.ttl.as_secs(),
+            )?),
+            metrics_interval: Duration::from_secs(env_number(
+                "PROFILE_CACHE_METRICS_INTERVAL_SECS",
+                defaults.metrics_interval.as_secs(),
+            )?),
+        })
+    }
+}
+
+impl ProfileReadSettings {
+    pub fn from_env() -> Result<Self> {
+        let policy = match std::env::var("PROFILE_READ_POLICY").as_deref() {
+            Ok("strict") | Err(_) => ProfileReadPolicy::Strict,
+            Ok("lenient") => ProfileReadPolicy::Lenient,
+            Ok(other) => return Err(anyhow!("Unknown profile read policy: {}", other)),
+        };
+
+        Ok(Self {
+            policy,
+            metrics_interval: Duration::from_secs(env_number(
+                "PROFILE_READ_METRICS_INTERVAL_SECS",
+                300,
+            )?),
+        })
+    }
+}
+
+impl ProfileSchemaSettings {
+    pub fn from_env() -> Result<Self> {
+        Ok(Self {
+            upgrade_in_background: matches!(
+                std::env::var("PROFILE_SCHEMA_UPGRADE").as_deref(),
+                Ok("true") | Ok("1")
+            ),
+            upgrade_batch_size: env_number("PROFILE_SCHEMA_UPGRADE_BATCH_SIZE", 500)?.max(1),
+            upgrade_pause: Duration::from_millis(env_number(
+                "PROFILE_SCHEMA_UPGRADE_PAUSE_MS",
+                100,
+            )?),
+        })
+    }
+}
+
+impl FromStr for Environment {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "development" | "dev" => Ok(Environment::Development),
+            "staging" => Ok(Environment::Staging),
+            "production" | "prod" => Ok(Environment::Production),
+            other => Err(anyhow!("Unknown environment: {}", other)),
+        }
+    }
+}
+
+impl CorsSettings {
+    /// Defaults per environment: development accepts any local origin and
+    /// keeps preflights short lived so changes are picked up quickly; staging
+    /// and production only accept the configured origins.
+    pub fn preset(environment: Environment) -> Self {
+        let (allowed_origins, max_age) = match environment {
+            Environment::Development => (
+                vec![
+                    OriginPattern::Exact("http://localhost:3000".into()),
+                    OriginPattern::Exact("http://localhost:5173".into()),
+                    OriginPattern::Exact("http://127.0.0.1:3000".into()),
+                ],
+                Duration::from_secs(60),
+            ),
+            Environment::Staging => (Vec::new(), Duration::from_secs(600)),
+            Environment::Production => (Vec::new(), Duration::from_secs(3600)),
+        };
+
+        Self {
+            allowed_origins,
+            allowed_methods: vec![
+                Method::GET,
+                Method::POST,
+                Method::PUT,
+                Method::PATCH,
+                Method::DELETE,
+            ],
+            exposed_headers: vec![
+                ETAG,
+                LAST_MODIFIED,
+                LOCATION,
+                RETRY_AFTER,
+                HeaderName::from_static(RATE_LIMIT_LIMIT),
+                HeaderName::from_static(RATE_LIMIT_REMAINING),
+                HeaderName::from_static(RATE_LIMIT_RESET),
+                HeaderName::from_static(IDEMPOTENT_REPLAYED),
+                HeaderName::from_static(DEPRECATION),
+                HeaderName::from_static(SUNSET),
+                LINK,
+            ],
+            max_age,
+        }
+    }
+
+    /// Starts from the `APP_ENV` preset and always allows `REQUEST_HOST`, so
+    /// existing deployments keep working without extra configuration.
+    pub fn from_env(request_host: &str) -> Result<Self> {
+        let environment = parse_environment(std::env::var("APP_ENV").ok().as_deref())?;
+
+        let mut settings = Self::preset(environment);
+
+        settings.allowed_origins.push(
+            request_host
+                .parse()
+                .map_err(|e| anyhow!("Invalid REQUEST_HOST: {}", e))?,
+        );
+
+        if let Ok(value) = std::env::var("CORS_ALLOWED_ORIGINS") {
+            settings.allowed_origins.extend(parse_origins(&value)?);
+        }
+
+        if let Ok(value) = std::env::var("CORS_ALLOWED_METHODS") {
+            settings.allowed_methods = parse_list(&value, "method")?;
+        }
+
+        if let Ok(value) = std::env::var("CORS_EXPOSED_HEADERS") {
+            settings.exposed_headers = parse_list(&value, "header")?;
+        }
+
+        settings.max_age =
+            Duration::from_secs(env_number("CORS_MAX_AGE_SECS", settings.max_age.as_secs())?);
+
+        Ok(settings)
+    }
+}
+
+/// Without `APP_ENV` the production preset applies: a deployment that
+/// forgot it must not accept credentialed requests from local origins.
+fn parse_environment(value: Option<&str>) -> Result<Environment> {
+    value.map_or(Ok(Environment::Production), str::parse)
+}
+
+/// Parses a comma separated list of origins, e.g.
+/// `https://app.example.com, https://*.preview.example.com`.
+pub fn parse_origins(value: &str) -> Result<Vec<OriginPattern>> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| entry.parse().map_err(|e| anyhow!("Invalid origin: {}", e)))
+        .collect()
+}
+
+fn parse_list<T: FromStr>(value: &str, kind: &str) -> Result<Vec<T>> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| {
+            entry
+                .to_ascii_uppercase()
+                .parse()
+                .or_else(|_| entry.to_ascii_lowercase().parse())
+                .map_err(|_| anyhow!("Invalid {}: {}", kind, entry))
+        })
+        .collect()
+}
+
+fn legacy_routes_from_env() -> Result<Deprecation> {
+    let since =
+        std::env::var("API_LEGACY_DEPRECATED_AT").unwrap_or_else(|_| LEGACY_DEPRECATED_AT.into());
+    let sunset = std::env::var("API_LEGACY_SUNSET")
+        .ok()
+        .map(|value| parse_date(&value))
+        .transpose()?;
+
+    Ok(Deprecation {
+        since: parse_date(&since)?,
+        sunset,
+        successor: Some(V1_PREFIX.into()),
+    })
+}
+
+/// Accepts either a plain date (`2027-06-30`, midnight UTC) or RFC 3339.
+pub fn parse_date(value: &str) -> Result<DateTime<Utc>> {
+    let value = value.trim();
+
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        return Ok(date.and_time(Default::default()).and_utc());
+    }
+
+    DateTime::parse_from_rfc3339(value)
+        .map(|date| date.with_timezone(&Utc))
+        .map_err(|_| anyhow!("Invalid date: {}", value))
+}
+
+fn username_policy_from_env() -> Result<UsernamePolicy> {
+    let defaults = UsernamePolicy::default();
+
+    Ok(UsernamePolicy {
+        change_interval: chrono::Duration::days(env_number(
+            "USERNAME_CHANGE_INTERVAL_DAYS",
+            defaults.change_interval.num_days() as u64,
+        )? as i64),
+        reservation_period: chrono::Duration::days(env_number(
+            "USERNAME_RESERVATION_DAYS",
+            defaults.reservation_period.num_days() as u64,
+        )? as i64),
+    })
+}
+
+fn env_number(name: &str, default: u64) -> Result<u64> {
+    match std::env::var(name) {
+        Ok(value) => value
+            .trim()
+            .parse()
+            .map_err(|_| anyhow!("{} must be a positive number", name)),
+        Err(_) => Ok(default),
+    }
+}
+
+/// Parses `METHOD /path=limit;METHOD /path=limit`, e.g.
+/// `POST /profiles=10;PUT /profiles/{id}=30`.
+pub fn parse_route_limits(value: &str) -> Result<HashMap<String, u64>> {
+    value
+        .split(';')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| {
+            let (route, limit) = entry
+                .rsplit_once('=')
+                .ok_or_else(|| anyhow!("Invalid route limit entry: {}", entry))?;
+
+            let limit = limit
+                .trim()
+                .parse()
+                .map_err(|_| anyhow!("Invalid limit for route: {}", route))?;
+
+            Ok((route.trim().to_string(), limit))
+        })
+        .collect()
+}
+
+/// Parses `subject=scope,scope;subject=scope` into a subject to scopes map,
+/// e.g. `iam-service=profile:create;backoffice=profile:read,profile:update`.
+pub fn parse_trusted_clients(value: &str) -> Result<HashMap<String, Vec<Scope>>> {
+    value
+        .split(';')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| {
+            let (subject, scopes) = entry
+                .split_once('=')
+                .ok_or_else(|| anyhow!("Invalid trusted client entry: {}", entry))?;
+
+            let scopes = scopes
+                .split(',')
+                .map(str::trim)
+                .filter(|scope| !scope.is_empty())
+                .map(|scope| {
+                    Scope::from_str(scope).map_err(|_| anyhow!("Unknown scope: {}", scope))
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            Ok((subject.trim().to_string(), scopes))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn when_trusted_clients_are_valid_should_map_subjects_to_scopes() {
+        let clients = parse_trusted_clients(
+            "iam-service=profile:create; backoffice=profile:read,profile:update",
+        )
+        .unwrap();
+
+        assert_eq!(clients["iam-service"], vec![Scope::ProfileCreate]);
+        assert_eq!(
+            clients["backoffice"],
+            vec![Scope::ProfileRead, Scope::ProfileUpdate]
+        );
+    }
+
+    #[test]
+    fn when_trusted_client_has_unknown_scope_should_return_error() {
+        assert!(parse_trusted_clients("iam-service=profile:everything").is_err());
+    }
+
+    #[test]
+    fn when_trusted_client_entry_has_no_scopes_separator_should_return_error() {
+        assert!(parse_trusted_clients("iam-service").is_err());
+    }
+
+    #[test]
+    fn when_route_limits_are_valid_should_map_routes_to_limits() {
+        let limits = parse_route_limits("POST /profiles=10; PUT /profiles/{id}=30").unwrap();
+
+        assert_eq!(limits["POST /profiles"], 10);
+        assert_eq!(limits["PUT /profiles/{id}"], 30);
+    }
+
+    #[test]
+    fn when_route_limit_is_not_a_number_should_return_error() {
+        assert!(parse_route_limits("POST /profiles=many").is_err());
+    }
+
+    #[test]
+    fn when_origins_are_valid_should_parse_exact_and_wildcard_patterns() {
+        let origins =
+            parse_origins("https://app.example.com, https://*.preview.example.com").unwrap();
+
+        assert_eq!(origins.len(), 2);
+        assert!(origins[1].matches("https://pr-7.preview.example.com"));
+    }
+
+    #[test]
+    fn when_origin_has_no_scheme_should_return_error() {
+        assert!(parse_origins("app.example.com").is_err());
+    }
+
+    #[test]
+    fn when_methods_are_listed_should_parse_case_insensitively() {
+        let methods: Vec<Method> = parse_list("get, Delete", "method").unwrap();
+
+        assert_eq!(methods, vec![Method::GET, Method::DELETE]);
+    }
+
+    #[test]
+    fn when_environment_is_production_should_not_allow_any_origin_by_default() {
+        let settings = CorsSettings::preset("prod".parse().unwrap());
+
+        assert!(settings.allowed_origins.is_empty());
+        assert!(settings.exposed_headers.contains(&ETAG));
+        assert_eq!(settings.max_age, Duration::from_secs(3600));
+    }
+
+    #[test]
+    fn when_date_is_plain_or_rfc_3339_should_parse_as_utc() {
+        assert_eq!(
+            parse_date("2027-06-30").unwrap().to_rfc3339(),
+            "2027-06-30T00:00:00+00:00"
+        );
+        assert_eq!(
+            parse_date("2027-06-30T12:00:00-03:00")
+                .unwrap()
+                .to_rfc3339(),
+            "2027-06-30T15:00:00+00:00"
+        );
+        assert!(parse_date("30/06/2027").is_err());
+    }
+
+    #[test]
+    fn when_environment_is_not_set_should_fall_back_to_production() {
+        assert_eq!(parse_environment(None).unwrap(), Environment::Production);
+        assert_eq!(
+            parse_environment(Some("dev")).unwrap(),
+            Environment::Development
+        );
+    }
+
+    #[test]
+    fn when_environment_is_unknown_should_return_error() {
+        assert!("qa".parse::<Environment>().is_err());
+    }
+}
@@ -0,0 +1,413 @@
+use std::{collections::HashMap, net::SocketAddr, sync::Arc, time::Duration};
+
+use anyhow::{Context, Result};
+use axum::{Router, body::Body, extract::ConnectInfo};
+use hyper::{body::Incoming, service::service_fn};
+use hyper_util::{
+    rt::{TokioExecutor, TokioIo},
+    server::conn::auto::Builder,
+};
+use rustls::{
+    RootCertStore, ServerConfig,
+    crypto::{CryptoProvider, ring},
+    pki_types::{CertificateDer, PrivateKeyDer, pem::PemObject},
+    server::WebPkiClientVerifier,
+};
+use tokio::net::TcpListener;
+use tokio_rustls::TlsAcceptor;
+use tower::ServiceExt;
+
+use crate::presentation::api::{
+    security::{ClientIdentity, Scope},
+    settings::TlsSettings,
+};
+
+/// Loads the server certificate, key and (optional) client CA from disk.
+pub fn load_server_config(settings: &TlsSettings) -> Result<ServerConfig> {
+    let certs = CertificateDer::pem_file_iter(&settings.cert_path)
+        .context("Failed to read TLS certificate")?
+        .collect::<Result<Vec<_>, _>>()
+        .context("Invalid TLS certificate")?;
+
+    let key = PrivateKeyDer::from_pem_file(&settings.key_path).context("Invalid TLS key")?;
+
+    let client_roots = settings
+        .client_ca_path
+        .as_ref()
+        .map(|path| {
+            let mut roots = RootCertStore::empty();
+            for cert in CertificateDer::pem_file_iter(path).context("Failed to read client CA")? {
+                roots.add(cert.context("Invalid client CA")?)?;
+            }
+            Ok::<_, anyhow::Error>(roots)
+        })
+        .transpose()?;
+
+    build_server_config(certs, key, client_roots)
+}
+
+/// Client certificates are optional so that callers without one can still
+/// authenticate with a bearer token.
+pub fn build_server_config(
+    certs: Vec<CertificateDer<'static>>,
+    key: PrivateKeyDer<'static>,
+    client_roots: Option<RootCertStore>,
+) -> Result<ServerConfig> {
+    let provider = Arc::new(ring::default_provider());
+
+    let builder = ServerConfig::builder_with_provider(Arc::clone(&provider))
+        .with_safe_default_protocol_versions()?;
+
+    let mut config = match client_roots {
+        Some(roots) => {
+            let verifier = client_verifier(roots, provider)?;
+            builder
+                .with_client_cert_verifier(verifier)
+                .with_single_cert(certs, key)?
+        }
+        None => builder.with_no_client_auth().with_single_cert(certs, key)?,
+    };
+
+    config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+
+    Ok(config)
+}
+
+fn client_verifier(
+    roots: RootCertStore,
+    provider: Arc<CryptoProvider>,
+) -> Result<Arc<dyn rustls::server::danger::ClientCertVerifier>> {
+    Ok(
+        WebPkiClientVerifier::builder_with_provider(Arc::new(roots), provider)
+            .allow_unauthenticated()
+            .build()?,
+    )
+}
+
+/// Resolves the identity of a verified client certificate, if its subject
+/// common name is one of the trusted clients.
+pub fn client_identity(
+    cert: &CertificateDer<'_>,
+    trusted_clients: &HashMap<String, Vec<Scope>>,
+) -> Option<ClientIdentity> {
+    let (_, cert) = x509_parser::parse_x509_certificate(cert.as_ref()).ok()?;
+
+    let subject = cert
+        .subject()
+        .iter_common_name()
+        .next()?
+        .as_str()
+        .ok()?
+        .to_string();
+
+    let scopes = trusted_clients.get(&subject)?.clone();
+
+    Some(ClientIdentity { subject, scopes })
+}
+
+/// Pause after an accept error that is not about a single connection, as
+/// `axum::serve` does.
+const ACCEPT_ERROR_BACKOFF: Duration = Duration::from_secs(1);
+
+/// Accepts TLS connections on `listener` and serves `app` over them. A
+/// client that does not finish the handshake within `handshake_timeout` is
+/// dropped, so idle sockets cannot pile up.
+pub async fn serve(
+    listener: TcpListener,
+    app: Router,
+    config: ServerConfig,
+    trusted_clients: HashMap<String, Vec<Scope>>,
+    handshake_timeout: Duration,
+) {
+    let acceptor = TlsAcceptor::from(Arc::new(config));
+    let trusted_clients = Arc::new(trusted_clients);
+
+    loop {
+        let (stream, remote_addr) = match listener.accept().await {
+            Ok(conn) => conn,
+            // O cliente desistiu antes do accept; nada a esperar
+            Err(e) if is_connection_error(&e) => continue,
+            Err(e) => {
+                // Ex.: EMFILE. Tentar de novo na hora só giraria o laço e
+                // inundaria o log até algum descritor ser liberado
+                tracing::warn!("Failed to accept connection: {}", e);
+                tokio::time::sleep(ACCEPT_ERROR_BACKOFF).await;
+                continue;
+            }
+        };
+
+        let acceptor = acceptor.clone();
+        let trusted_clients = Arc::clone(&trusted_clients);
+        let app = app.clone();
+
+        tokio::spawn(async move {
+            let stream =
+                match tokio::time::timeout(handshake_timeout, acceptor.accept(stream)).await {
+                    Ok(Ok(stream)) => stream,
+                    Ok(Err(e)) => {
+                        tracing::debug!("TLS handshake with {} failed: {}", remote_addr, e);
+                        return;
+                    }
+                    Err(_) => {
+                        tracing::debug!("TLS handshake with {} timed out", remote_addr);
+                        return;
+                    }
+                };
+
+            let identity = stream
+                .get_ref()
+                .1
+                .peer_certificates()
+                .and_then(|certs| certs.first())
+                .and_then(|cert| client_identity(cert, &trusted_clients));
+
+            serve_connection(TokioIo::new(stream), app, remote_addr, identity).await;
+        });
+    }
+}
+
+fn is_connection_error(error: &std::io::Error) -> bool {
+    matches!(
+        error.kind(),
+        std::io::ErrorKind::ConnectionRefused
+            | std::io::ErrorKind::ConnectionAborted
+            | std::io::ErrorKind::ConnectionReset
+    )
+}
+
+async fn serve_connection<I>(
+    io: I,
+    app: Router,
+    remote_addr: SocketAddr,
+    identity: Option<ClientIdentity>,
+) where
+    I: hyper::rt::Read + hyper::rt::Write + Unpin + Send + 'static,
+{
+    let service = service_fn(move |mut request: hyper::Request<Incoming>| {
+        request.extensions_mut().insert(ConnectInfo(remote_addr));
+        if let Some(identity) = identity.clone() {
+            request.extensions_mut().insert(identity);
+        }
+
+        app.clone().oneshot(request.map(Body::new))
+    });
+
+    if let Err(e) = Builder::new(TokioExecutor::new())
+        .serve_connection_with_upgrades(io, service)
+        .await
+    {
+        tracing::debug!("Connection with {} closed: {}", remote_addr, e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{http::StatusCode, routing::post};
+    use rcgen::{BasicConstraints, CertificateParams, DnType, IsCa, Issuer, KeyPair};
+    use rustls::{ClientConfig, pki_types::ServerName};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio_rustls::TlsConnector;
+
+    use super::*;
+    use crate::{
+        domain::repositories::profile_repo::MockProfileRepository,
+        presentation::api::{
+            handlers::tests::{SharedMockRepository, get_test_decoding_key},
+            security::CreateClaims,
+            service::AppState,
+            utils::AppErrorResponse,
+        },
+    };
+
+    struct TestPki {
+        ca: CertificateDer<'static>,
+        issuer: Issuer<'static, KeyPair>,
+    }
+
+    impl TestPki {
+        fn new() -> Self {
+            let mut params = CertificateParams::new(Vec::new()).unwrap();
+            params.is_ca = IsCa::Ca(BasicConstraints::Unconstrained);
+            params
+                .distinguished_name
+                .push(DnType::CommonName, "profile-service test CA");
+
+            let key = KeyPair::generate().unwrap();
+            let ca = params.self_signed(&key).unwrap();
+
+            Self {
+                ca: ca.der().clone(),
+                issuer: Issuer::new(params, key),
+            }
+        }
+
+        fn issue(
+            &self,
+            common_name: &str,
+        ) -> (Vec<CertificateDer<'static>>, PrivateKeyDer<'static>) {
+            let mut params = CertificateParams::new(vec!["localhost".to_string()]).unwrap();
+            params
+                .distinguished_name
+                .push(DnType::CommonName, common_name);
+
+            let key = KeyPair::generate().unwrap();
+            let cert = params.signed_by(&key, &self.issuer).unwrap();
+
+            (
+                vec![cert.der().clone()],
+                PrivateKeyDer::try_from(key.serialize_der()).unwrap(),
+            )
+        }
+
+        fn roots(&self) -> RootCertStore {
+            let mut roots = RootCertStore::empty();
+            roots.add(self.ca.clone()).unwrap();
+            roots
+        }
+    }
+
+    const HANDSHAKE_TIMEOUT: Duration = Duration::from_millis(200);
+
+    async fn start_server(
+        pki: &TestPki,
+        trusted_clients: HashMap<String, Vec<Scope>>,
+    ) -> SocketAddr {
+        let (certs, key) = pki.issue("localhost");
+        let config = build_server_config(certs, key, Some(pki.roots())).unwrap();
+
+        let state = AppState::new(
+            Arc::new(SharedMockRepository(Arc::new(MockProfileRepository::new()))),
+            Arc::new(get_test_decoding_key()),
+        );
+
+        let app = Router::new()
+            .route(
+                "/profiles",
+                post(
+                    |claims: Result<CreateClaims, AppErrorResponse>| async move {
+                        claims.map(|_| StatusCode::CREATED)
+                    },
+                ),
+            )
+            .with_state(state);
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        tokio::spawn(serve(
+            listener,
+            app,
+            config,
+            trusted_clients,
+            HANDSHAKE_TIMEOUT,
+        ));
+
+        addr
+    }
+
+    async fn post_profiles(
+        pki: &TestPki,
+        addr: SocketAddr,
+        client_cert: Option<(Vec<CertificateDer<'static>>, PrivateKeyDer<'static>)>,
+    ) -> String {
+        let builder = ClientConfig::builder_with_provider(Arc::new(ring::default_provider()))
+            .with_safe_default_protocol_versions()
+            .unwrap()
+            .with_root_certificates(pki.roots());
+
+        let config = match client_cert {
+            Some((certs, key)) => builder.with_client_auth_cert(certs, key).unwrap(),
+            None => builder.with_no_client_auth(),
+        };
+
+        let stream = tokio::net::TcpStream::connect(addr).await.unwrap();
+        let Ok(mut stream) = TlsConnector::from(Arc::new(config))
+            .connect(ServerName::try_from("localhost").unwrap(), stream)
+            .await
+        else {
+            return String::new();
+        };
+
+        let _ = stream
+            .write_all(
+                b"POST /profiles HTTP/1.1\r\nHost: localhost\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+            )
+            .await;
+
+        let mut response = String::new();
+        let _ = stream.read_to_string(&mut response).await;
+
+        response.lines().next().unwrap_or_default().to_string()
+    }
+
+    fn trusted_iam_service() -> HashMap<String, Vec<Scope>> {
+        HashMap::from([("iam-service".to_string(), vec![Scope::ProfileCreate])])
+    }
+
+    #[tokio::test]
+    async fn when_client_certificate_is_trusted_should_authenticate_with_its_scopes() {
+        let pki = TestPki::new();
+        let addr = start_server(&pki, trusted_iam_service()).await;
+
+        let status = post_profiles(&pki, addr, Some(pki.issue("iam-service"))).await;
+
+        assert_eq!(status, "HTTP/1.1 201 Created");
+    }
+
+    #[tokio::test]
+    async fn when_client_certificate_subject_is_not_trusted_should_be_unauthorized() {
+        let pki = TestPki::new();
+        let addr = start_server(&pki, trusted_iam_service()).await;
+
+        let status = post_profiles(&pki, addr, Some(pki.issue("unknown-service"))).await;
+
+        assert_eq!(status, "HTTP/1.1 401 Unauthorized");
+    }
+
+    #[tokio::test]
+    async fn when_client_has_no_certificate_should_be_unauthorized() {
+        let pki = TestPki::new();
+        let addr = start_server(&pki, trusted_iam_service()).await;
+
+        let status = post_profiles(&pki, addr, None).await;
+
+        assert_eq!(status, "HTTP/1.1 401 Unauthorized");
+    }
+
+    #[tokio::test]
+    async fn when_client_certificate_is_signed_by_another_ca_should_reject_handshake() {
+        let pki = TestPki::new();
+        let other_pki = TestPki::new();
+        let addr = start_server(&pki, trusted_iam_service()).await;
+
+        let status = post_profiles(&pki, addr, Some(other_pki.issue("iam-service"))).await;
+
+        assert!(status.is_empty());
+    }
+
+    #[tokio::test]
+    async fn when_client_never_starts_handshake_should_close_connection() {
+        let pki = TestPki::new();
+        let addr = start_server(&pki, trusted_iam_service()).await;
+
+        let mut stream = tokio::net::TcpStream::connect(addr).await.unwrap();
+        let mut buffer = [0; 1];
+
+        let read = tokio::time::timeout(Duration::from_secs(5), stream.read(&mut buffer))
+            .await
+            .expect("connection was kept open past the handshake timeout");
+
+        assert!(matches!(read, Ok(0) | Err(_)));
+    }
+
+    #[test]
+    fn when_certificate_subject_is_trusted_should_resolve_identity() {
+        let pki = TestPki::new();
+        let (certs, _) = pki.issue("iam-service");
+
+        let identity = client_identity(&certs[0], &trusted_iam_service()).unwrap();
+
+        assert_eq!(identity.subject, "iam-service");
+        assert_eq!(identity.scopes, vec![Scope::ProfileCreate]);
+    }
+}
@@ -5,22 +5,114 @@ use axum::{
     http::{Request, StatusCode},
     response::IntoResponse,
 };
+use std::collections::BTreeMap;
+
 use chrono::{DateTime, Utc};
 
 use serde::Serialize;
 use serde::de::DeserializeOwned;
-use validify::Validify;
+use serde_json::Value;
+use utoipa::ToSchema;
+use validify::{ValidationError, ValidationErrors, Validify};
 
-use crate::domain::models::profile::ProfileError;
+use crate::domain::models::{
+    error_code::ErrorCode,
+    field_error::{FieldError, join_field_errors},
+    profile::ProfileError,
+};
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct AppErrorResponse {
     pub message: String,
     pub status_code: u16,
     pub timestamp: DateTime<Utc>,
-    pub code: Option<String>,
+    pub code: ErrorCode,
     pub details: Option<String>,
+    #[serde(rename = "errors", skip_serializing_if = "Vec::is_empty")]
+    pub violations: Vec<FieldViolation>,
+}
+
+/// A single invalid field of a request body. `params` carries the limits that
+/// were broken (`min`, `max`) so clients can build their own messages.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub struct FieldViolation {
+    pub field: String,
+    pub code: ErrorCode,
+    pub message: String,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    #[schema(value_type = Object)]
+    pub params: BTreeMap<String, Value>,
+}
+
+impl From<FieldError> for FieldViolation {
+    fn from(error: FieldError) -> Self {
+        Self {
+            field: error.field,
+            code: error.code,
+            message: error.message,
+            params: error
+                .params
+                .into_iter()
+                .map(|(name, value)| (name.to_string(), Value::from(value)))
+                .collect(),
+        }
+    }
+}
+
+impl From<&ValidationError> for FieldViolation {
+    fn from(error: &ValidationError) -> Self {
+        let params: BTreeMap<String, Value> = error
+            .params()
+            .into_iter()
+            .map(|(name, value)| (name.to_string(), value))
+            .collect();
+
+        let number = |name: &str| params.get(name).and_then(Value::as_u64);
+
+        let code = match error.code().as_str() {
+            "length" => match (number("actual"), number("min"), number("max")) {
+                (Some(actual), Some(min), _) if actual < min => ErrorCode::FieldTooShort,
+                (Some(actual), _, Some(max)) if actual > max => ErrorCode::FieldTooLong,
+                _ => ErrorCode::FieldInvalid,
+            },
+            "email" | "url" | "pattern" | "ip" | "phone" => ErrorCode::FieldInvalidFormat,
+            _ => ErrorCode::FieldInvalid,
+        };
+
+        Self {
+            field: error.field_name().unwrap_or_default().to_string(),
+            code,
+            message: error.message().unwrap_or_default(),
+            params: params
+                .into_iter()
+                .filter(|(name, _)| name != "actual")
+                .collect(),
+        }
+    }
+}
+
+impl AppErrorResponse {
+    pub fn new(status_code: StatusCode, code: ErrorCode, message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            status_code: status_code.as_u16(),
+            timestamp: Utc::now(),
+            code,
+            details: None,
+            violations: Vec::new(),
+        }
+    }
+
+    pub fn with_details(mut self, details: impl Into<String>) -> Self {
+        self.details = Some(details.into());
+        self
+    }
+
+    pub fn with_violations(mut self, violations: Vec<FieldViolation>) -> Self {
+        self.violations = violations;
+        self
+    }
 }
 
 impl IntoResponse for AppErrorResponse {
@@ -28,48 +120,82 @@ impl IntoResponse for AppErrorResponse {
         let status_code =
             StatusCode::from_u16(self.status_code).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
 
-        (status_code, axum::Json(self)).into_response()
+        let mut response = (status_code, axum::Json(&self)).into_response();
+
+        // Mantém o erro original para que middlewares possam re-renderizá-lo
+        response.extensions_mut().insert(self);
+        response
     }
 }
 
 impl From<ProfileError> for AppErrorResponse {
     fn from(error: ProfileError) -> Self {
-        let (message, status_code, details) = match error {
-            ProfileError::AlreadyExists(details) => (
-                "Profile already exists".to_string(),
-                409,
-                Some(details.to_string()),
-            ),
-            ProfileError::VersionConflict(details) => (
-                "Version conflict".to_string(),
-                409,
-                Some(details.to_string()),
-            ),
+        let code = error.code();
+
+        let (status_code, message, details) = match error {
+            ProfileError::AlreadyExists(details) => {
+                (StatusCode::CONFLICT, "Profile already exists", details)
+            }
+            ProfileError::VersionConflict(details) => {
+                (StatusCode::CONFLICT, "Version conflict", details)
+            }
+            // O documento gravado é que está inválido, não o pedido
             ProfileError::InvalidData(details) => (
-                "Validation error".to_string(),
-                400,
-                Some(details.to_string()),
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Stored profile data is invalid",
+                details,
             ),
-            ProfileError::NotFound(details) => (
-                "Profile not found".to_string(),
-                404,
-                Some(details.to_string()),
+            ProfileError::InvalidFields(errors) => {
+                return Self::new(StatusCode::BAD_REQUEST, code, "Validation error")
+                    .with_details(join_field_errors(&errors))
+                    .with_violations(errors.into_iter().map(FieldViolation::from).collect());
+            }
+            ProfileError::NotFound(details) => {
+                (StatusCode::NOT_FOUND, "Profile not found", details)
+            }
+            ProfileError::UsernameTaken(details) => {
+                (StatusCode::CONFLICT, "Username already taken", details)
+            }
+            ProfileError::UsernameChangeTooSoon(_) => (
+                StatusCode::TOO_MANY_REQUESTS,
+                "Username changed too recently",
+                error.to_string(),
             ),
             ProfileError::Unknown(details) => {
-                ("Database error".to_string(), 500, Some(details.to_string()))
+                (StatusCode::INTERNAL_SERVER_ERROR, "Database error", details)
             }
         };
 
-        Self {
-            message,
-            status_code,
-            timestamp: Utc::now(),
-            code: None,
-            details,
-        }
+        Self::new(status_code, code, message).with_details(details)
     }
 }
 
+/// `Json` rejecting a malformed body with an `AppErrorResponse`, for bodies
+/// whose content the domain validates.
+pub struct AppJson<T>(pub T);
+
+impl<T, S> FromRequest<S> for AppJson<T>
+where
+    S: Send + Sync,
+    T: DeserializeOwned,
+{
+    type Rejection = AppErrorResponse;
+
+    async fn from_request(req: Request<Body>, _state: &S) -> Result<Self, Self::Rejection> {
+        let Json(value) = Json::<T>::from_request(req, _state).await.map_err(|err| {
+            AppErrorResponse::new(
+                StatusCode::BAD_REQUEST,
+                ErrorCode::InvalidJson,
+                "Invalid JSON",
+            )
+            .with_details(err.to_string())
+        })?;
+
+        Ok(AppJson(value))
+    }
+}
+
+/// Applies the `#[modify]` rules of `T`, then its `#[validate]` ones.
 pub struct ValidatedJson<T>(pub T);
 
 impl<T, S> FromRequest<S> for ValidatedJson<T>
@@ -80,46 +206,40 @@ where
     type Rejection = AppErrorResponse;
 
     async fn from_request(req: Request<Body>, _state: &S) -> Result<Self, Self::Rejection> {
-        let Json(value) =
-            Json::<T>::from_request(req, _state)
-                .await
-                .map_err(|err| AppErrorResponse {
-                    message: "Invalid JSON".to_string(),
-                    status_code: 400,
-                    timestamp: Utc::now(),
-                    code: None,
-                    details: Some(err.to_string()),
-                })?;
-
-        value.validate().map_err(|e| {
-            let error_messages: Vec<String> = e
-                .errors()
-                .iter()
-                .map(|err| {
-                    format!(
-                        "'{}': '{}'",
-                        err.field_name().unwrap_or_default(),
-                        err.message().unwrap_or_default()
-                    )
-                })
-                .collect();
-
-            AppErrorResponse {
-                message: "Validation error".to_string(),
-                status_code: 400,
-                timestamp: Utc::now(),
-                code: None,
-                details: Some(error_messages.join(", ")),
-            }
-        })?;
+        let AppJson(mut value) = AppJson::<T>::from_request(req, _state).await?;
+
+        value.validify().map_err(validation_error)?;
 
         Ok(ValidatedJson(value))
     }
 }
 
+/// Answer for a body that breaks its `#[validate]` rules, with one violation
+/// per field.
+pub(crate) fn validation_error(errors: ValidationErrors) -> AppErrorResponse {
+    let violations: Vec<FieldViolation> =
+        errors.errors().iter().map(FieldViolation::from).collect();
+
+    let error_messages: Vec<String> = violations
+        .iter()
+        .map(|violation| format!("'{}': '{}'", violation.field, violation.message))
+        .collect();
+
+    AppErrorResponse::new(
+        StatusCode::BAD_REQUEST,
+        ErrorCode::ValidationFailed,
+        "Validation error",
+    )
+    .with_details(error_messages.join(", "))
+    .with_violations(violations)
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::presentation::api::handlers::requests::CreateProfileRequest;
+    use crate::{
+        application::dtos::update_profile_input::UpdateProfileInput,
+        presentation::api::handlers::requests::{CreateProfileRequest, UpdateProfileRequest},
+    };
 
     use super::*;
     use axum::{Router, body::Body, http::Request, routing::post};
@@ -206,4 +326,68 @@ mod tests {
         let response = app.oneshot(request).await.unwrap();
         assert_eq!(response.status(), StatusCode::BAD_REQUEST);
     }
+
+    #[tokio::test]
+    async fn when_validation_fails_then_lists_every_invalid_field() {
+        let app = Router::new().route(
+            "/test",
+            post(|_: ValidatedJson<UpdateProfileRequest>| async {}),
+        );
+
+        let request = Request::builder()
+            .method("POST")
+            .uri("/test")
+            .header("content-type", "application/json")
+            .body(Body::from(
+                json!({
+                    "firstName": "J",
+                    "bio": "b".repeat(200)
+                })
+                .to_string(),
+            ))
+            .unwrap();
+
+        let response = app.oneshot(request).await.unwrap();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+        let errors = body["errors"].as_array().unwrap();
+        let first_name = errors.iter().find(|e| e["field"] == "firstName").unwrap();
+        let bio = errors.iter().find(|e| e["field"] == "bio").unwrap();
+
+        assert_eq!(errors.len(), 2);
+        assert_eq!(first_name["code"], "FIELD_TOO_SHORT");
+        assert_eq!(first_name["params"]["min"], 2);
+        assert_eq!(first_name["params"]["max"], 15);
+        assert_eq!(bio["code"], "FIELD_TOO_LONG");
+        assert!(bio["params"].get("actual").is_none());
+    }
+
+    #[test]
+    fn when_profile_has_several_invalid_fields_then_maps_each_to_a_violation() {
+        let error =
+            UpdateProfileInput::try_new("not-a-uuid".into(), Some("J".into()), None, None, None)
+                .unwrap_err();
+
+        let response = AppErrorResponse::from(error);
+
+        assert_eq!(response.code, ErrorCode::ValidationFailed);
+        assert_eq!(response.violations.len(), 2);
+        assert_eq!(response.violations[0].code, ErrorCode::IdInvalid);
+        assert_eq!(
+            response.violations[1].params["min"],
+            serde_json::Value::from(2)
+        );
+    }
+
+    #[test]
+    fn when_stored_data_is_invalid_then_maps_to_server_error() {
+        let response = AppErrorResponse::from(ProfileError::InvalidData("bio too short".into()));
+
+        assert_eq!(response.status_code, 500);
+        assert_eq!(response.code, ErrorCode::ProfileDataCorrupted);
+        assert_eq!(response.message, "Stored profile data is invalid");
+    }
 }
@@ -0,0 +1,268 @@
+use std::sync::Arc;
+
+use axum::{
+    Router,
+    extract::{Request, State},
+    http::{HeaderName, HeaderValue, header::LINK},
+    middleware::{Next, from_fn_with_state},
+    response::Response,
+};
+use chrono::{DateTime, Utc};
+use utoipa::openapi::{Deprecated, OpenApi as OpenApiDocument};
+use utoipa_axum::router::OpenApiRouter;
+
+use crate::presentation::api::conditional::http_date;
+
+pub const V1_PREFIX: &str = "/v1";
+
+pub const DEPRECATION: &str = "deprecation";
+pub const SUNSET: &str = "sunset";
+
+/// Lifecycle of a deprecated API version, announced on every response
+/// through the `Deprecation` (RFC 9745) and `Sunset` (RFC 8594) headers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Deprecation {
+    pub since: DateTime<Utc>,
+    pub sunset: Option<DateTime<Utc>>,
+    /// Prefix of the version replacing this one. When set, a
+    /// `Link: <...>; rel="successor-version"` points to the same resource
+    /// under it.
+    pub successor: Option<String>,
+}
+
+impl Deprecation {
+    pub fn deprecation_header(&self) -> HeaderValue {
+        HeaderValue::from_str(&format!("@{}", self.since.timestamp()))
+            .expect("a timestamp is a valid header value")
+    }
+
+    pub fn sunset_header(&self) -> Option<HeaderValue> {
+        self.sunset.map(|sunset| {
+            HeaderValue::from_str(&http_date(sunset)).expect("an HTTP date is a valid header value")
+        })
+    }
+
+    fn successor_link(&self, path: &str) -> Option<HeaderValue> {
+        let successor = self.successor.as_ref()?;
+
+        HeaderValue::from_str(&format!(
+            "<{}{}>; rel=\"successor-version\"",
+            successor,
+            unversioned_path(path)
+        ))
+        .ok()
+    }
+}
+
+async fn deprecation_middleware(
+    State(deprecation): State<Arc<Deprecation>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let link = deprecation.successor_link(request.uri().path());
+
+    let mut response = next.run(request).await;
+    let headers = response.headers_mut();
+
+    headers.insert(
+        HeaderName::from_static(DEPRECATION),
+        deprecation.deprecation_header(),
+    );
+
+    if let Some(sunset) = deprecation.sunset_header() {
+        headers.insert(HeaderName::from_static(SUNSET), sunset);
+    }
+
+    if let Some(link) = link {
+        headers.append(LINK, link);
+    }
+
+    response
+}
+
+/// Mounts the routes of one API version under `prefix`; an empty prefix
+/// mounts them at the root, which is how the unversioned alias is served.
+///
+/// Versions keep their own handlers, so a `/v2` with different request and
+/// response types is mounted side by side with its own call. Its schemas
+/// need distinct names in the document (`#[schema(as = v2::ProfileResponse)]`).
+pub fn mount_version<S>(
+    routes: OpenApiRouter<S>,
+    prefix: &str,
+    deprecation: Option<Deprecation>,
+) -> (Router<S>, OpenApiDocument)
+where
+    S: Clone + Send + Sync + 'static,
+{
+    let routes = if prefix.is_empty() {
+        routes
+    } else {
+        OpenApiRouter::new().nest(prefix, routes)
+    };
+
+    let (router, mut openapi) = routes.split_for_parts();
+
+    let Some(deprecation) = deprecation else {
+        return (router, openapi);
+    };
+
+    for item in openapi.paths.paths.values_mut() {
+        for operation in [
+            &mut item.get,
+            &mut item.post,
+            &mut item.put,
+            &mut item.patch,
+            &mut item.delete,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            operation.deprecated = Some(Deprecated::True);
+        }
+    }
+
+    let router = router.layer(from_fn_with_state(
+        Arc::new(deprecation),
+        deprecation_middleware,
+    ));
+
+    (router, openapi)
+}
+
+/// Route as declared without its version prefix, so `/v1/profiles` and the
+/// `/profiles` alias share per-route settings such as rate limits.
+pub fn unversioned_path(path: &str) -> &str {
+    let Some(rest) = path.strip_prefix("/v") else {
+        return path;
+    };
+
+    let digits = rest.chars().take_while(char::is_ascii_digit).count();
+
+    match &rest[digits..] {
+        remainder if digits > 0 && (remainder.is_empty() || remainder.starts_with('/')) => {
+            remainder
+        }
+        _ => path,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        Json,
+        body::Body,
+        http::{Method, StatusCode},
+    };
+    use chrono::TimeZone;
+    use serde::Serialize;
+    use tower::ServiceExt;
+    use utoipa::ToSchema;
+
+    use super::*;
+
+    #[derive(Serialize, ToSchema)]
+    struct LegacyBody {
+        name: String,
+    }
+
+    #[derive(Serialize, ToSchema)]
+    struct CurrentBody {
+        given_name: String,
+        family_name: String,
+    }
+
+    #[utoipa::path(get, path = "/items", responses((status = 200, body = LegacyBody)))]
+    async fn legacy_handler() -> Json<LegacyBody> {
+        Json(LegacyBody {
+            name: "John Doe".into(),
+        })
+    }
+
+    #[utoipa::path(get, path = "/items", responses((status = 200, body = CurrentBody)))]
+    async fn current_handler() -> Json<CurrentBody> {
+        Json(CurrentBody {
+            given_name: "John".into(),
+            family_name: "Doe".into(),
+        })
+    }
+
+    fn deprecation() -> Deprecation {
+        Deprecation {
+            since: Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap(),
+            sunset: Some(Utc.with_ymd_and_hms(2026, 7, 1, 0, 0, 0).unwrap()),
+            successor: Some("/v2".into()),
+        }
+    }
+
+    fn app() -> (Router, OpenApiDocument) {
+        let (v1, mut openapi) = mount_version(
+            OpenApiRouter::new().routes(utoipa_axum::routes!(legacy_handler)),
+            "/v1",
+            Some(deprecation()),
+        );
+        let (v2, v2_openapi) = mount_version(
+            OpenApiRouter::new().routes(utoipa_axum::routes!(current_handler)),
+            "/v2",
+            None,
+        );
+        openapi.merge(v2_openapi);
+
+        (v1.merge(v2), openapi)
+    }
+
+    async fn get(app: &Router, path: &str) -> Response {
+        let request = Request::builder()
+            .method(Method::GET)
+            .uri(path)
+            .body(Body::empty())
+            .unwrap();
+
+        app.clone().oneshot(request).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn when_version_is_deprecated_should_announce_deprecation_and_sunset() {
+        let (app, _) = app();
+
+        let response = get(&app, "/v1/items").await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[DEPRECATION], "@1767225600");
+        assert_eq!(response.headers()[SUNSET], "Wed, 01 Jul 2026 00:00:00 GMT");
+        assert_eq!(
+            response.headers()[LINK],
+            "</v2/items>; rel=\"successor-version\""
+        );
+    }
+
+    #[tokio::test]
+    async fn when_versions_are_mounted_side_by_side_should_serve_each_shape() {
+        let (app, openapi) = app();
+
+        let response = get(&app, "/v2/items").await;
+        assert!(response.headers().get(DEPRECATION).is_none());
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["family_name"], "Doe");
+
+        let json = serde_json::to_value(&openapi).unwrap();
+        assert_eq!(json["paths"]["/v1/items"]["get"]["deprecated"], true);
+        assert!(
+            json["paths"]["/v2/items"]["get"]
+                .get("deprecated")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn when_path_has_version_prefix_should_strip_it() {
+        assert_eq!(unversioned_path("/v1/profiles/{id}"), "/profiles/{id}");
+        assert_eq!(unversioned_path("/v12/profiles"), "/profiles");
+        assert_eq!(unversioned_path("/profiles"), "/profiles");
+        assert_eq!(unversioned_path("/videos"), "/videos");
+        assert_eq!(unversioned_path("/v1beta/profiles"), "/v1beta/profiles");
+    }
+}
@@ -0,0 +1,735 @@
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, Write},
+    path::PathBuf,
+    process::ExitCode,
+    sync::Arc,
+    time::Duration,
+};
+
+use anyhow::{Context, Result};
+use clap::{Parser, Subcommand};
+use mongodb::bson::Document;
+use serde::Serialize;
+
+use crate::{
+    application::{
+        dtos::{
+            create_profile_input::CreateProfileInput, get_profile_by_id_input::GetProfileByIdInput,
+            update_profile_input::UpdateProfileInput,
+        },
+        use_cases::{
+            create_profile::CreateProfileUseCase, delete_profile::DeleteProfileUseCase,
+            get_profile_by_id::GetProfileByIdUseCase, update_profile::UpdateProfileUseCase,
+        },
+    },
+    domain::{models::profile::Profile, repositories::profile_repo::ProfileRepository},
+    infrastructure::repositories::{
+        mongo_profile_repo::{MongoProfileRepository, ProfileDocument},
+        profile_integrity::{self, FieldIssue, RepairAction, RepairMode},
+        profile_schema,
+    },
+};
+
+/// Maintenance commands run by operators against the same database, and with
+/// the same environment, as the service.
+#[derive(Debug, Parser)]
+#[command(name = "profile-admin", version, about)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Prints a profile as stored
+    Get { id: String },
+
+    /// Creates a profile with the same validation as the API
+    Create { id: String, email: String },
+
+    /// Changes the given fields of a profile, bumping its version
+    Update {
+        id: String,
+        #[arg(long)]
+        first_name: Option<String>,
+        #[arg(long)]
+        last_name: Option<String>,
+        #[arg(long)]
+        bio: Option<String>,
+        #[arg(long)]
+        profile_image_url: Option<String>,
+    },
+
+    /// Removes a profile for good
+    Delete { id: String },
+
+    /// Inserts the profiles of an NDJSON file written by `export`
+    Import {
+        /// Read from stdin when omitted
+        path: Option<PathBuf>,
+        #[arg(long, default_value_t = 500)]
+        batch_size: usize,
+    },
+
+    /// Writes every stored profile as NDJSON
+    Export {
+        /// Write to stdout when omitted
+        path: Option<PathBuf>,
+    },
+
+    /// Checks every stored document against the domain's value objects,
+    /// printing one NDJSON report per invalid document
+    Revalidate {
+        /// Fix invalid fields: normalize, truncate or null; each mode also
+        /// tries the milder ones first
+        #[arg(long)]
+        repair: Option<RepairMode>,
+        /// Report what the repair would change without writing
+        #[arg(long, requires = "repair")]
+        dry_run: bool,
+    },
+
+    /// Rewrites the documents stored in an older shape in the current one;
+    /// reads upgrade them on the fly either way
+    UpgradeSchema {
+        #[arg(long, default_value_t = 500)]
+        batch_size: u64,
+        /// Pause between batches, to spread the load
+        #[arg(long, default_value_t = 0)]
+        pause_ms: u64,
+    },
+
+    /// Prints the size of the collection
+    Stats,
+}
+
+/// Where commands write; stdout and stderr outside of tests.
+pub struct Output<'a> {
+    pub out: &'a mut dyn Write,
+    pub err: &'a mut dyn Write,
+}
+
+/// Runs `command`. Single-profile commands and `import` go through
+/// `repository`, so they follow the same rules and cache invalidation as the
+/// API; the remaining ones read the collection directly through `mongo`.
+pub async fn run<R: ProfileRepository>(
+    command: Command,
+    repository: Arc<R>,
+    mongo: &MongoProfileRepository,
+    output: Output<'_>,
+) -> Result<ExitCode> {
+    match command {
+        Command::Get { id } => {
+            let profile = GetProfileByIdUseCase::new(repository)
+                .execute(GetProfileByIdInput::try_new(id)?)
+                .await?;
+
+            print_profile(profile, output.out)
+        }
+        Command::Create { id, email } => {
+            let profile = CreateProfileUseCase::new(repository)
+                .execute(CreateProfileInput::try_new(id, email)?)
+                .await?;
+
+            print_profile(profile, output.out)
+        }
+        Command::Update {
+            id,
+            first_name,
+            last_name,
+            bio,
+            profile_image_url,
+        } => {
+            let input =
+                UpdateProfileInput::try_new(id, first_name, last_name, bio, profile_image_url)?;
+            let profile = UpdateProfileUseCase::new(repository).execute(input).await?;
+
+            print_profile(profile, output.out)
+        }
+        Command::Delete { id } => {
+            DeleteProfileUseCase::new(repository)
+                .execute(GetProfileByIdInput::try_new(id.clone())?)
+                .await?;
+
+            writeln!(output.out, "Deleted {}", id)?;
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Import { path, batch_size } => {
+            let input: Box<dyn BufRead> = match path {
+                Some(path) => Box::new(BufReader::new(
+                    File::open(&path).with_context(|| format!("Cannot open {}", path.display()))?,
+                )),
+                None => Box::new(io::stdin().lock()),
+            };
+
+            import(repository.as_ref(), input, batch_size.max(1), output).await
+        }
+        Command::Export { path } => {
+            let mut file;
+            let out: &mut dyn Write = match path {
+                Some(path) => {
+                    file = io::BufWriter::new(
+                        File::create(&path)
+                            .with_context(|| format!("Cannot create {}", path.display()))?,
+                    );
+                    &mut file
+                }
+                None => output.out,
+            };
+
+            export(mongo, out, output.err).await
+        }
+        Command::Revalidate { repair, dry_run } => {
+            revalidate(repository.as_ref(), mongo, repair, dry_run, output).await
+        }
+        Command::UpgradeSchema {
+            batch_size,
+            pause_ms,
+        } => {
+            let summary = mongo
+                .upgrade_schema(batch_size, Duration::from_millis(pause_ms))
+                .await?;
+
+            serde_json::to_writer_pretty(&mut *output.out, &summary)?;
+            writeln!(output.out)?;
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Stats => {
+            let stats = mongo.stats().await?;
+
+            serde_json::to_writer_pretty(&mut *output.out, &stats)?;
+            writeln!(output.out)?;
+            Ok(ExitCode::SUCCESS)
+        }
+    }
+}
+
+fn print_profile(profile: Profile, out: &mut dyn Write) -> Result<ExitCode> {
+    serde_json::to_writer_pretty(&mut *out, &ProfileDocument::from(profile))?;
+    writeln!(out)?;
+
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Inserts the valid lines in batches; invalid lines and profiles that could
+/// not be inserted are reported on `err` without stopping the import.
+async fn import<R: ProfileRepository>(
+    repository: &R,
+    input: Box<dyn BufRead>,
+    batch_size: usize,
+    output: Output<'_>,
+) -> Result<ExitCode> {
+    let (mut imported, mut failed) = (0, 0);
+    let mut batch: Vec<(usize, Profile)> = Vec::with_capacity(batch_size);
+
+    let mut lines = input.lines().enumerate().peekable();
+
+    while let Some((index, line)) = lines.next() {
+        let number = index + 1;
+        let line = line?;
+
+        if !line.trim().is_empty() {
+            // Exportações de versões anteriores ainda podem ser importadas
+            let profile = serde_json::from_str::<Document>(&line)
+                .map_err(|e| e.to_string())
+                .and_then(|document| {
+                    profile_schema::read_document(document).map_err(|e| e.to_string())
+                })
+                .and_then(|document| Profile::try_from(document).map_err(|e| e.to_string()));
+
+            match profile {
+                Ok(profile) => batch.push((number, profile)),
+                Err(e) => {
+                    failed += 1;
+                    writeln!(output.err, "line {}: {}", number, e)?;
+                }
+            }
+        }
+
+        if batch.is_empty() || (batch.len() < batch_size && lines.peek().is_some()) {
+            continue;
+        }
+
+        let profiles: Vec<Profile> = batch.iter().map(|(_, profile)| profile.clone()).collect();
+
+        // Um lote que falha por inteiro não interrompe os seguintes
+        let outcomes = match repository.insert_profiles(&profiles).await {
+            Ok(outcomes) => outcomes,
+            Err(e) => vec![Err(e); profiles.len()],
+        };
+
+        for ((number, _), outcome) in batch.drain(..).zip(outcomes) {
+            match outcome {
+                Ok(()) => imported += 1,
+                Err(e) => {
+                    failed += 1;
+                    writeln!(output.err, "line {}: {}", number, e)?;
+                }
+            }
+        }
+    }
+
+    writeln!(output.out, "Imported {}, failed {}", imported, failed)?;
+
+    Ok(if failed == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
+
+async fn export(
+    mongo: &MongoProfileRepository,
+    out: &mut dyn Write,
+    err: &mut dyn Write,
+) -> Result<ExitCode> {
+    let mut cursor = mongo.find_all().await?;
+    let (mut exported, mut skipped) = (0, 0);
+
+    while cursor.advance().await? {
+        match read_current(&cursor) {
+            Ok(document) => {
+                serde_json::to_writer(&mut *out, &document)?;
+                writeln!(out)?;
+                exported += 1;
+            }
+            Err(e) => {
+                skipped += 1;
+                writeln!(err, "{}: {}", document_id(cursor.current()), e)?;
+            }
+        }
+    }
+
+    out.flush()?;
+    writeln!(err, "Exported {}, skipped {}", exported, skipped)?;
+
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Outcome of one document in `revalidate`, written as an NDJSON line.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DocumentReport {
+    id: String,
+    status: DocumentStatus,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    issues: Vec<FieldIssue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum DocumentStatus {
+    /// Fails validation; no repair was requested
+    Invalid,
+    /// Would be fixed by the requested repair (dry run)
+    Repairable,
+    Repaired,
+    /// The requested repair does not fix every field
+    Unrepairable,
+    /// Does not deserialize into a `ProfileDocument` at all
+    Unreadable,
+    /// Fixed, but the write failed, e.g. the profile changed meanwhile
+    Failed,
+}
+
+/// Reports every document the service would refuse to read, e.g. one written
+/// before a validation rule was tightened, and optionally repairs it. Fails
+/// while any document is left invalid, so it can run as a scheduled check.
+async fn revalidate<R: ProfileRepository>(
+    repository: &R,
+    mongo: &MongoProfileRepository,
+    repair: Option<RepairMode>,
+    dry_run: bool,
+    output: Output<'_>,
+) -> Result<ExitCode> {
+    let mut cursor = mongo.find_all().await?;
+    let (mut checked, mut invalid, mut repaired) = (0, 0, 0);
+
+    while cursor.advance().await? {
+        checked += 1;
+
+        let report = match read_current(&cursor) {
+            Ok(document) => check_document(repository, document, repair, dry_run).await,
+            Err(e) => Some(DocumentReport {
+                id: document_id(cursor.current()),
+                status: DocumentStatus::Unreadable,
+                issues: Vec::new(),
+                error: Some(e),
+            }),
+        };
+
+        let Some(report) = report else {
+            continue;
+        };
+
+        match report.status {
+            DocumentStatus::Repaired => repaired += 1,
+            _ => invalid += 1,
+        }
+
+        serde_json::to_writer(&mut *output.out, &report)?;
+        writeln!(output.out)?;
+    }
+
+    writeln!(
+        output.err,
+        "Checked {}, repaired {}, invalid {}",
+        checked, repaired, invalid
+    )?;
+
+    Ok(if invalid == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
+
+/// `None` when the document is valid. A repaired document is saved as a new
+/// version through `repository`, so a concurrent change is never overwritten
+/// and cached copies are invalidated.
+async fn check_document<R: ProfileRepository>(
+    repository: &R,
+    mut document: ProfileDocument,
+    repair: Option<RepairMode>,
+    dry_run: bool,
+) -> Option<DocumentReport> {
+    let id = document.id().to_string();
+    let issues = profile_integrity::inspect(&mut document, repair);
+
+    let report = |status, issues, error| {
+        Some(DocumentReport {
+            id: id.clone(),
+            status,
+            issues,
+            error,
+        })
+    };
+
+    if issues.is_empty() {
+        // Campos fora da varredura, como o _id, ainda podem ser inválidos
+        return match Profile::try_from(document) {
+            Ok(_) => None,
+            Err(e) => report(DocumentStatus::Invalid, issues, Some(e.to_string())),
+        };
+    }
+
+    let unrepaired = issues
+        .iter()
+        .any(|issue| issue.action == Some(RepairAction::Unrepaired));
+
+    match repair {
+        None => report(DocumentStatus::Invalid, issues, None),
+        Some(_) if unrepaired => report(DocumentStatus::Unrepairable, issues, None),
+        Some(_) => {
+            document.touch();
+
+            let profile = match Profile::try_from(document) {
+                Ok(profile) => profile,
+                Err(e) => {
+                    return report(DocumentStatus::Unrepairable, issues, Some(e.to_string()));
+                }
+            };
+
+            if dry_run {
+                return report(DocumentStatus::Repairable, issues, None);
+            }
+
+            match repository.save(&profile).await {
+                Ok(()) => report(DocumentStatus::Repaired, issues, None),
+                Err(e) => report(DocumentStatus::Failed, issues, Some(e.to_string())),
+            }
+        }
+    }
+}
+
+/// The current document of a `find_all` cursor, in the current shape.
+fn read_current(cursor: &mongodb::Cursor<Document>) -> Result<ProfileDocument, String> {
+    cursor
+        .deserialize_current()
+        .map_err(|e| e.to_string())
+        .and_then(|document| profile_schema::read_document(document).map_err(|e| e.to_string()))
+}
+
+fn document_id(document: &mongodb::bson::RawDocument) -> String {
+    document
+        .get_str("_id")
+        .map(str::to_string)
+        .unwrap_or_else(|_| "<no _id>".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+    use crate::domain::{
+        object_values::{email::Email, id::Id},
+        repositories::profile_repo::{MockProfileRepository, ProfileRepositoryError},
+    };
+
+    fn line(id: &Id) -> String {
+        serde_json::to_string(&ProfileDocument::from(Profile::new(
+            id.clone(),
+            Email::try_from("john.doe@example.com").unwrap(),
+        )))
+        .unwrap()
+    }
+
+    async fn run_import(
+        mock_repo: MockProfileRepository,
+        input: String,
+        batch_size: usize,
+    ) -> (ExitCode, String, String) {
+        let (mut out, mut err) = (Vec::new(), Vec::new());
+
+        let code = import(
+            &mock_repo,
+            Box::new(Cursor::new(input)),
+            batch_size,
+            Output {
+                out: &mut out,
+                err: &mut err,
+            },
+        )
+        .await
+        .unwrap();
+
+        (
+            code,
+            String::from_utf8(out).unwrap(),
+            String::from_utf8(err).unwrap(),
+        )
+    }
+
+    fn stored(first_name: &str, version: u64) -> ProfileDocument {
+        profile_schema::read_document(
+            serde_json::from_value(serde_json::json!({
+                "_id": Id::generate().to_string(),
+                "email": "john.doe@example.com",
+                "first_name": first_name,
+                "last_name": null,
+                "bio": null,
+                "profile_image_url": null,
+                "created_at": chrono::Utc::now(),
+                "updated_at": null,
+                "version": version
+            }))
+            .unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn when_repair_mode_is_given_should_parse_it() {
+        let cli =
+            Cli::try_parse_from(["profile-admin", "revalidate", "--repair", "truncate"]).unwrap();
+
+        assert!(matches!(
+            cli.command,
+            Command::Revalidate {
+                repair: Some(RepairMode::Truncate),
+                dry_run: false
+            }
+        ));
+        assert!(Cli::try_parse_from(["profile-admin", "revalidate", "--dry-run"]).is_err());
+    }
+
+    #[tokio::test]
+    async fn when_document_is_valid_should_not_report_it() {
+        let report = check_document(
+            &MockProfileRepository::new(),
+            stored("John", 1),
+            Some(RepairMode::Null),
+            false,
+        )
+        .await;
+
+        assert!(report.is_none());
+    }
+
+    #[tokio::test]
+    async fn when_no_repair_is_requested_should_only_report() {
+        let report = check_document(
+            &MockProfileRepository::new(),
+            stored("-John-", 1),
+            None,
+            false,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(report.status, DocumentStatus::Invalid);
+        assert_eq!(report.issues[0].field, "first_name");
+    }
+
+    #[tokio::test]
+    async fn when_dry_run_should_report_repair_without_saving() {
+        let report = check_document(
+            &MockProfileRepository::new(),
+            stored("-John-", 1),
+            Some(RepairMode::Normalize),
+            true,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(report.status, DocumentStatus::Repairable);
+        assert_eq!(report.issues[0].repaired_value.as_deref(), Some("John"));
+    }
+
+    #[tokio::test]
+    async fn when_repairing_should_save_a_new_version() {
+        let mut mock_repo = MockProfileRepository::new();
+
+        mock_repo
+            .expect_save()
+            .withf(|profile| {
+                profile.version() == 4
+                    && profile.updated_at().is_some()
+                    && profile.first_name().map(ToString::to_string) == Some("John".into())
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let report = check_document(
+            &mock_repo,
+            stored("-John-", 3),
+            Some(RepairMode::Normalize),
+            false,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(report.status, DocumentStatus::Repaired);
+    }
+
+    #[tokio::test]
+    async fn when_profile_changed_meanwhile_should_report_failure() {
+        let mut mock_repo = MockProfileRepository::new();
+
+        mock_repo.expect_save().returning(|profile| {
+            Err(ProfileRepositoryError::VersionConflict(
+                profile.id().to_string(),
+            ))
+        });
+
+        let report = check_document(
+            &mock_repo,
+            stored("-John-", 1),
+            Some(RepairMode::Normalize),
+            false,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(report.status, DocumentStatus::Failed);
+        assert!(report.error.is_some());
+    }
+
+    #[tokio::test]
+    async fn when_repair_does_not_fix_every_field_should_not_save() {
+        let report = check_document(
+            &MockProfileRepository::new(),
+            stored("J", 1),
+            Some(RepairMode::Truncate),
+            false,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(report.status, DocumentStatus::Unrepairable);
+    }
+
+    #[test]
+    fn when_update_has_flags_should_parse_them() {
+        let cli = Cli::try_parse_from([
+            "profile-admin",
+            "update",
+            "0190b0b4-0000-7000-8000-000000000000",
+            "--first-name",
+            "John",
+        ])
+        .unwrap();
+
+        assert!(matches!(
+            cli.command,
+            Command::Update { first_name: Some(ref name), last_name: None, .. } if name == "John"
+        ));
+    }
+
+    #[test]
+    fn when_import_has_no_batch_size_should_default_to_500() {
+        let cli = Cli::try_parse_from(["profile-admin", "import", "profiles.ndjson"]).unwrap();
+
+        assert!(matches!(
+            cli.command,
+            Command::Import {
+                path: Some(_),
+                batch_size: 500
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn when_importing_should_insert_in_batches() {
+        let ids: Vec<Id> = (0..3).map(|_| Id::generate()).collect();
+        let input = ids.iter().map(line).collect::<Vec<_>>().join("\n");
+
+        let mut mock_repo = MockProfileRepository::new();
+        mock_repo
+            .expect_insert_profiles()
+            .withf(|profiles| profiles.len() == 2)
+            .times(1)
+            .returning(|profiles| Ok(vec![Ok(()); profiles.len()]));
+        mock_repo
+            .expect_insert_profiles()
+            .withf(|profiles| profiles.len() == 1)
+            .times(1)
+            .returning(|profiles| Ok(vec![Ok(()); profiles.len()]));
+
+        let (code, out, err) = run_import(mock_repo, input, 2).await;
+
+        assert_eq!(code, ExitCode::SUCCESS);
+        assert_eq!(out, "Imported 3, failed 0\n");
+        assert!(err.is_empty());
+    }
+
+    #[tokio::test]
+    async fn when_lines_fail_should_report_them_and_keep_going() {
+        let (taken, fresh) = (Id::generate(), Id::generate());
+        let input = format!(
+            "{}\n\nnot json\n{}\n{}\n",
+            line(&taken),
+            line(&fresh).replace("john.doe@example.com", "not-an-email"),
+            line(&fresh)
+        );
+
+        let mut mock_repo = MockProfileRepository::new();
+        mock_repo
+            .expect_insert_profiles()
+            .times(1)
+            .returning(|profiles| {
+                Ok(vec![
+                    Err(ProfileRepositoryError::AlreadyExists(
+                        profiles[0].id().to_string(),
+                    )),
+                    Ok(()),
+                ])
+            });
+
+        let (code, out, err) = run_import(mock_repo, input, 500).await;
+
+        assert_eq!(code, ExitCode::FAILURE);
+        assert_eq!(out, "Imported 1, failed 3\n");
+
+        let errors: Vec<&str> = err.lines().collect();
+
+        assert_eq!(errors.len(), 3);
+        assert!(errors[0].starts_with("line 3: "));
+        assert!(errors[1].starts_with("line 4: "));
+        assert!(errors[2].starts_with("line 1: "));
+    }
+}